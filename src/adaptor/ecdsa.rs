@@ -0,0 +1,71 @@
+use ring::digest::{digest, SHA256};
+
+use crate::{ecdsa::{bits_to_scalar, Signature}, proofs::{DleqProof, Transcript}, types::{ECpoint, Scalar, Generator, EC}};
+
+///ECDSA pre-signature with R = k * T, R' = k * G and s' = k^-1 * (z + r * d) for
+///r = R.x mod N. The proof shows log_G(R') = log_T(R), so R really is the nonce the
+///completed signature s = s' * t^-1 will have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EcdsaPreSignature<E: EC> {
+    pub nonce: ECpoint<E>,
+    pub nonce_g: ECpoint<E>,
+    pub s: Scalar<E>,
+    pub proof: DleqProof<E>,
+}
+
+fn transcript<E: EC>(public: &ECpoint<E>, z: &Scalar<E>) -> Transcript {
+    let mut transcript = Transcript::new(b"ECDSA adaptor signature");
+    transcript.append_point(b"P", public);
+    transcript.append_scalar(b"z", z);
+    transcript
+}
+
+impl<E: Generator> EcdsaPreSignature<E> {
+    ///Pre-signs the SHA-256 digest of msg under the adaptor point. None for a zero
+    ///secret or adaptor at infinity.
+    pub fn sign(secret: Scalar<E>, msg: &[u8], adaptor: &ECpoint<E>) -> Option<Self> {
+        if secret.is_zero() || adaptor.is_infinity() {
+            return None
+        }
+        let z = bits_to_scalar(digest(&SHA256, msg).as_ref());
+        let public = E::generator() * secret;
+        loop {
+            let k = Scalar::generate_secret();
+            let nonce = *adaptor * k;
+            if nonce.is_infinity() {
+                continue
+            }
+            let r = Scalar::new(nonce.x().unwrap());
+            let s = (z + r * secret) / k;
+            if r.is_zero() || s.is_zero() {
+                continue
+            }
+            let proof = DleqProof::prove(&mut transcript(&public, &z), adaptor, k);
+            return Some(Self { nonce, nonce_g: E::generator() * k, s, proof })
+        }
+    }
+    ///Checks the DLEQ proof and s' * R' == z * G + r * P
+    pub fn verify(&self, public: &ECpoint<E>, msg: &[u8], adaptor: &ECpoint<E>) -> bool {
+        if self.nonce.is_infinity() || self.s.is_zero() || public.is_infinity() {
+            return false
+        }
+        let z = bits_to_scalar(digest(&SHA256, msg).as_ref());
+        let r = Scalar::new(self.nonce.x().unwrap());
+        !r.is_zero()
+            && self.proof.verify(&mut transcript(public, &z), adaptor, &self.nonce_g, &self.nonce)
+            && self.nonce_g * self.s == E::generator() * z + *public * r
+    }
+    ///The ECDSA signature (r, s' * t^-1)
+    pub fn complete(&self, adaptor_secret: Scalar<E>) -> Signature<E> {
+        Signature { r: Scalar::new(self.nonce.x().unwrap()), s: self.s / adaptor_secret }
+    }
+    ///t = s' * s^-1, or its negation if the signature was normalized to low s. None
+    ///if neither matches the adaptor.
+    pub fn extract(&self, signature: &Signature<E>, adaptor: &ECpoint<E>) -> Option<Scalar<E>> {
+        if signature.s.is_zero() || signature.r != Scalar::new(self.nonce.x().unwrap()) {
+            return None
+        }
+        let t = self.s / signature.s;
+        [t, -t].into_iter().find(|&t| E::generator() * t == *adaptor)
+    }
+}
@@ -0,0 +1,12 @@
+//!Adaptor signatures, the building block of scriptless scripts: a pre-signature is
+//!encrypted under an adaptor point T = t * G, anyone can check it would become a
+//!valid signature once t is added, and whoever sees both the pre-signature and the
+//!completed signature learns t. In an atomic swap, publishing the signature of one
+//!leg reveals the secret that completes the other. BIP340 Schnorr over secp256k1 and
+//!ECDSA over any curve, the latter with a DLEQ proof as in Fournier's one time
+//!verifiably encrypted signatures.
+pub use schnorr::SchnorrPreSignature;
+pub use ecdsa::EcdsaPreSignature;
+
+mod schnorr;
+mod ecdsa;
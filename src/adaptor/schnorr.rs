@@ -0,0 +1,65 @@
+use crate::{curves::Secp256k1, schnorr::bip340::{challenge, x_bytes}, types::{ECpoint, Scalar, U256, Generator}};
+
+///BIP340 pre-signature (R, s') with s' * G = R - T + e * P, where the final nonce
+///R has an even y and e = H_BIP0340/challenge(R.x || P.x || msg). Completed with t it
+///is the plain BIP340 signature R.x || s' + t.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchnorrPreSignature {
+    pub nonce: ECpoint<Secp256k1>,
+    pub s: Scalar<Secp256k1>,
+}
+
+impl SchnorrPreSignature {
+    ///Pre-signs msg under the adaptor point, with a random nonce k such that
+    ///R = k * G + T has an even y. None for a zero secret or adaptor at infinity.
+    pub fn sign(secret: Scalar<Secp256k1>, msg: &[u8], adaptor: &ECpoint<Secp256k1>) -> Option<Self> {
+        if secret.is_zero() || adaptor.is_infinity() {
+            return None
+        }
+        let g = Secp256k1::generator();
+        let public = g * secret;
+        let d = if public.has_even_y() { secret } else { -secret };
+        let (k, nonce) = loop {
+            let k = Scalar::generate_secret();
+            let nonce = g * k + *adaptor;
+            if !nonce.is_infinity() && nonce.has_even_y() {
+                break (k, nonce)
+            }
+        };
+        let s = k + challenge(&x_bytes(&nonce), &x_bytes(&public), msg) * d;
+        Some(Self { nonce, s })
+    }
+    ///Checks s' * G == R - T + e * P for the x-only public key
+    pub fn verify(&self, public_key: &[u8; 32], msg: &[u8], adaptor: &ECpoint<Secp256k1>) -> bool {
+        let Some(p) = ECpoint::<Secp256k1>::lift_x(U256::from_big_endian(public_key)) else {
+            return false
+        };
+        if self.nonce.is_infinity() || !self.nonce.has_even_y() {
+            return false
+        }
+        let e = challenge(&x_bytes(&self.nonce), public_key, msg);
+        Secp256k1::generator() * self.s == self.nonce - *adaptor + p * e
+    }
+    ///The BIP340 signature R.x || s' + t
+    pub fn complete(&self, adaptor_secret: Scalar<Secp256k1>) -> [u8; 64] {
+        let s = self.s + adaptor_secret;
+        [x_bytes(&self.nonce).to_vec(), s.to_be_bytes()].concat().try_into().unwrap()
+    }
+    ///t = s - s' from the completed signature, None if the signature does not
+    ///complete this pre-signature for the adaptor
+    pub fn extract(&self, signature: &[u8; 64], adaptor: &ECpoint<Secp256k1>) -> Option<Scalar<Secp256k1>> {
+        if signature[..32] != x_bytes(&self.nonce) {
+            return None
+        }
+        let t = Scalar::from_be_bytes(&signature[32..])? - self.s;
+        (Secp256k1::generator() * t == *adaptor).then_some(t)
+    }
+    ///R.x || s'
+    pub fn to_bytes(&self) -> [u8; 64] {
+        [x_bytes(&self.nonce).to_vec(), self.s.to_be_bytes()].concat().try_into().unwrap()
+    }
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<Self> {
+        let nonce = ECpoint::lift_x(U256::from_big_endian(&bytes[..32]))?;
+        Some(Self { nonce, s: Scalar::from_be_bytes(&bytes[32..])? })
+    }
+}
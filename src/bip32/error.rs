@@ -0,0 +1,32 @@
+///Reasons a BIP32 operation can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///the seed is not between 16 and 64 bytes
+    InvalidSeedLength,
+    ///I_L is not below N or the derived key is zero or infinity, the next index has to
+    ///be used instead, which happens with probability below 2^-127
+    InvalidKey,
+    ///hardened children need the private key
+    HardenedFromPublic,
+    ///the key is at depth 255
+    MaxDepth,
+    ///the derivation path is malformed
+    InvalidPath,
+    ///wrong Base58Check, length, version, key data or depth 0 with a parent
+    InvalidEncoding,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidSeedLength => write!(f, "seed must be 16 to 64 bytes"),
+            Error::InvalidKey => write!(f, "derived key is invalid"),
+            Error::HardenedFromPublic => write!(f, "cannot derive a hardened child from a public key"),
+            Error::MaxDepth => write!(f, "maximum depth reached"),
+            Error::InvalidPath => write!(f, "invalid derivation path"),
+            Error::InvalidEncoding => write!(f, "invalid extended key encoding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
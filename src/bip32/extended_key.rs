@@ -0,0 +1,175 @@
+use ring::hmac::{sign, Key, HMAC_SHA512};
+
+use crate::{bip32::{DerivationPath, Error, HARDENED}, curves::Secp256k1, types::{ECpoint, Scalar, Generator}, utils::{base58check_decode, base58check_encode, hash160}};
+
+const XPRV: [u8; 4] = [0x04, 0x88, 0xad, 0xe4];
+const XPUB: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+
+///Extended private key, the secret key with its chain code and its place in the tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedPrivateKey {
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub secret_key: Scalar<Secp256k1>,
+}
+
+///Extended public key, derives the normal children of the matching private key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedPublicKey {
+    pub depth: u8,
+    pub parent_fingerprint: [u8; 4],
+    pub child_number: u32,
+    pub chain_code: [u8; 32],
+    pub public_key: ECpoint<Secp256k1>,
+}
+
+///HMAC-SHA512 split into I_L, None if it is not below N, and I_R
+fn hmac_split(key: &[u8], data: &[u8]) -> (Option<Scalar<Secp256k1>>, [u8; 32]) {
+    let tag = sign(&Key::new(HMAC_SHA512, key), data);
+    let (il, ir) = tag.as_ref().split_at(32);
+    (Scalar::from_be_bytes(il), ir.try_into().unwrap())
+}
+
+///The first 4 bytes of HASH160 of the compressed public key
+fn fingerprint(public: &ECpoint<Secp256k1>) -> [u8; 4] {
+    hash160(&public.to_sec1(true))[..4].try_into().unwrap()
+}
+
+///version || depth || parent fingerprint || child number || chain code || key data
+fn encode(version: [u8; 4], depth: u8, parent: [u8; 4], child: u32, chain_code: &[u8; 32], key: &[u8]) -> String {
+    base58check_encode(&[version.as_slice(), &[depth], parent.as_slice(), &child.to_be_bytes(), chain_code, key].concat())
+}
+
+///The fields of a 78 byte serialization with the expected version, key data last
+#[allow(clippy::type_complexity)]
+fn decode(s: &str, version: [u8; 4]) -> Result<(u8, [u8; 4], u32, [u8; 32], Vec<u8>), Error> {
+    let data = base58check_decode(s).ok_or(Error::InvalidEncoding)?;
+    if data.len() != 78 || data[..4] != version {
+        return Err(Error::InvalidEncoding)
+    }
+    let (depth, parent) = (data[4], data[5..9].try_into().unwrap());
+    let child = u32::from_be_bytes(data[9..13].try_into().unwrap());
+    if depth == 0 && (parent != [0; 4] || child != 0) {
+        return Err(Error::InvalidEncoding)
+    }
+    Ok((depth, parent, child, data[13..45].try_into().unwrap(), data[45..].to_vec()))
+}
+
+impl ExtendedPrivateKey {
+    ///Master key, I = HMAC-SHA512("Bitcoin seed", seed) with I_L the key and I_R the
+    ///chain code
+    pub fn new_master(seed: &[u8]) -> Result<Self, Error> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(Error::InvalidSeedLength)
+        }
+        match hmac_split(b"Bitcoin seed", seed) {
+            (Some(secret_key), chain_code) if !secret_key.is_zero() =>
+                Ok(Self { depth: 0, parent_fingerprint: [0; 4], child_number: 0, chain_code, secret_key }),
+            _ => Err(Error::InvalidKey)
+        }
+    }
+    ///CKDpriv, I = HMAC-SHA512(c, 0x00 || k || i) for hardened i and
+    ///HMAC-SHA512(c, K || i) otherwise, the child key is I_L + k
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        let depth = self.depth.checked_add(1).ok_or(Error::MaxDepth)?;
+        let public = Secp256k1::generator() * self.secret_key;
+        let key = match index >= HARDENED {
+            true => [&[0], self.secret_key.to_be_bytes().as_slice()].concat(),
+            false => public.to_sec1(true)
+        };
+        match hmac_split(&self.chain_code, &[key.as_slice(), &index.to_be_bytes()].concat()) {
+            (Some(il), chain_code) if !(il + self.secret_key).is_zero() => Ok(Self {
+                depth,
+                parent_fingerprint: fingerprint(&public),
+                child_number: index,
+                chain_code,
+                secret_key: il + self.secret_key,
+            }),
+            _ => Err(Error::InvalidKey)
+        }
+    }
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.0.iter().try_fold(*self, |key, &index| key.derive_child(index))
+    }
+    ///N(), the extended public key of the same node
+    pub fn to_public(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+            chain_code: self.chain_code,
+            public_key: Secp256k1::generator() * self.secret_key,
+        }
+    }
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&(Secp256k1::generator() * self.secret_key))
+    }
+}
+
+impl ExtendedPublicKey {
+    ///CKDpub, K_i = I_L * G + K for I = HMAC-SHA512(c, K || i), normal children only
+    pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+        if index >= HARDENED {
+            return Err(Error::HardenedFromPublic)
+        }
+        let depth = self.depth.checked_add(1).ok_or(Error::MaxDepth)?;
+        let (il, chain_code) = hmac_split(&self.chain_code, &[self.public_key.to_sec1(true).as_slice(), &index.to_be_bytes()].concat());
+        let public_key = Secp256k1::generator() * il.ok_or(Error::InvalidKey)? + self.public_key;
+        if public_key.is_infinity() {
+            return Err(Error::InvalidKey)
+        }
+        Ok(Self { depth, parent_fingerprint: fingerprint(&self.public_key), child_number: index, chain_code, public_key })
+    }
+    ///Fails on hardened levels
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.0.iter().try_fold(*self, |key, &index| key.derive_child(index))
+    }
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key)
+    }
+    ///HASH160 of the compressed public key, the fingerprint is its first 4 bytes
+    pub fn identifier(&self) -> [u8; 20] {
+        hash160(&self.public_key.to_sec1(true))
+    }
+}
+
+impl std::fmt::Display for ExtendedPrivateKey {
+    ///xprv..., the key data is 0x00 || k
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = [&[0], self.secret_key.to_be_bytes().as_slice()].concat();
+        write!(f, "{}", encode(XPRV, self.depth, self.parent_fingerprint, self.child_number, &self.chain_code, &key))
+    }
+}
+
+impl std::fmt::Display for ExtendedPublicKey {
+    ///xpub..., the key data is the compressed public key
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", encode(XPUB, self.depth, self.parent_fingerprint, self.child_number, &self.chain_code, &self.public_key.to_sec1(true)))
+    }
+}
+
+impl std::str::FromStr for ExtendedPrivateKey {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (depth, parent_fingerprint, child_number, chain_code, key) = decode(s, XPRV)?;
+        match (key[0], Scalar::from_be_bytes(&key[1..])) {
+            (0, Some(secret_key)) if !secret_key.is_zero() => Ok(Self { depth, parent_fingerprint, child_number, chain_code, secret_key }),
+            _ => Err(Error::InvalidEncoding)
+        }
+    }
+}
+
+impl std::str::FromStr for ExtendedPublicKey {
+    type Err = Error;
+    ///The key has to be a compressed point on the curve
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (depth, parent_fingerprint, child_number, chain_code, key) = decode(s, XPUB)?;
+        let public_key = match key[0] {
+            2 | 3 => ECpoint::from_sec1(&key).ok_or(Error::InvalidEncoding)?,
+            _ => return Err(Error::InvalidEncoding)
+        };
+        Ok(Self { depth, parent_fingerprint, child_number, chain_code, public_key })
+    }
+}
@@ -0,0 +1,11 @@
+//!BIP32 hierarchical deterministic keys for secp256k1: a master key from a seed,
+//!hardened and normal child derivation of private keys, normal derivation of public
+//!keys, derivation paths like m/84'/0'/0'/0/1 and the Base58Check xprv / xpub
+//!encoding of mainnet.
+pub use error::Error;
+pub use path::{DerivationPath, HARDENED};
+pub use extended_key::{ExtendedPrivateKey, ExtendedPublicKey};
+
+mod error;
+mod path;
+mod extended_key;
@@ -0,0 +1,48 @@
+use crate::bip32::Error;
+
+///Child numbers from HARDENED on are hardened, written with ' as i'
+pub const HARDENED: u32 = 1 << 31;
+
+///Path of child numbers from the master key
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DerivationPath(pub Vec<u32>);
+
+impl std::str::FromStr for DerivationPath {
+    type Err = Error;
+    ///"m" followed by "/i" or "/i'" for every level, h or H can replace '
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut parts = s.split('/');
+        if parts.next() != Some("m") {
+            return Err(Error::InvalidPath)
+        }
+        parts
+            .map(|part| {
+                let (index, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                    Some(index) => (index, HARDENED),
+                    None => (part, 0)
+                };
+                if index.is_empty() || !index.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(Error::InvalidPath)
+                }
+                match index.parse::<u32>() {
+                    Ok(index) if index < HARDENED => Ok(index | hardened),
+                    _ => Err(Error::InvalidPath)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(DerivationPath)
+    }
+}
+
+impl std::fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "m")?;
+        for &index in &self.0 {
+            match index >= HARDENED {
+                true => write!(f, "/{}'", index - HARDENED)?,
+                false => write!(f, "/{}", index)?
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{bitcoin::{bech32::convert_bits, bech32_decode, bech32_encode, Error, Network, Variant}, curves::Secp256k1, schnorr::bip340::x_bytes, taproot::tweak_public_key, types::{ECpoint, U256}, utils::{base58check_decode, base58check_encode, hash160}};
+
+///What an address pays to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Payload {
+    ///HASH160 of the SEC1 public key
+    P2pkh([u8; 20]),
+    ///HASH160 of the compressed public key, segwit version 0
+    P2wpkh([u8; 20]),
+    ///x-only output key, segwit version 1
+    P2tr([u8; 32]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Address {
+    pub network: Network,
+    pub payload: Payload,
+}
+
+impl Address {
+    pub fn p2pkh(public_key: &ECpoint<Secp256k1>, compressed: bool, network: Network) -> Self {
+        Self { network, payload: Payload::P2pkh(hash160(&public_key.to_sec1(compressed))) }
+    }
+    pub fn p2wpkh(public_key: &ECpoint<Secp256k1>, network: Network) -> Self {
+        Self { network, payload: Payload::P2wpkh(hash160(&public_key.to_sec1(true))) }
+    }
+    ///Pays to the internal key tweaked with the merkle root of the script tree, or
+    ///without one for key path only spending as in BIP86. None if the tweak fails.
+    pub fn p2tr(internal_key: &ECpoint<Secp256k1>, merkle_root: Option<&[u8; 32]>, network: Network) -> Option<Self> {
+        let output_key = tweak_public_key(internal_key, merkle_root)?;
+        Some(Self { network, payload: Payload::P2tr(x_bytes(&output_key)) })
+    }
+    ///The scriptPubKey of the output
+    pub fn script_pubkey(&self) -> Vec<u8> {
+        match &self.payload {
+            Payload::P2pkh(hash) => [&[0x76, 0xa9, 0x14], hash.as_slice(), &[0x88, 0xac]].concat(),
+            Payload::P2wpkh(hash) => [&[0x00, 0x14], hash.as_slice()].concat(),
+            Payload::P2tr(key) => [&[0x51, 0x20], key.as_slice()].concat(),
+        }
+    }
+}
+
+///Segwit address of BIP173 and BIP350, the witness version and the program. The
+///human readable part must be the one of the network.
+fn decode_segwit(s: &str, network: Network) -> Result<Payload, Error> {
+    let (hrp, data, variant) = bech32_decode(s, 90)?;
+    if hrp != network.hrp() {
+        return Err(Error::InvalidVersion)
+    }
+    let (&version, program) = data.split_first().ok_or(Error::InvalidLength)?;
+    let program = convert_bits(program, 5, 8, false).ok_or(Error::InvalidBech32)?;
+    if version > 16 {
+        return Err(Error::InvalidVersion)
+    }
+    if !(2..=40).contains(&program.len()) || (version == 0 && ![20, 32].contains(&program.len())) {
+        return Err(Error::InvalidLength)
+    }
+    if (version == 0) != (variant == Variant::Bech32) {
+        return Err(Error::InvalidBech32)
+    }
+    match (version, program.len()) {
+        (0, 20) => Ok(Payload::P2wpkh(program.try_into().unwrap())),
+        (1, 32) => ECpoint::<Secp256k1>::lift_x(U256::from_big_endian(&program))
+            .map(|_| Payload::P2tr(program.try_into().unwrap()))
+            .ok_or(Error::InvalidKey),
+        _ => Err(Error::UnsupportedAddress)
+    }
+}
+
+impl std::fmt::Display for Address {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let segwit = |version: u8, program: &[u8], variant| {
+            let data = [vec![version], convert_bits(program, 8, 5, true).unwrap()].concat();
+            bech32_encode(self.network.hrp(), &data, variant)
+        };
+        let encoded = match &self.payload {
+            Payload::P2pkh(hash) => base58check_encode(&[&[self.network.p2pkh_prefix()], hash.as_slice()].concat()),
+            Payload::P2wpkh(hash) => segwit(0, hash, Variant::Bech32),
+            Payload::P2tr(key) => segwit(1, key, Variant::Bech32m),
+        };
+        write!(f, "{}", encoded)
+    }
+}
+
+impl std::str::FromStr for Address {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let lowercase = s.to_lowercase();
+        if let Some(network) = Network::all().into_iter().find(|n| lowercase.starts_with(&format!("{}1", n.hrp()))) {
+            return Ok(Self { network, payload: decode_segwit(s, network)? })
+        }
+        //valid Bech32 with the human readable part of no network
+        let data = base58check_decode(s).ok_or_else(|| match bech32_decode(s, 90) {
+            Ok(_) => Error::InvalidVersion,
+            Err(_) => Error::InvalidBase58Check,
+        })?;
+        match Network::all().into_iter().find(|n| Some(&n.p2pkh_prefix()) == data.first()) {
+            Some(network) if data.len() == 21 => Ok(Self { network, payload: Payload::P2pkh(data[1..].try_into().unwrap()) }),
+            Some(_) => Err(Error::InvalidLength),
+            //P2SH
+            None if matches!(data.first(), Some(0x05 | 0xc4)) => Err(Error::UnsupportedAddress),
+            None => Err(Error::InvalidVersion)
+        }
+    }
+}
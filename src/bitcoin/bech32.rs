@@ -0,0 +1,91 @@
+use crate::bitcoin::Error;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+///Bech32 of BIP173, or Bech32m of BIP350 with its other checksum constant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+impl Variant {
+    fn constant(&self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc830a3,
+        }
+    }
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    values.fold(1, |checksum, value| {
+        let top = checksum >> 25;
+        (0..5).fold(((checksum & 0x1ffffff) << 5) ^ value as u32, |c, i| match (top >> i) & 1 {
+            1 => c ^ GENERATOR[i],
+            _ => c
+        })
+    })
+}
+
+///High bits of every character, a zero, then the low bits
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes().map(|c| c >> 5).chain([0]).chain(hrp.bytes().map(|c| c & 31))
+}
+
+///Regroups bits, e.g. bytes into 5 bit groups. Without padding the leftover bits
+///have to be fewer than from and zero.
+pub(crate) fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let (mut acc, mut bits, mut out) = (0u32, 0, vec![]);
+    for &value in data {
+        if (value as u32) >> from != 0 {
+            return None
+        }
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & ((1 << to) - 1)) as u8);
+        }
+    }
+    match (pad, bits) {
+        (true, 0) => {}
+        (true, _) => out.push(((acc << (to - bits)) & ((1 << to) - 1)) as u8),
+        (false, _) if bits >= from || (acc << (to - bits)) & ((1 << to) - 1) != 0 => return None,
+        _ => {}
+    }
+    Some(out)
+}
+
+///hrp || "1" || data || checksum, data in 5 bit groups
+pub fn bech32_encode(hrp: &str, data: &[u8], variant: Variant) -> String {
+    let hrp = hrp.to_lowercase();
+    let checksum = polymod(hrp_expand(&hrp).chain(data.iter().copied()).chain([0; 6])) ^ variant.constant();
+    let checksum = (0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8);
+    let data = data.iter().copied().chain(checksum).map(|d| CHARSET[d as usize] as char).collect::<String>();
+    format!("{}1{}", hrp, data)
+}
+
+///The lowercase human readable part, the 5 bit groups without the checksum and the
+///variant of the checksum. Strings longer than max_len are refused, 90 for segwit.
+pub fn bech32_decode(s: &str, max_len: usize) -> Result<(String, Vec<u8>, Variant), Error> {
+    if s.len() > max_len || (s.to_lowercase() != s && s.to_uppercase() != s) {
+        return Err(Error::InvalidBech32)
+    }
+    let s = s.to_lowercase();
+    let (hrp, data) = s.rsplit_once('1').ok_or(Error::InvalidBech32)?;
+    if hrp.is_empty() || data.len() < 6 || !hrp.bytes().all(|c| (33..=126).contains(&c)) {
+        return Err(Error::InvalidBech32)
+    }
+    let data = data.bytes()
+        .map(|c| CHARSET.iter().position(|&x| x == c).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or(Error::InvalidBech32)?;
+    let variant = match polymod(hrp_expand(hrp).chain(data.iter().copied())) {
+        1 => Variant::Bech32,
+        0x2bc830a3 => Variant::Bech32m,
+        _ => return Err(Error::InvalidBech32)
+    };
+    Ok((hrp.to_string(), data[..data.len() - 6].to_vec(), variant))
+}
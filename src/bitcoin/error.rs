@@ -0,0 +1,31 @@
+///Reasons decoding a key or an address can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///bad Base58 characters or checksum
+    InvalidBase58Check,
+    ///bad Bech32 characters, case, length or checksum
+    InvalidBech32,
+    ///unknown version byte, human readable part or witness version
+    InvalidVersion,
+    ///the payload has the wrong length
+    InvalidLength,
+    ///the scalar is zero or not below N, or the point is not on the curve
+    InvalidKey,
+    ///a valid encoding of a kind of address that is not supported
+    UnsupportedAddress,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidBase58Check => write!(f, "invalid Base58Check encoding"),
+            Error::InvalidBech32 => write!(f, "invalid Bech32 encoding"),
+            Error::InvalidVersion => write!(f, "invalid version or prefix"),
+            Error::InvalidLength => write!(f, "invalid payload length"),
+            Error::InvalidKey => write!(f, "invalid key"),
+            Error::UnsupportedAddress => write!(f, "unsupported address type"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,17 @@
+//!Bitcoin encodings of keys and addresses: WIF private keys, Base58Check P2PKH,
+//!Bech32 P2WPKH (BIP173), Bech32m P2TR with the BIP341 key path tweak (BIP350) and
+//!silent payment addresses (BIP352). Decoding checks checksums, versions, lengths
+//!and that keys are valid scalars or points on the curve.
+pub use error::Error;
+pub use network::Network;
+pub use bech32::{bech32_decode, bech32_encode, Variant};
+pub use private_key::PrivateKey;
+pub use address::{Address, Payload};
+pub use silent_payment::SilentPaymentAddress;
+
+mod error;
+mod network;
+mod bech32;
+mod private_key;
+mod address;
+mod silent_payment;
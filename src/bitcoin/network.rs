@@ -0,0 +1,38 @@
+///Mainnet or testnet, they differ in the version bytes and human readable parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+impl Network {
+    pub fn wif_prefix(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            Network::Testnet => 0xef,
+        }
+    }
+    pub fn p2pkh_prefix(&self) -> u8 {
+        match self {
+            Network::Mainnet => 0x00,
+            Network::Testnet => 0x6f,
+        }
+    }
+    ///Human readable part of segwit addresses
+    pub fn hrp(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "bc",
+            Network::Testnet => "tb",
+        }
+    }
+    ///Human readable part of silent payment addresses
+    pub fn silent_payment_hrp(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "sp",
+            Network::Testnet => "tsp",
+        }
+    }
+    pub(crate) fn all() -> [Network; 2] {
+        [Network::Mainnet, Network::Testnet]
+    }
+}
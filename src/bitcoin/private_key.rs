@@ -0,0 +1,48 @@
+use crate::{bitcoin::{Error, Network}, curves::Secp256k1, types::{ECpoint, Scalar, Generator}, utils::{base58check_decode, base58check_encode}};
+
+///Private key with the network and whether its public key is used compressed, as
+///encoded in WIF
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivateKey {
+    pub secret: Scalar<Secp256k1>,
+    pub network: Network,
+    pub compressed: bool,
+}
+
+impl PrivateKey {
+    pub fn new(secret: Scalar<Secp256k1>, network: Network) -> Self {
+        Self { secret, network, compressed: true }
+    }
+    pub fn public_key(&self) -> ECpoint<Secp256k1> {
+        Secp256k1::generator() * self.secret
+    }
+    ///SEC1 public key, 33 bytes compressed or 65 bytes
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        self.public_key().to_sec1(self.compressed)
+    }
+}
+
+impl std::fmt::Display for PrivateKey {
+    ///WIF, Base58Check of prefix || k, with a trailing 0x01 for compressed keys
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let suffix: &[u8] = if self.compressed { &[0x01] } else { &[] };
+        write!(f, "{}", base58check_encode(&[&[self.network.wif_prefix()], self.secret.to_be_bytes().as_slice(), suffix].concat()))
+    }
+}
+
+impl std::str::FromStr for PrivateKey {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let data = base58check_decode(s).ok_or(Error::InvalidBase58Check)?;
+        let network = Network::all().into_iter().find(|n| Some(&n.wif_prefix()) == data.first()).ok_or(Error::InvalidVersion)?;
+        let compressed = match data.len() {
+            33 => false,
+            34 if data[33] == 0x01 => true,
+            _ => return Err(Error::InvalidLength)
+        };
+        match Scalar::from_be_bytes(&data[1..33]) {
+            Some(secret) if !secret.is_zero() => Ok(Self { secret, network, compressed }),
+            _ => Err(Error::InvalidKey)
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{bitcoin::{bech32::convert_bits, bech32_decode, bech32_encode, Error, Network, Variant}, curves::Secp256k1, types::ECpoint};
+
+///BIP352 silent payment address, the scan key B_scan and the spend key B_spend the
+///sender tweaks into a fresh output key for every payment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SilentPaymentAddress {
+    pub network: Network,
+    pub scan_key: ECpoint<Secp256k1>,
+    pub spend_key: ECpoint<Secp256k1>,
+}
+
+impl std::fmt::Display for SilentPaymentAddress {
+    ///Bech32m of version 0 and the compressed keys, sp1q... on mainnet
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys = [self.scan_key.to_sec1(true), self.spend_key.to_sec1(true)].concat();
+        let data = [vec![0], convert_bits(&keys, 8, 5, true).unwrap()].concat();
+        write!(f, "{}", bech32_encode(self.network.silent_payment_hrp(), &data, Variant::Bech32m))
+    }
+}
+
+impl std::str::FromStr for SilentPaymentAddress {
+    type Err = Error;
+    ///Version 0 has exactly the two keys, later versions up to 30 may append data
+    ///that is ignored, version 31 is reserved
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (hrp, data, variant) = bech32_decode(s, 1023)?;
+        let network = Network::all().into_iter().find(|n| n.silent_payment_hrp() == hrp).ok_or(Error::InvalidVersion)?;
+        let (&version, data) = data.split_first().ok_or(Error::InvalidLength)?;
+        if variant != Variant::Bech32m {
+            return Err(Error::InvalidBech32)
+        }
+        if version == 31 {
+            return Err(Error::InvalidVersion)
+        }
+        let keys = convert_bits(data, 5, 8, false).ok_or(Error::InvalidBech32)?;
+        if keys.len() < 66 || (version == 0 && keys.len() != 66) {
+            return Err(Error::InvalidLength)
+        }
+        let key = |bytes: &[u8]| match bytes[0] {
+            2 | 3 => ECpoint::from_sec1(bytes).ok_or(Error::InvalidKey),
+            _ => Err(Error::InvalidKey)
+        };
+        Ok(Self { network, scan_key: key(&keys[..33])?, spend_key: key(&keys[33..66])? })
+    }
+}
@@ -0,0 +1,14 @@
+//!Blind Schnorr signatures: the user gets a Schnorr signature (R', s') on a message
+//!the signer never sees, and the signer cannot link it to the session it signed in.
+//!The signer commits to R = k * G, the user blinds it to R' = R + alpha * G + beta * X
+//!and sends c = H(R', X, m) + beta, the signer answers s = k + c * x and the user
+//!unblinds s' = s + alpha. Sessions are typestates consumed by their last step, so a
+//!nonce cannot answer two challenges. Running many sessions concurrently is open to
+//!the ROS attack (Benhamouda et al. 2021), sign one session at a time.
+pub use signature::Signature;
+pub use signer::{Signer, SignerSession};
+pub use user::UserSession;
+
+mod signature;
+mod signer;
+mod user;
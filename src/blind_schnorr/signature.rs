@@ -0,0 +1,31 @@
+use crate::{types::{ECpoint, Scalar, Generator, EC}, utils::hash_to_scalar};
+
+///Schnorr signature (R, s) with s * G = R + H(R, X, m) * X
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature<E: EC> {
+    pub r: ECpoint<E>,
+    pub s: Scalar<E>,
+}
+
+///H(R || X || m) with compressed points
+pub(crate) fn challenge<E: EC>(r: &ECpoint<E>, public: &ECpoint<E>, msg: &[u8]) -> Scalar<E> {
+    hash_to_scalar(&[r.to_sec1(true), public.to_sec1(true), msg.to_vec()].concat(), b"blind schnorr challenge")
+}
+
+impl<E: Generator> Signature<E> {
+    pub fn verify(&self, public: &ECpoint<E>, msg: &[u8]) -> bool {
+        !self.r.is_infinity() && !public.is_infinity()
+            && E::generator() * self.s == self.r + *public * challenge(&self.r, public, msg)
+    }
+    ///Compressed R || s
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.r.to_sec1(true), self.s.to_be_bytes()].concat()
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let point_len = 1 + E::P.bits().div_ceil(8);
+        if bytes.len() != point_len + E::N.bits().div_ceil(8) {
+            return None
+        }
+        Some(Self { r: ECpoint::from_sec1(&bytes[..point_len])?, s: Scalar::from_be_bytes(&bytes[point_len..])? })
+    }
+}
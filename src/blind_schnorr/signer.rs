@@ -0,0 +1,49 @@
+use crate::types::{ECpoint, Scalar, Generator, EC};
+
+///Signer holding the secret key x
+#[derive(Clone, PartialEq, Eq)]
+pub struct Signer<E: EC> {
+    secret: Scalar<E>,
+}
+
+///Open signing session holding the nonce k. Neither Clone nor Copy, answering a
+///challenge consumes it.
+#[derive(PartialEq, Eq)]
+pub struct SignerSession<E: EC> {
+    secret: Scalar<E>,
+    nonce: Scalar<E>,
+}
+
+impl<E: EC> std::fmt::Debug for Signer<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Signer [curve {}]", E::NAME)
+    }
+}
+
+impl<E: EC> std::fmt::Debug for SignerSession<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SignerSession [curve {}]", E::NAME)
+    }
+}
+
+impl<E: Generator> Signer<E> {
+    ///None for a zero secret
+    pub fn new(secret: Scalar<E>) -> Option<Self> {
+        (!secret.is_zero()).then_some(Self { secret })
+    }
+    pub fn public_key(&self) -> ECpoint<E> {
+        E::generator() * self.secret
+    }
+    ///Starts a session with a fresh nonce k, returns it with R = k * G for the user
+    pub fn commit(&self) -> (SignerSession<E>, ECpoint<E>) {
+        let nonce = Scalar::generate_secret();
+        (SignerSession { secret: self.secret, nonce }, E::generator() * nonce)
+    }
+}
+
+impl<E: EC> SignerSession<E> {
+    ///s = k + c * x for the user's blinded challenge, ends the session
+    pub fn respond(self, challenge: Scalar<E>) -> Scalar<E> {
+        self.nonce + challenge * self.secret
+    }
+}
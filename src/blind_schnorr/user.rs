@@ -0,0 +1,34 @@
+use crate::{blind_schnorr::{signature::challenge, Signature}, types::{ECpoint, Scalar, Generator, EC}};
+
+///User side of a session, holding the blinding factors alpha and the blinded nonce R'
+///until the signer's response arrives. Neither Clone nor Copy, unblinding consumes it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UserSession<E: EC> {
+    public: ECpoint<E>,
+    alpha: Scalar<E>,
+    nonce: ECpoint<E>,
+    msg: Vec<u8>,
+}
+
+impl<E: Generator> UserSession<E> {
+    ///Blinds the signer's commitment R with random alpha and beta, returns the session
+    ///with the challenge c = H(R', X, m) + beta for the signer. None if R or X is
+    ///infinity.
+    pub fn blind(public: &ECpoint<E>, commitment: &ECpoint<E>, msg: &[u8]) -> Option<(Self, Scalar<E>)> {
+        if public.is_infinity() || commitment.is_infinity() {
+            return None
+        }
+        let (alpha, beta) = (Scalar::generate_secret(), Scalar::generate_secret());
+        let nonce = *commitment + E::generator() * alpha + *public * beta;
+        if nonce.is_infinity() {
+            return None
+        }
+        let c = challenge(&nonce, public, msg);
+        Some((Self { public: *public, alpha, nonce, msg: msg.to_vec() }, c + beta))
+    }
+    ///The signature (R', s + alpha), None if the response does not make a valid one
+    pub fn unblind(self, response: Scalar<E>) -> Option<Signature<E>> {
+        let signature = Signature { r: self.nonce, s: response + self.alpha };
+        signature.verify(&self.public, &self.msg).then_some(signature)
+    }
+}
@@ -0,0 +1,28 @@
+///Reasons a range proof can not be created
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///the bit size is not 8, 16, 32 or 64
+    InvalidBitsize,
+    ///the number of values is not a power of two
+    InvalidAggregation,
+    ///there are fewer generators than bits to prove
+    InvalidGeneratorsLength,
+    ///not one blinding factor per value
+    WrongNumBlindingFactors,
+    ///a value does not fit into the bit size
+    ValueOutOfRange,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidBitsize => write!(f, "bit size is not 8, 16, 32 or 64"),
+            Error::InvalidAggregation => write!(f, "number of values is not a power of two"),
+            Error::InvalidGeneratorsLength => write!(f, "not enough generators"),
+            Error::WrongNumBlindingFactors => write!(f, "wrong number of blinding factors"),
+            Error::ValueOutOfRange => write!(f, "value out of range"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,44 @@
+use crate::{types::{ECpoint, Scalar, Generator, EC}, utils::hash_to_point};
+
+///The bases of Pedersen commitments v * B + r * B_blinding. B is the generator G,
+///B_blinding is hashed to the curve so nobody knows its discrete log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PedersenGens<E: EC> {
+    pub b: ECpoint<E>,
+    pub b_blinding: ECpoint<E>,
+}
+
+impl<E: Generator> Default for PedersenGens<E> {
+    fn default() -> Self {
+        Self { b: E::generator(), b_blinding: hash_to_point(b"Bulletproofs blinding generator") }
+    }
+}
+
+impl<E: EC> PedersenGens<E> {
+    pub fn commit(&self, value: Scalar<E>, blinding: Scalar<E>) -> ECpoint<E> {
+        self.b * value + self.b_blinding * blinding
+    }
+}
+
+///The vectors G and H of independent generators the bits are committed with,
+///G_i and H_i are hashed to the curve from their index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulletproofGens<E: EC> {
+    pub g_vec: Vec<ECpoint<E>>,
+    pub h_vec: Vec<ECpoint<E>>,
+}
+
+impl<E: EC> BulletproofGens<E> {
+    ///Enough generators for proofs of up to capacity bits in total, e.g. 128 for
+    ///two aggregated 64 bit range proofs
+    pub fn new(capacity: usize) -> Self {
+        let generator = |label: &[u8], i: usize| hash_to_point(&[label, &(i as u32).to_be_bytes()].concat());
+        Self {
+            g_vec: (0..capacity).map(|i| generator(b"Bulletproofs G", i)).collect(),
+            h_vec: (0..capacity).map(|i| generator(b"Bulletproofs H", i)).collect(),
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.g_vec.len()
+    }
+}
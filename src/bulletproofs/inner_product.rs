@@ -0,0 +1,117 @@
+use crate::{proofs::Transcript, types::{ECpoint, Scalar, EC}, utils::multiscalar_mul};
+
+pub(crate) fn inner_product<E: EC>(a: &[Scalar<E>], b: &[Scalar<E>]) -> Scalar<E> {
+    a.iter().zip(b).map(|(&a, &b)| a * b).sum()
+}
+
+///Proof of knowledge of vectors a and b with P = <a, G> + <b, H> + <a, b> * Q in
+///2 log n points. Every round halves the vectors, committing to the cross terms
+///L and R and folding with the challenge u.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InnerProductProof<E: EC> {
+    pub l_vec: Vec<ECpoint<E>>,
+    pub r_vec: Vec<ECpoint<E>>,
+    pub a: Scalar<E>,
+    pub b: Scalar<E>,
+}
+
+impl<E: EC> InnerProductProof<E> {
+    ///Proves P = <a, G> + <b, H'> + <a, b> * Q for H'_i = h_factors_i * H_i, the
+    ///factors are folded in without computing H'. The length n has to be a power of
+    ///two, panics otherwise.
+    pub fn prove(
+        transcript: &mut Transcript,
+        q: &ECpoint<E>,
+        h_factors: &[Scalar<E>],
+        mut g: Vec<ECpoint<E>>,
+        mut h: Vec<ECpoint<E>>,
+        mut a: Vec<Scalar<E>>,
+        mut b: Vec<Scalar<E>>,
+    ) -> Self {
+        let mut n = g.len();
+        assert!(n.is_power_of_two() && [h.len(), a.len(), b.len(), h_factors.len()].iter().all(|&l| l == n));
+        transcript.append_message(b"proof", b"inner-product");
+        transcript.append_u64(b"n", n as u64);
+        let mut h_factors = h_factors.to_vec();
+        let (mut l_vec, mut r_vec) = (vec![], vec![]);
+        while n > 1 {
+            n /= 2;
+            let (a_lo, a_hi) = a.split_at(n);
+            let (b_lo, b_hi) = b.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+            let (h_lo, h_hi) = h.split_at(n);
+            let (f_lo, f_hi) = h_factors.split_at(n);
+            let scaled = |b: &[Scalar<E>], f: &[Scalar<E>]| b.iter().zip(f).map(|(&b, &f)| b * f).collect::<Vec<_>>();
+            let l = multiscalar_mul(
+                &[a_lo, &scaled(b_hi, f_lo), &[inner_product(a_lo, b_hi)]].concat(),
+                &[g_hi, h_lo, &[*q]].concat()
+            );
+            let r = multiscalar_mul(
+                &[a_hi, &scaled(b_lo, f_hi), &[inner_product(a_hi, b_lo)]].concat(),
+                &[g_lo, h_hi, &[*q]].concat()
+            );
+            transcript.append_point(b"L", &l);
+            transcript.append_point(b"R", &r);
+            let u = transcript.challenge_scalar::<E>(b"u");
+            let u_inv = Scalar::one() / u;
+            a = (0..n).map(|i| a_lo[i] * u + a_hi[i] * u_inv).collect();
+            b = (0..n).map(|i| b_lo[i] * u_inv + b_hi[i] * u).collect();
+            g = (0..n).map(|i| multiscalar_mul(&[u_inv, u], &[g_lo[i], g_hi[i]])).collect();
+            h = (0..n).map(|i| multiscalar_mul(&[u * f_lo[i], u_inv * f_hi[i]], &[h_lo[i], h_hi[i]])).collect();
+            h_factors = vec![Scalar::one(); n];
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+        Self { l_vec, r_vec, a: a[0], b: b[0] }
+    }
+    ///The squared challenges u_j^2, their inverses and s_i, the product of u_j for
+    ///the rounds i was in the upper half and u_j^-1 for the others, so the folded
+    ///generators are <s, G> and <s^-1, H>. None if the proof does not fit n.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn verification_scalars(&self, n: usize, transcript: &mut Transcript)
+    -> Option<(Vec<Scalar<E>>, Vec<Scalar<E>>, Vec<Scalar<E>>)> {
+        let rounds = self.l_vec.len();
+        //compares the exponent, 1 << rounds overflows for a malformed proof
+        if !n.is_power_of_two() || n.trailing_zeros() as usize != rounds || self.r_vec.len() != rounds {
+            return None
+        }
+        transcript.append_message(b"proof", b"inner-product");
+        transcript.append_u64(b"n", n as u64);
+        let challenges = self.l_vec.iter().zip(&self.r_vec)
+            .map(|(l, r)| {
+                transcript.append_point(b"L", l);
+                transcript.append_point(b"R", r);
+                transcript.challenge_scalar::<E>(b"u")
+            })
+            .collect::<Vec<_>>();
+        let inverses = challenges.iter().map(|&u| Scalar::one() / u).collect::<Vec<_>>();
+        let s = (0..n)
+            .map(|i| (0..rounds)
+                .map(|j| match (i >> (rounds - 1 - j)) & 1 {
+                    1 => challenges[j],
+                    _ => inverses[j]
+                })
+                .fold(Scalar::one(), |acc, x| acc * x))
+            .collect();
+        let square = |v: &[Scalar<E>]| v.iter().map(|&u| u * u).collect::<Vec<_>>();
+        Some((square(&challenges), square(&inverses), s))
+    }
+    ///Checks P == a * <s, G> + b * <s^-1, H> + a * b * Q - sum of u_j^2 * L_j + u_j^-2 * R_j
+    pub fn verify(&self, transcript: &mut Transcript, q: &ECpoint<E>, p: &ECpoint<E>, g: &[ECpoint<E>], h: &[ECpoint<E>]) -> bool {
+        let Some((u_sq, u_inv_sq, s)) = self.verification_scalars(g.len(), transcript) else {
+            return false
+        };
+        if h.len() != g.len() {
+            return false
+        }
+        let scalars = [
+            s.iter().map(|&s| self.a * s).collect::<Vec<_>>(),
+            s.iter().map(|&s| self.b / s).collect(),
+            vec![self.a * self.b],
+            u_sq.iter().map(|&u| -u).collect(),
+            u_inv_sq.iter().map(|&u| -u).collect(),
+        ].concat();
+        let points = [g, h, &[*q], &self.l_vec, &self.r_vec].concat();
+        multiscalar_mul(&scalars, &points) == *p
+    }
+}
@@ -0,0 +1,12 @@
+//!Bulletproofs range proofs for Pedersen commitments, single and aggregated, with
+//!the logarithmic inner product argument they are built on. Proofs are made
+//!non-interactive with the Fiat-Shamir transcript of the proofs module.
+pub use error::Error;
+pub use generators::{BulletproofGens, PedersenGens};
+pub use inner_product::InnerProductProof;
+pub use range_proof::RangeProof;
+
+mod error;
+mod generators;
+mod inner_product;
+mod range_proof;
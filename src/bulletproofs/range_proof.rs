@@ -0,0 +1,235 @@
+use crate::{bulletproofs::{inner_product::inner_product, BulletproofGens, Error, InnerProductProof, PedersenGens}, proofs::Transcript, types::{ECpoint, Scalar, EC}, utils::multiscalar_mul};
+
+///1, x, x^2, ..., x^(n-1)
+fn powers<E: EC>(x: Scalar<E>, n: usize) -> Vec<Scalar<E>> {
+    std::iter::successors(Some(Scalar::one()), |&p| Some(p * x)).take(n).collect()
+}
+
+fn random_vec<E: EC>(n: usize) -> Vec<Scalar<E>> {
+    (0..n).map(|_| Scalar::generate_secret()).collect()
+}
+
+///Bulletproofs range proof (Buenz et al. 2018) that m Pedersen commitments
+///V_j = v_j * B + gamma_j * B_blinding hold values in [0, 2^n), without revealing
+///them. Logarithmic in size, 2 log(n * m) + 4 points and 5 scalars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeProof<E: EC> {
+    ///commitment to the bits a_L and a_R = a_L - 1
+    pub a: ECpoint<E>,
+    ///commitment to the blinding vectors s_L and s_R
+    pub s: ECpoint<E>,
+    ///commitments to the coefficients t_1 and t_2 of t(X) = <l(X), r(X)>
+    pub t1: ECpoint<E>,
+    pub t2: ECpoint<E>,
+    ///t(x) and its blinding factor
+    pub t_x: Scalar<E>,
+    pub t_x_blinding: Scalar<E>,
+    ///blinding factor of A + x * S
+    pub e_blinding: Scalar<E>,
+    ///proof that <l(x), r(x)> = t(x)
+    pub ipp_proof: InnerProductProof<E>,
+}
+
+///Checks the parameters shared by the prover and the verifier
+fn check_parameters<E: EC>(bp_gens: &BulletproofGens<E>, n: usize, m: usize) -> Result<(), Error> {
+    if ![8, 16, 32, 64].contains(&n) {
+        return Err(Error::InvalidBitsize)
+    }
+    if !m.is_power_of_two() {
+        return Err(Error::InvalidAggregation)
+    }
+    if bp_gens.capacity() < n * m {
+        return Err(Error::InvalidGeneratorsLength)
+    }
+    Ok(())
+}
+
+///z^2, z^3, ..., the weights of the m values
+fn value_weights<E: EC>(z: Scalar<E>, m: usize) -> Vec<Scalar<E>> {
+    powers(z, m + 2)[2..].to_vec()
+}
+
+impl<E: EC> RangeProof<E> {
+    ///Proves value is in [0, 2^n) and returns the proof with the commitment
+    pub fn prove_single(
+        bp_gens: &BulletproofGens<E>,
+        pc_gens: &PedersenGens<E>,
+        transcript: &mut Transcript,
+        value: u64,
+        blinding: Scalar<E>,
+        n: usize,
+    ) -> Result<(Self, ECpoint<E>), Error> {
+        let (proof, commitments) = Self::prove_multiple(bp_gens, pc_gens, transcript, &[value], &[blinding], n)?;
+        Ok((proof, commitments[0]))
+    }
+    ///Aggregated proof that every value is in [0, 2^n), returns the proof with the
+    ///commitments. The number of values has to be a power of two.
+    pub fn prove_multiple(
+        bp_gens: &BulletproofGens<E>,
+        pc_gens: &PedersenGens<E>,
+        transcript: &mut Transcript,
+        values: &[u64],
+        blindings: &[Scalar<E>],
+        n: usize,
+    ) -> Result<(Self, Vec<ECpoint<E>>), Error> {
+        let m = values.len();
+        check_parameters(bp_gens, n, m)?;
+        if blindings.len() != m {
+            return Err(Error::WrongNumBlindingFactors)
+        }
+        if n < 64 && values.iter().any(|&v| v >> n != 0) {
+            return Err(Error::ValueOutOfRange)
+        }
+        let nm = n * m;
+        let (g, h) = (&bp_gens.g_vec[..nm], &bp_gens.h_vec[..nm]);
+        let commitments = values.iter().zip(blindings).map(|(&v, &gamma)| pc_gens.commit(Scalar::new(v), gamma)).collect::<Vec<_>>();
+        transcript.append_message(b"proof", b"range-proof");
+        transcript.append_u64(b"n", n as u64);
+        transcript.append_u64(b"m", m as u64);
+        commitments.iter().for_each(|v| transcript.append_point(b"V", v));
+
+        //commit to the bits and to the blinding vectors
+        let a_l = values.iter()
+            .flat_map(|&v| (0..n).map(move |i| Scalar::new((v >> i) & 1)))
+            .collect::<Vec<Scalar<E>>>();
+        let a_r = a_l.iter().map(|&bit| bit - Scalar::one()).collect::<Vec<_>>();
+        let alpha = Scalar::generate_secret();
+        let a = multiscalar_mul(&[&[alpha], a_l.as_slice(), &a_r].concat(), &[&[pc_gens.b_blinding], g, h].concat());
+        let (s_l, s_r) = (random_vec(nm), random_vec(nm));
+        let rho = Scalar::generate_secret();
+        let s = multiscalar_mul(&[&[rho], s_l.as_slice(), &s_r].concat(), &[&[pc_gens.b_blinding], g, h].concat());
+        transcript.append_point(b"A", &a);
+        transcript.append_point(b"S", &s);
+        let y = transcript.challenge_scalar::<E>(b"y");
+        let z = transcript.challenge_scalar::<E>(b"z");
+
+        //l(X) = a_L - z + s_L * X, r(X) = y^i * (a_R + z + s_R * X) + z^(2+j) * 2^k
+        let (y_powers, two_powers, weights) = (powers(y, nm), powers(Scalar::new(2), n), value_weights(z, m));
+        let l0 = a_l.iter().map(|&a| a - z).collect::<Vec<_>>();
+        let r0 = (0..nm).map(|i| y_powers[i] * (a_r[i] + z) + weights[i / n] * two_powers[i % n]).collect::<Vec<_>>();
+        let r1 = (0..nm).map(|i| y_powers[i] * s_r[i]).collect::<Vec<_>>();
+        let t1 = inner_product(&l0, &r1) + inner_product(&s_l, &r0);
+        let t2 = inner_product(&s_l, &r1);
+        let (tau1, tau2) = (Scalar::generate_secret(), Scalar::generate_secret());
+        let (t1_commitment, t2_commitment) = (pc_gens.commit(t1, tau1), pc_gens.commit(t2, tau2));
+        transcript.append_point(b"T1", &t1_commitment);
+        transcript.append_point(b"T2", &t2_commitment);
+        let x = transcript.challenge_scalar::<E>(b"x");
+
+        let t_x_blinding = tau2 * x * x + tau1 * x + inner_product(&weights, blindings);
+        let e_blinding = alpha + rho * x;
+        let l = (0..nm).map(|i| l0[i] + s_l[i] * x).collect::<Vec<_>>();
+        let r = (0..nm).map(|i| r0[i] + r1[i] * x).collect::<Vec<_>>();
+        let t_x = inner_product(&l, &r);
+        transcript.append_scalar(b"t_x", &t_x);
+        transcript.append_scalar(b"t_x_blinding", &t_x_blinding);
+        transcript.append_scalar(b"e_blinding", &e_blinding);
+        let w = transcript.challenge_scalar::<E>(b"w");
+
+        //prove <l, r> = t_x with H'_i = y^-i * H_i
+        let y_inv = Scalar::one() / y;
+        let ipp_proof = InnerProductProof::prove(transcript, &(pc_gens.b * w), &powers(y_inv, nm), g.to_vec(), h.to_vec(), l, r);
+        let proof = Self { a, s, t1: t1_commitment, t2: t2_commitment, t_x, t_x_blinding, e_blinding, ipp_proof };
+        Ok((proof, commitments))
+    }
+    pub fn verify_single(&self, bp_gens: &BulletproofGens<E>, pc_gens: &PedersenGens<E>, transcript: &mut Transcript, commitment: &ECpoint<E>, n: usize) -> bool {
+        self.verify_multiple(bp_gens, pc_gens, transcript, &[*commitment], n)
+    }
+    ///Checks t(x) = sum of z^(2+j) * v_j + delta(y, z) + t_1 * x + t_2 * x^2 on the
+    ///commitments and the inner product argument on A + x * S, together as one
+    ///multiscalar multiplication with a random weight c for the first equation
+    pub fn verify_multiple(&self, bp_gens: &BulletproofGens<E>, pc_gens: &PedersenGens<E>, transcript: &mut Transcript, commitments: &[ECpoint<E>], n: usize) -> bool {
+        let m = commitments.len();
+        if check_parameters(bp_gens, n, m).is_err() {
+            return false
+        }
+        let nm = n * m;
+        transcript.append_message(b"proof", b"range-proof");
+        transcript.append_u64(b"n", n as u64);
+        transcript.append_u64(b"m", m as u64);
+        commitments.iter().for_each(|v| transcript.append_point(b"V", v));
+        transcript.append_point(b"A", &self.a);
+        transcript.append_point(b"S", &self.s);
+        let y = transcript.challenge_scalar::<E>(b"y");
+        let z = transcript.challenge_scalar::<E>(b"z");
+        transcript.append_point(b"T1", &self.t1);
+        transcript.append_point(b"T2", &self.t2);
+        let x = transcript.challenge_scalar::<E>(b"x");
+        transcript.append_scalar(b"t_x", &self.t_x);
+        transcript.append_scalar(b"t_x_blinding", &self.t_x_blinding);
+        transcript.append_scalar(b"e_blinding", &self.e_blinding);
+        let w = transcript.challenge_scalar::<E>(b"w");
+        let Some((u_sq, u_inv_sq, s)) = self.ipp_proof.verification_scalars(nm, transcript) else {
+            return false
+        };
+
+        let (y_powers, two_powers, weights) = (powers(y, nm), powers(Scalar::new(2), n), value_weights(z, m));
+        let y_inv_powers = powers(Scalar::one() / y, nm);
+        let sum_two_powers = two_powers.iter().copied().sum::<Scalar<E>>();
+        //delta(y, z) = (z - z^2) * <1, y^nm> - sum of z^(3+j) * <1, 2^n>
+        let sum_y_powers = y_powers.iter().copied().sum::<Scalar<E>>();
+        let delta = (z - z * z) * sum_y_powers - weights.iter().map(|&w| w * z * sum_two_powers).sum::<Scalar<E>>();
+        let c = Scalar::generate_secret();
+        let (a, b) = (self.ipp_proof.a, self.ipp_proof.b);
+        let scalars = [
+            s.iter().map(|&s| a * s + z).collect::<Vec<_>>(),
+            (0..nm).map(|i| y_inv_powers[i] * (b / s[i] - weights[i / n] * two_powers[i % n]) - z).collect(),
+            vec![
+                w * (a * b - self.t_x) + c * (delta - self.t_x),
+                self.e_blinding - c * self.t_x_blinding,
+                -Scalar::one(),
+                -x,
+                c * x,
+                c * x * x,
+            ],
+            weights.iter().map(|&w| c * w).collect(),
+            u_sq.iter().map(|&u| -u).collect(),
+            u_inv_sq.iter().map(|&u| -u).collect(),
+        ].concat();
+        let points = [
+            &bp_gens.g_vec[..nm],
+            &bp_gens.h_vec[..nm],
+            &[pc_gens.b, pc_gens.b_blinding, self.a, self.s, self.t1, self.t2],
+            commitments,
+            &self.ipp_proof.l_vec,
+            &self.ipp_proof.r_vec,
+        ].concat();
+        multiscalar_mul(&scalars, &points).is_infinity()
+    }
+    ///A, S, T1, T2 compressed, t_x, t_x_blinding, e_blinding, then the L_j, R_j pairs
+    ///and a, b of the inner product proof
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let points = [self.a, self.s, self.t1, self.t2].iter().flat_map(|p| p.to_sec1(true)).collect::<Vec<u8>>();
+        let scalars = [self.t_x, self.t_x_blinding, self.e_blinding].iter().flat_map(|s| s.to_be_bytes()).collect::<Vec<u8>>();
+        let ipp = self.ipp_proof.l_vec.iter().zip(&self.ipp_proof.r_vec)
+            .flat_map(|(l, r)| [l.to_sec1(true), r.to_sec1(true)].concat())
+            .collect::<Vec<u8>>();
+        [points, scalars, ipp, self.ipp_proof.a.to_be_bytes(), self.ipp_proof.b.to_be_bytes()].concat()
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (point_len, scalar_len) = (1 + E::P.bits().div_ceil(8), E::N.bits().div_ceil(8));
+        let ipp_len = bytes.len().checked_sub(4 * point_len + 5 * scalar_len)?;
+        if ipp_len % (2 * point_len) != 0 {
+            return None
+        }
+        let mut chunks = bytes.chunks(point_len);
+        let mut point = || ECpoint::from_sec1(chunks.next()?);
+        let (a, s, t1, t2) = (point()?, point()?, point()?, point()?);
+        let (mut l_vec, mut r_vec) = (vec![], vec![]);
+        let scalars = &bytes[4 * point_len..];
+        let ipp = &scalars[3 * scalar_len..3 * scalar_len + ipp_len];
+        for pair in ipp.chunks(2 * point_len) {
+            l_vec.push(ECpoint::from_sec1(&pair[..point_len])?);
+            r_vec.push(ECpoint::from_sec1(&pair[point_len..])?);
+        }
+        let scalar = |i: usize| Scalar::from_be_bytes(&scalars[i * scalar_len..(i + 1) * scalar_len]);
+        let tail = |i: usize| Scalar::from_be_bytes(&scalars[3 * scalar_len + ipp_len + i * scalar_len..][..scalar_len]);
+        Some(Self {
+            a, s, t1, t2,
+            t_x: scalar(0)?,
+            t_x_blinding: scalar(1)?,
+            e_blinding: scalar(2)?,
+            ipp_proof: InnerProductProof { l_vec, r_vec, a: tail(0)?, b: tail(1)? },
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use crate::types::{Generator, Montgomery, TwistedEdwards, EC, U256};
+
+///Curve25519 of RFC 7748, v^2 = u^3 + 486662 * u^2 + u mod 2^255 - 19, birationally
+///equivalent to edwards25519 -x^2 + y^2 = 1 - 121665 / 121666 * x^2 * y^2 of Ed25519.
+///A, B and the generator are in short Weierstrass form, G is the point u = 9.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Curve25519;
+
+impl EC for Curve25519 {
+    const NAME: &'static str = "Curve25519";
+    const A: U256 = U256([0xaaaaaa984914a144, 0xaaaaaaaaaaaaaaaa, 0xaaaaaaaaaaaaaaaa, 0x2aaaaaaaaaaaaaaa]);
+    const B: U256 = U256([0x260b5e9c7710c864, 0xed097b425ed097b4, 0x097b425ed097b425, 0x7b425ed097b425ed]);
+    const N: U256 = U256([0x5812631a5cf5d3ed, 0x14def9dea2f79cd6, 0x0000000000000000, 0x1000000000000000]);
+    const P: U256 = U256([0xffffffffffffffed, 0xffffffffffffffff, 0xffffffffffffffff, 0x7fffffffffffffff]);
+    const H: U256 = U256([8, 0, 0, 0]);
+}
+
+impl Generator for Curve25519 {
+    const GX: U256 = U256([0xaaaaaaaaaaad245a, 0xaaaaaaaaaaaaaaaa, 0xaaaaaaaaaaaaaaaa, 0x2aaaaaaaaaaaaaaa]);
+    const GY: U256 = U256([0x29e9c5a27eced3d9, 0x923d4d7e6d7c61b2, 0xe01edd2c7748d14c, 0x20ae19a1b8a086b4]);
+}
+
+impl Montgomery for Curve25519 {
+    const MONTGOMERY_A: U256 = U256([486662, 0, 0, 0]);
+    const MONTGOMERY_B: U256 = U256([1, 0, 0, 0]);
+}
+
+impl TwistedEdwards for Curve25519 {
+    //-1
+    const EDWARDS_A: U256 = U256([0xffffffffffffffec, 0xffffffffffffffff, 0xffffffffffffffff, 0x7fffffffffffffff]);
+    //-121665 / 121666
+    const EDWARDS_D: U256 = U256([0x75eb4dca135978a3, 0x00700a4d4141d8ab, 0x8cc740797779e898, 0x52036cee2b6ffe73]);
+    //sqrt(-486664), the root RFC 7748 uses
+    const EDWARDS_C: U256 = U256([0x3391fb5500ba81e7, 0x3a5e2c2eb482e57d, 0x2d84f723fc03b081, 0x70d9120b9f5ff944]);
+}
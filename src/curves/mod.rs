@@ -0,0 +1,8 @@
+//!Standard curves, usable with all the generic machinery of the crate
+pub use secp256k1::Secp256k1;
+pub use p256::P256;
+pub use curve25519::Curve25519;
+
+mod secp256k1;
+mod p256;
+mod curve25519;
@@ -0,0 +1,18 @@
+use crate::types::{Generator, EC, U256};
+
+///P-256 of FIPS 186, also known as secp256r1 or prime256v1, y^2 = x^3 - 3 * x + b
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct P256;
+
+impl EC for P256 {
+    const NAME: &'static str = "P-256";
+    const A: U256 = U256([0xfffffffffffffffc, 0x00000000ffffffff, 0x0000000000000000, 0xffffffff00000001]);
+    const B: U256 = U256([0x3bce3c3e27d2604b, 0x651d06b0cc53b0f6, 0xb3ebbd55769886bc, 0x5ac635d8aa3a93e7]);
+    const N: U256 = U256([0xf3b9cac2fc632551, 0xbce6faada7179e84, 0xffffffffffffffff, 0xffffffff00000000]);
+    const P: U256 = U256([0xffffffffffffffff, 0x00000000ffffffff, 0x0000000000000000, 0xffffffff00000001]);
+}
+
+impl Generator for P256 {
+    const GX: U256 = U256([0xf4a13945d898c296, 0x77037d812deb33a0, 0xf8bce6e563a440f2, 0x6b17d1f2e12c4247]);
+    const GY: U256 = U256([0xcbb6406837bf51f5, 0x2bce33576b315ece, 0x8ee7eb4a7c0f9e16, 0x4fe342e2fe1a7f9b]);
+}
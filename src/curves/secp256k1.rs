@@ -0,0 +1,18 @@
+use crate::types::{Generator, EC, U256};
+
+///secp256k1 of SEC 2, y^2 = x^3 + 7, the curve of Bitcoin
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Secp256k1;
+
+impl EC for Secp256k1 {
+    const NAME: &'static str = "Secp256k1";
+    const A: U256 = U256([0;4]);
+    const B: U256 = U256([7, 0, 0, 0]);
+    const N: U256 = U256([0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff]);
+    const P: U256 = U256([0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff]);
+}
+
+impl Generator for Secp256k1 {
+    const GX: U256 = U256([0x59f2815b16f81798, 0x029bfcdb2dce28d9, 0x55a06295ce870b07, 0x79be667ef9dcbbac]);
+    const GY: U256 = U256([0x9c47d08ffb10d4b8, 0xfd17b448a6855419, 0x5da4fbfc0e1108a8, 0x483ada7726a3c465]);
+}
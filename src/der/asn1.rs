@@ -0,0 +1,107 @@
+use crate::der::Error;
+
+pub(crate) const INTEGER: u8 = 0x02;
+pub(crate) const BIT_STRING: u8 = 0x03;
+pub(crate) const OCTET_STRING: u8 = 0x04;
+pub(crate) const OBJECT_IDENTIFIER: u8 = 0x06;
+pub(crate) const SEQUENCE: u8 = 0x30;
+///[0] and [1] EXPLICIT
+pub(crate) const CONTEXT_0: u8 = 0xa0;
+pub(crate) const CONTEXT_1: u8 = 0xa1;
+
+///tag || length || content with the length in the shortest form
+pub(crate) fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let len = content.len().to_be_bytes();
+    let length = match content.len() {
+        0..0x80 => vec![content.len() as u8],
+        _ => {
+            let significant = &len[len.iter().take_while(|&&b| b == 0).count()..];
+            [&[0x80 | significant.len() as u8], significant].concat()
+        }
+    };
+    [&[tag], length.as_slice(), content].concat()
+}
+
+///INTEGER of a non negative big endian value, zero bytes stripped and one put back
+///when the top bit is set
+pub(crate) fn integer(be_bytes: &[u8]) -> Vec<u8> {
+    let value = &be_bytes[be_bytes.iter().take_while(|&&b| b == 0).count()..];
+    match value.first() {
+        None => tlv(INTEGER, &[0]),
+        Some(b) if b & 0x80 != 0 => tlv(INTEGER, &[&[0], value].concat()),
+        Some(_) => tlv(INTEGER, value)
+    }
+}
+
+///BIT STRING without unused bits
+pub(crate) fn bit_string(bytes: &[u8]) -> Vec<u8> {
+    tlv(BIT_STRING, &[&[0], bytes].concat())
+}
+
+///Strict DER reader over a sequence of elements
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+    pub(crate) fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+    ///Content of the next element, which must have the tag. Indefinite and non minimal
+    ///lengths are rejected.
+    pub(crate) fn read(&mut self, tag: u8) -> Result<&'a [u8], Error> {
+        let (&t, rest) = self.data.split_first().ok_or(Error::InvalidDer)?;
+        let (&first, rest) = rest.split_first().ok_or(Error::InvalidDer)?;
+        if t != tag {
+            return Err(Error::InvalidDer)
+        }
+        let (len, rest) = match first {
+            0..=0x7f => (first as usize, rest),
+            0x81..=0x84 => {
+                let n = (first & 0x7f) as usize;
+                if rest.len() < n || rest[0] == 0 {
+                    return Err(Error::InvalidDer)
+                }
+                let len = rest[..n].iter().fold(0usize, |acc, &b| acc << 8 | b as usize);
+                if len < 0x80 {
+                    return Err(Error::InvalidDer)
+                }
+                (len, &rest[n..])
+            },
+            _ => return Err(Error::InvalidDer)
+        };
+        if rest.len() < len {
+            return Err(Error::InvalidDer)
+        }
+        self.data = &rest[len..];
+        Ok(&rest[..len])
+    }
+    ///Non negative INTEGER in minimal form, returned without the sign byte
+    pub(crate) fn read_unsigned(&mut self) -> Result<&'a [u8], Error> {
+        let content = self.read(INTEGER)?;
+        match content {
+            [] => Err(Error::InvalidDer),
+            [b, ..] if b & 0x80 != 0 => Err(Error::InvalidDer),
+            [0, b, ..] if b & 0x80 == 0 => Err(Error::InvalidDer),
+            [0, rest @ ..] if !rest.is_empty() => Ok(rest),
+            _ => Ok(content)
+        }
+    }
+    ///BIT STRING without unused bits
+    pub(crate) fn read_bit_string(&mut self) -> Result<&'a [u8], Error> {
+        match self.read(BIT_STRING)? {
+            [0, bytes @ ..] => Ok(bytes),
+            _ => Err(Error::InvalidDer)
+        }
+    }
+    ///Fails if anything is left
+    pub(crate) fn finish(&self) -> Result<(), Error> {
+        match self.data.is_empty() {
+            true => Ok(()),
+            false => Err(Error::InvalidDer)
+        }
+    }
+}
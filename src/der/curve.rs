@@ -0,0 +1,20 @@
+use crate::{curves::{Secp256k1, P256}, types::Generator};
+
+///Curves with an object identifier for the namedCurve choice of ECParameters
+pub trait NamedCurve: Generator {
+    ///Content octets of the OID, without tag and length
+    const OID: &'static [u8];
+}
+
+///1.3.132.0.10 of SEC2
+impl NamedCurve for Secp256k1 {
+    const OID: &'static [u8] = &[0x2b, 0x81, 0x04, 0x00, 0x0a];
+}
+
+///1.2.840.10045.3.1.7, prime256v1 of X9.62
+impl NamedCurve for P256 {
+    const OID: &'static [u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+}
+
+///1.2.840.10045.2.1 of RFC 5480
+pub(crate) const ID_EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
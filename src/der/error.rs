@@ -0,0 +1,29 @@
+///Reasons decoding a key can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///wrong tag, length or version, non minimal encoding or trailing data
+    InvalidDer,
+    ///missing armor, a different label or malformed base64
+    InvalidPem,
+    ///an algorithm other than id-ecPublicKey or explicit curve parameters
+    UnsupportedAlgorithm,
+    ///the named curve is not the one asked for
+    CurveMismatch,
+    ///the secret is not in [1, N), the point is not on the curve or is infinity, or
+    ///the embedded public key does not belong to the secret
+    InvalidKey,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidDer => write!(f, "invalid DER encoding"),
+            Error::InvalidPem => write!(f, "invalid PEM encoding"),
+            Error::UnsupportedAlgorithm => write!(f, "unsupported key algorithm"),
+            Error::CurveMismatch => write!(f, "key is on a different curve"),
+            Error::InvalidKey => write!(f, "invalid key"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
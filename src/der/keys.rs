@@ -0,0 +1,130 @@
+use crate::{der::{asn1::{bit_string, integer, tlv, Reader, CONTEXT_0, CONTEXT_1, OBJECT_IDENTIFIER, OCTET_STRING, SEQUENCE}, curve::ID_EC_PUBLIC_KEY, Error, NamedCurve}, types::{ECpoint, Scalar}};
+
+///AlgorithmIdentifier { id-ecPublicKey, namedCurve }
+fn algorithm<E: NamedCurve>() -> Vec<u8> {
+    tlv(SEQUENCE, &[tlv(OBJECT_IDENTIFIER, ID_EC_PUBLIC_KEY), tlv(OBJECT_IDENTIFIER, E::OID)].concat())
+}
+
+fn read_algorithm<E: NamedCurve>(reader: &mut Reader) -> Result<(), Error> {
+    let mut algorithm = Reader::new(reader.read(SEQUENCE)?);
+    if algorithm.read(OBJECT_IDENTIFIER)? != ID_EC_PUBLIC_KEY {
+        return Err(Error::UnsupportedAlgorithm)
+    }
+    //ECParameters is a CHOICE, only namedCurve is supported
+    if algorithm.peek_tag() != Some(OBJECT_IDENTIFIER) {
+        return Err(Error::UnsupportedAlgorithm)
+    }
+    if algorithm.read(OBJECT_IDENTIFIER)? != E::OID {
+        return Err(Error::CurveMismatch)
+    }
+    algorithm.finish()
+}
+
+fn read_point<E: NamedCurve>(sec1: &[u8]) -> Result<ECpoint<E>, Error> {
+    match ECpoint::from_sec1(sec1) {
+        Some(point) if !point.is_infinity() => Ok(point),
+        _ => Err(Error::InvalidKey)
+    }
+}
+
+///ECPrivateKey ::= SEQUENCE { version 1, privateKey OCTET STRING, [0] parameters
+///OPTIONAL, [1] publicKey OPTIONAL }, the public key is always written uncompressed
+fn ec_private_key<E: NamedCurve>(secret: &Scalar<E>, with_parameters: bool) -> Vec<u8> {
+    let parameters = match with_parameters {
+        true => tlv(CONTEXT_0, &tlv(OBJECT_IDENTIFIER, E::OID)),
+        false => vec![]
+    };
+    let public_key = tlv(CONTEXT_1, &bit_string(&(E::generator() * *secret).to_sec1(false)));
+    let content = [integer(&[1]), tlv(OCTET_STRING, &secret.to_be_bytes()), parameters, public_key].concat();
+    tlv(SEQUENCE, &content)
+}
+
+fn read_ec_private_key<E: NamedCurve>(der: &[u8]) -> Result<Scalar<E>, Error> {
+    let mut outer = Reader::new(der);
+    let mut reader = Reader::new(outer.read(SEQUENCE)?);
+    outer.finish()?;
+    if reader.read_unsigned()? != [1] {
+        return Err(Error::InvalidDer)
+    }
+    //some encoders drop leading zero bytes of the secret
+    let bytes = reader.read(OCTET_STRING)?;
+    let len = E::N.bits().div_ceil(8);
+    if bytes.is_empty() || bytes.len() > len {
+        return Err(Error::InvalidKey)
+    }
+    let secret = Scalar::from_be_bytes(&[vec![0; len - bytes.len()], bytes.to_vec()].concat()).ok_or(Error::InvalidKey)?;
+    if secret.is_zero() {
+        return Err(Error::InvalidKey)
+    }
+    if reader.peek_tag() == Some(CONTEXT_0) {
+        let mut parameters = Reader::new(reader.read(CONTEXT_0)?);
+        if parameters.peek_tag() != Some(OBJECT_IDENTIFIER) {
+            return Err(Error::UnsupportedAlgorithm)
+        }
+        if parameters.read(OBJECT_IDENTIFIER)? != E::OID {
+            return Err(Error::CurveMismatch)
+        }
+        parameters.finish()?;
+    }
+    if reader.peek_tag() == Some(CONTEXT_1) {
+        let mut public_key = Reader::new(reader.read(CONTEXT_1)?);
+        if read_point::<E>(public_key.read_bit_string()?)? != E::generator() * secret {
+            return Err(Error::InvalidKey)
+        }
+        public_key.finish()?;
+    }
+    reader.finish()?;
+    Ok(secret)
+}
+
+///RFC 5915 ECPrivateKey with the named curve and the public key, as OpenSSL writes it
+pub fn sec1_to_der<E: NamedCurve>(secret: &Scalar<E>) -> Vec<u8> {
+    ec_private_key(secret, true)
+}
+
+///Checks the named curve and that the public key matches the secret when they are present
+pub fn sec1_from_der<E: NamedCurve>(der: &[u8]) -> Result<Scalar<E>, Error> {
+    read_ec_private_key(der)
+}
+
+///PrivateKeyInfo ::= SEQUENCE { version 0, algorithm, privateKey OCTET STRING } of
+///RFC 5208, wrapping an ECPrivateKey without the parameters already in the algorithm
+pub fn pkcs8_to_der<E: NamedCurve>(secret: &Scalar<E>) -> Vec<u8> {
+    let content = [integer(&[0]), algorithm::<E>(), tlv(OCTET_STRING, &ec_private_key(secret, false))].concat();
+    tlv(SEQUENCE, &content)
+}
+
+///Version 1 of RFC 5958 is accepted as well as long as no public key attribute follows
+pub fn pkcs8_from_der<E: NamedCurve>(der: &[u8]) -> Result<Scalar<E>, Error> {
+    let mut outer = Reader::new(der);
+    let mut reader = Reader::new(outer.read(SEQUENCE)?);
+    outer.finish()?;
+    if !matches!(reader.read_unsigned()?, [0] | [1]) {
+        return Err(Error::InvalidDer)
+    }
+    read_algorithm::<E>(&mut reader)?;
+    let secret = read_ec_private_key(reader.read(OCTET_STRING)?)?;
+    reader.finish()?;
+    Ok(secret)
+}
+
+///SubjectPublicKeyInfo ::= SEQUENCE { algorithm, subjectPublicKey BIT STRING } of
+///RFC 5480 with the SEC1 point. None for infinity.
+pub fn spki_to_der<E: NamedCurve>(public: &ECpoint<E>, compressed: bool) -> Option<Vec<u8>> {
+    if public.is_infinity() {
+        return None
+    }
+    Some(tlv(SEQUENCE, &[algorithm::<E>(), bit_string(&public.to_sec1(compressed))].concat()))
+}
+
+///Compressed and uncompressed points are accepted
+pub fn spki_from_der<E: NamedCurve>(der: &[u8]) -> Result<ECpoint<E>, Error> {
+    let mut outer = Reader::new(der);
+    let mut reader = Reader::new(outer.read(SEQUENCE)?);
+    outer.finish()?;
+    read_algorithm::<E>(&mut reader)?;
+    let point = read_point(reader.read_bit_string()?)?;
+    reader.finish()?;
+    Ok(point)
+}
+
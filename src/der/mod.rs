@@ -0,0 +1,14 @@
+//!Standard key and signature formats: ASN.1 DER of the RFC 5915 ECPrivateKey, PKCS#8
+//!private keys and SubjectPublicKeyInfo public keys for curves with a named curve
+//!OID, and PEM armor around them, as OpenSSL reads and writes them. The DER
+//!signature encoding lives on ecdsa::Signature.
+pub use error::Error;
+pub use curve::NamedCurve;
+pub use keys::{pkcs8_from_der, pkcs8_to_der, sec1_from_der, sec1_to_der, spki_from_der, spki_to_der};
+pub use pem::{pem_decode, pem_encode, PKCS8_LABEL, SEC1_LABEL, SPKI_LABEL};
+
+mod error;
+mod curve;
+pub(crate) mod asn1;
+mod keys;
+mod pem;
@@ -0,0 +1,26 @@
+use crate::{der::Error, utils::{base64_decode, base64_encode}};
+
+///RFC 5915 ECPrivateKey
+pub const SEC1_LABEL: &str = "EC PRIVATE KEY";
+///PKCS#8 PrivateKeyInfo
+pub const PKCS8_LABEL: &str = "PRIVATE KEY";
+///SubjectPublicKeyInfo
+pub const SPKI_LABEL: &str = "PUBLIC KEY";
+
+///RFC 7468 armor with 64 character lines
+pub fn pem_encode(label: &str, der: &[u8]) -> String {
+    let base64 = base64_encode(der);
+    let lines: Vec<&str> = base64.as_bytes().chunks(64).map(|l| std::str::from_utf8(l).unwrap()).collect();
+    format!("-----BEGIN {label}-----\n{}\n-----END {label}-----\n", lines.join("\n"))
+}
+
+///DER of the first block with the label, text around it is ignored as RFC 7468 allows.
+///Headers and other whitespace inside the base64 are rejected.
+pub fn pem_decode(pem: &str, label: &str) -> Result<Vec<u8>, Error> {
+    let begin = format!("-----BEGIN {label}-----");
+    let end = format!("-----END {label}-----");
+    let start = pem.find(&begin).ok_or(Error::InvalidPem)? + begin.len();
+    let len = pem[start..].find(&end).ok_or(Error::InvalidPem)?;
+    let base64: String = pem[start..start + len].lines().map(str::trim_end).collect();
+    base64_decode(&base64).ok_or(Error::InvalidPem)
+}
@@ -0,0 +1,28 @@
+///Reasons an ECDH operation can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///bytes are not a valid SEC1 point or scalar encoding
+    InvalidEncoding,
+    ///the public key is the point at infinity
+    PointAtInfinity,
+    ///the public key is not in the subgroup of order N
+    NotInSubgroup,
+    ///the secret scalar is zero
+    ZeroScalar,
+    ///the key derivation function can not output that many bytes
+    InvalidLength,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidEncoding => write!(f, "invalid encoding"),
+            Error::PointAtInfinity => write!(f, "point at infinity"),
+            Error::NotInSubgroup => write!(f, "point is not in the prime order subgroup"),
+            Error::ZeroScalar => write!(f, "secret scalar is zero"),
+            Error::InvalidLength => write!(f, "invalid output length"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,14 @@
+//!Elliptic curve Diffie-Hellman key agreement. Generic over E with validated public
+//!keys, cofactor multiplication, the SEC1 x coordinate shared secret and an HKDF step,
+//!plus the x-only X25519 function of RFC 7748.
+pub use error::Error;
+pub use public_key::PublicKey;
+pub use secret::{EphemeralSecret, StaticSecret};
+pub use shared_secret::SharedSecret;
+pub use x25519::{x25519, X25519_BASEPOINT};
+
+mod error;
+mod public_key;
+mod secret;
+mod shared_secret;
+mod x25519;
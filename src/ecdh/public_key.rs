@@ -0,0 +1,40 @@
+use crate::{der::{spki_from_der, NamedCurve}, ecdh::Error, types::{ECpoint, EC}};
+
+///A validated public key: on the curve, not infinity and in the subgroup of order N
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublicKey<E: EC> {
+    point: ECpoint<E>,
+}
+
+impl<E: EC> PublicKey<E> {
+    ///Validates the point. Being on the curve is guaranteed by ECpoint, the subgroup
+    ///check N * Q = O is only done for curves with a cofactor, otherwise every point
+    ///other than infinity is in the subgroup.
+    pub fn from_point(point: ECpoint<E>) -> Result<Self, Error> {
+        if point.is_infinity() {
+            return Err(Error::PointAtInfinity)
+        }
+        if E::H != 1.into() && !point.mul_integer(E::N).is_infinity() {
+            return Err(Error::NotInSubgroup)
+        }
+        Ok(Self { point })
+    }
+    ///Decodes and validates a SEC1 encoded point, compressed or not
+    pub fn from_sec1(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_point(ECpoint::from_sec1(bytes).ok_or(Error::InvalidEncoding)?)
+    }
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        self.point.to_sec1(compressed)
+    }
+    pub fn point(&self) -> ECpoint<E> {
+        self.point
+    }
+}
+
+impl<E: NamedCurve> PublicKey<E> {
+    ///Decodes and validates a SubjectPublicKeyInfo, a key of another curve or
+    ///algorithm is an invalid encoding as well
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, Error> {
+        Self::from_point(spki_from_der(der).map_err(|_| Error::InvalidEncoding)?)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{ecdh::{Error, PublicKey, SharedSecret}, types::{ECpoint, Scalar, Generator, EC, U256}};
+
+///Computes h * d * Q and takes its x coordinate from the compressed SEC1 encoding.
+///Multiplying by the cofactor h maps any small order component of Q to infinity.
+fn diffie_hellman<E: EC>(secret: Scalar<E>, public: &PublicKey<E>) -> Result<SharedSecret, Error> {
+    let point = public.point().mul_integer(E::H) * secret;
+    match point {
+        ECpoint::Infinity => Err(Error::PointAtInfinity),
+        ECpoint::Point(_) => Ok(SharedSecret::new(point.to_sec1(true)[1..].to_vec()))
+    }
+}
+
+///Decodes a big endian scalar, it has to be in [1, N - 1]
+fn scalar_from_be_bytes<E: EC>(bytes: &[u8]) -> Result<Scalar<E>, Error> {
+    if bytes.len() > 32 {
+        return Err(Error::InvalidEncoding)
+    }
+    let d = U256::from_big_endian(bytes);
+    if d >= E::N {
+        return Err(Error::InvalidEncoding)
+    }
+    match d.is_zero() {
+        true => Err(Error::ZeroScalar),
+        false => Ok(Scalar::new(d))
+    }
+}
+
+///A long term secret key, can be used for any number of key agreements
+#[derive(Clone, PartialEq, Eq)]
+pub struct StaticSecret<E: EC> {
+    secret: Scalar<E>,
+}
+
+impl<E: EC> std::fmt::Debug for StaticSecret<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StaticSecret [curve {}]", E::NAME)
+    }
+}
+
+impl<E: Generator> StaticSecret<E> {
+    pub fn random() -> Self {
+        Self { secret: Scalar::generate_secret() }
+    }
+    pub fn new(secret: Scalar<E>) -> Result<Self, Error> {
+        match secret.is_zero() {
+            true => Err(Error::ZeroScalar),
+            false => Ok(Self { secret })
+        }
+    }
+    ///Big endian secret, has to be in [1, N - 1]
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self { secret: scalar_from_be_bytes(bytes)? })
+    }
+    pub fn scalar(&self) -> Scalar<E> {
+        self.secret
+    }
+    pub fn public_key(&self) -> PublicKey<E> {
+        PublicKey::from_point(E::generator() * self.secret).expect("d * G is a valid public key")
+    }
+    pub fn diffie_hellman(&self, public: &PublicKey<E>) -> Result<SharedSecret, Error> {
+        diffie_hellman(self.secret, public)
+    }
+}
+
+///A single use secret key, consumed by the key agreement so it can not be reused
+pub struct EphemeralSecret<E: EC> {
+    secret: Scalar<E>,
+}
+
+impl<E: EC> std::fmt::Debug for EphemeralSecret<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EphemeralSecret [curve {}]", E::NAME)
+    }
+}
+
+impl<E: Generator> EphemeralSecret<E> {
+    pub fn random() -> Self {
+        Self { secret: StaticSecret::<E>::random().secret }
+    }
+    pub fn public_key(&self) -> PublicKey<E> {
+        PublicKey::from_point(E::generator() * self.secret).expect("d * G is a valid public key")
+    }
+    pub fn diffie_hellman(self, public: &PublicKey<E>) -> Result<SharedSecret, Error> {
+        diffie_hellman(self.secret, public)
+    }
+}
@@ -0,0 +1,40 @@
+use ring::hkdf;
+
+use crate::ecdh::Error;
+
+///The x coordinate of the shared point, big endian and padded to the byte length
+///of P as in SEC1. Not uniformly random, derive keys from it with a KDF.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SharedSecret(Vec<u8>);
+
+impl std::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SharedSecret")
+    }
+}
+
+///Output length for ring's HKDF expand
+struct Len(usize);
+
+impl hkdf::KeyType for Len {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+impl SharedSecret {
+    pub(crate) fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+    pub fn raw_secret_bytes(&self) -> &[u8] {
+        &self.0
+    }
+    ///HKDF-SHA256 (RFC 5869) of the shared secret, fills out with the derived key.
+    ///At most 255 * 32 bytes can be derived.
+    pub fn hkdf_sha256(&self, salt: &[u8], info: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, salt).extract(&self.0);
+        let info = [info];
+        let okm = prk.expand(&info, Len(out.len())).map_err(|_| Error::InvalidLength)?;
+        okm.fill(out).map_err(|_| Error::InvalidLength)
+    }
+}
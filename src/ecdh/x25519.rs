@@ -0,0 +1,24 @@
+use crate::{curves::Curve25519, types::{MontgomeryPoint, Zp, U256}};
+
+///u coordinate of the Curve25519 base point
+pub const X25519_BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+///The X25519 function of RFC 7748, little endian scalar and u coordinate. The scalar
+///is clamped to a multiple of the cofactor 8 with bit 254 set, the top bit of u is
+///ignored. Public keys are x25519(k, X25519_BASEPOINT). Shared secrets should be
+///checked for all zeros, that is what a small order u gives.
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let mut k = scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    let mut u = u;
+    u[31] &= 127;
+    let u = Zp::<Curve25519>::new(U256::from_little_endian(&u));
+    let mut out = [0u8; 32];
+    MontgomeryPoint::ladder(u, U256::from_little_endian(&k)).unwrap().to_little_endian(&mut out);
+    out
+}
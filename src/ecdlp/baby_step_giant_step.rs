@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::types::{ECpoint, Scalar, EC, U256};
+
+///Solves Q = k * G for k using Shanks' baby-step giant-step algorithm.
+///G is expected to generate the subgroup of order N, time and memory
+///are both O(sqrt(N)). Returns None if Q is not a multiple of G.
+pub fn baby_step_giant_step<E: EC>(g: ECpoint<E>, q: ECpoint<E>) -> Option<Scalar<E>> {
+    baby_step_giant_step_bounded(g, q, E::N).map(Scalar::new)
+}
+
+///Solves Q = k * G for k, knowing that 0 <= k < bound. Useful when the
+///logarithm is known to be small, or when G generates a subgroup of order
+///bound instead of N. Time and memory are O(sqrt(bound)).
+pub fn baby_step_giant_step_bounded<E: EC, T: Into<U256>>(g: ECpoint<E>, q: ECpoint<E>, bound: T) -> Option<U256> {
+    let bound = bound.into();
+    if bound.is_zero() {
+        return None
+    }
+    //m = ceil(sqrt(bound)), so that m * m >= bound
+    let mut m = bound.integer_sqrt();
+    if m * m < bound {
+        m += U256::one();
+    }
+    //baby steps: remember j * G for 0 <= j < m
+    let mut baby_steps = HashMap::new();
+    let mut point = ECpoint::Infinity;
+    let mut j = U256::zero();
+    while j < m {
+        baby_steps.entry(point).or_insert(j);
+        point += g;
+        j += U256::one();
+    }
+    //giant steps: look for Q - i * m * G among the baby steps
+    let giant_step = -point; // point == m * G at this moment
+    let mut gamma = q;
+    let mut i = U256::zero();
+    while i < m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            let k = i * m + *j;
+            return match k < bound {
+                true => Some(k),
+                false => None
+            }
+        }
+        gamma += giant_step;
+        i += U256::one();
+    }
+    None
+}
@@ -0,0 +1,12 @@
+//!Solvers for the elliptic curve discrete logarithm problem, finding k such that
+//!Q = k * G. They are only practical on small or weak curves and exist to
+//!demonstrate why curve parameters matter.
+pub use baby_step_giant_step::{baby_step_giant_step, baby_step_giant_step_bounded};
+pub use pollard_rho::pollard_rho;
+pub use pohlig_hellman::pohlig_hellman;
+pub use mov_attack::mov_attack;
+
+mod baby_step_giant_step;
+mod pollard_rho;
+mod pohlig_hellman;
+mod mov_attack;
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use crate::{pairing::tate_pairing, types::{CurveOver, ECpoint, Field, Scalar, Zp, EC, U256}};
+
+///Solves Q = k * G with the Menezes-Okamoto-Vanstone reduction. With S a point of
+///order N over K that is independent of G (e.g. G under a distortion map),
+///e(Q, S) = e(G, S)^k, so the problem moves to the multiplicative group of K
+///where it is solved with baby-step giant-step. K has to contain the N-th roots
+///of unity, which is only feasible when the embedding degree is small.
+pub fn mov_attack<E, K>(g: ECpoint<E>, q: ECpoint<E>, s: ECpoint<E, K>) -> Option<Scalar<E>>
+where E: EC + CurveOver<K>, K: Field + From<Zp<E>> {
+    let alpha = tate_pairing(g.lift()?, s, E::N)?;
+    let beta = tate_pairing(q.lift()?, s, E::N)?;
+    if alpha.is_one() {
+        //degenerate pairing, S is not independent of G
+        return None
+    }
+    //m = ceil(sqrt(N))
+    let mut m = E::N.integer_sqrt();
+    if m * m < E::N {
+        m += U256::one();
+    }
+    //baby steps: alpha^j for 0 <= j < m
+    let mut baby_steps = HashMap::new();
+    let mut value = K::one();
+    let mut j = U256::zero();
+    while j < m {
+        baby_steps.entry(value).or_insert(j);
+        value *= alpha;
+        j += U256::one();
+    }
+    //giant steps: look for beta * alpha^(-i * m) among the baby steps
+    let giant_step = value.inverse()?; // value == alpha^m at this moment
+    let mut gamma = beta;
+    let mut i = U256::zero();
+    while i < m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(Scalar::new(i * m + *j))
+        }
+        gamma *= giant_step;
+        i += U256::one();
+    }
+    None
+}
@@ -0,0 +1,42 @@
+use crate::{ecdlp::baby_step_giant_step_bounded, types::{ECpoint, Scalar, EC, U256}, utils::{find_factors, mod_inverse}};
+
+///Solves Q = k * G for k using the Pohlig-Hellman reduction. The problem is
+///split into one problem per prime power p^e dividing N, each solved in the
+///subgroup of order p, and the results are combined with the chinese
+///remainder theorem. Fast whenever the largest prime factor of N is small.
+///Returns None if Q is not a multiple of G.
+pub fn pohlig_hellman<E: EC>(g: ECpoint<E>, q: ECpoint<E>) -> Option<Scalar<E>> {
+    let n = E::N;
+    //group the factorization into (p, e) pairs, skipping the leading 1
+    let mut prime_powers: Vec<(U256, u32)> = vec![];
+    for p in find_factors(n).into_iter().skip(1) {
+        match prime_powers.last_mut() {
+            Some((last, e)) if *last == p => *e += 1,
+            _ => prime_powers.push((p, 1)),
+        }
+    }
+
+    let mut k = Scalar::<E>::zero();
+    for (p, e) in prime_powers {
+        let pe = p.pow(e.into());
+        //G0 = (N / p) * G has order p
+        let g0 = g * Scalar::new(n / p);
+        //k mod p^e = x0 + x1 * p + ... + x(e-1) * p^(e-1), one digit at a time
+        let mut x = U256::zero();
+        let mut p_i = U256::one();
+        for _ in 0..e {
+            let h = (q - g * Scalar::new(x)) * Scalar::new(n / (p_i * p));
+            let digit = baby_step_giant_step_bounded(g0, h, p)?;
+            x += digit * p_i;
+            p_i *= p;
+        }
+        //chinese remainder theorem: k += x * (N / p^e) * ((N / p^e)^-1 mod p^e)
+        let m = n / pe;
+        let m_inverse = mod_inverse(m, pe)?;
+        k += Scalar::new(x) * Scalar::new(m) * Scalar::new(m_inverse);
+    }
+    match g * k == q {
+        true => Some(k),
+        false => None
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::types::{ECpoint, Scalar, EC};
+
+///Number of partitions of the r-adding walk
+const PARTITIONS: usize = 32;
+///How many of the last walk points are remembered to detect short fruitless cycles
+const CYCLE_MEMORY: usize = 12;
+
+///A point of the random walk, together with a and b such that
+///point = a * G + b * Q
+#[derive(Clone, Copy)]
+struct WalkPoint<E: EC> {
+    point: ECpoint<E>,
+    a: Scalar<E>,
+    b: Scalar<E>,
+}
+
+impl<E: EC> WalkPoint<E> {
+    fn random(g: ECpoint<E>, q: ECpoint<E>) -> Self {
+        let (a, b) = (Scalar::generate_secret(), Scalar::generate_secret());
+        WalkPoint { point: g * a + q * b, a, b }.canonical()
+    }
+    ///Negation map, P and -P are treated as the same point. The representative
+    ///is the one with the smaller y coordinate, coefficients are negated with it.
+    fn canonical(self) -> Self {
+        match self.point {
+            ECpoint::Infinity => self,
+            ECpoint::Point(_) if self.point.y().unwrap() > (-self.point.y()).unwrap() => {
+                WalkPoint { point: -self.point, a: -self.a, b: -self.b }
+            },
+            ECpoint::Point(_) => self,
+        }
+    }
+    fn partition(&self) -> usize {
+        match self.point {
+            ECpoint::Infinity => 0,
+            ECpoint::Point(_) => self.point.x().unwrap().low_u64() as usize % PARTITIONS
+        }
+    }
+    fn is_distinguished(&self, mask: u64) -> bool {
+        match self.point {
+            ECpoint::Infinity => true,
+            ECpoint::Point(_) => self.point.x().unwrap().low_u64() & mask == 0
+        }
+    }
+    fn double(self) -> Self {
+        WalkPoint {
+            point: self.point + self.point,
+            a: self.a + self.a,
+            b: self.b + self.b,
+        }.canonical()
+    }
+    fn add(self, other: &Self) -> Self {
+        WalkPoint {
+            point: self.point + other.point,
+            a: self.a + other.a,
+            b: self.b + other.b,
+        }.canonical()
+    }
+}
+
+///Solves Q = k * G for k using Pollard's rho with an r-adding walk,
+///distinguished points and the negation map, expected time is
+///about sqrt(pi * N / 4) point additions.
+///N has to be a prime, the order of G. Returns None if no solution was
+///found, e.g. Q is not a multiple of G.
+pub fn pollard_rho<E: EC>(g: ECpoint<E>, q: ECpoint<E>) -> Option<Scalar<E>> {
+    if q.is_infinity() {
+        return Some(Scalar::zero())
+    }
+    //a point is distinguished if the low bits of its x coordinate are zero,
+    //with about N^(1/3) steps between two distinguished points, as each new
+    //walk starts with two costly scalar multiplications
+    let distinguished_bits = (E::N.bits() / 3).min(16);
+    let mask = (1u64 << distinguished_bits) - 1;
+    let max_walk_length = 20 * (mask + 1);
+    //give up after many times the expected number of steps
+    let max_steps = match E::N.bits() > 120 {
+        true => u64::MAX,
+        false => 64 * (E::N.integer_sqrt().low_u64() + 1),
+    };
+
+    let steps = (0..PARTITIONS)
+        .map(|_| WalkPoint::random(g, q))
+        .collect::<Vec<_>>();
+    //looking ahead avoids fruitless 2-cycles introduced by the negation map,
+    //a step is never made with the partition of the point it leads to
+    let next = |w: WalkPoint<E>| {
+        let j = w.partition();
+        (0..PARTITIONS)
+            .map(|t| (j + t) % PARTITIONS)
+            .map(|j| (j, w.add(&steps[j])))
+            .find(|(j, next)| next.partition() != *j)
+            .map(|(_, next)| next)
+            .unwrap_or_else(|| w.add(&steps[j]))
+    };
+    let solve = |w1: &WalkPoint<E>, w2: &WalkPoint<E>| {
+        //a1 * G + b1 * Q = a2 * G + b2 * Q => k = (a1 - a2) / (b2 - b1)
+        if w1.b == w2.b {
+            return None
+        }
+        let k = (w1.a - w2.a) / (w2.b - w1.b);
+        match g * k == q {
+            true => Some(k),
+            false => None
+        }
+    };
+
+    let mut distinguished: HashMap<ECpoint<E>, WalkPoint<E>> = HashMap::new();
+    let mut total_steps = 0u64;
+    while total_steps < max_steps {
+        let mut w = WalkPoint::random(g, q);
+        let mut walk_length = 0;
+        let mut recent: VecDeque<WalkPoint<E>> = VecDeque::from([w]);
+        //walks longer than expected are most likely stuck in a longer fruitless cycle
+        while !w.is_distinguished(mask) && walk_length < max_walk_length {
+            let candidate = next(w);
+            w = match recent.iter().position(|r| r.point == candidate.point) {
+                //escape a short fruitless cycle deterministically, by doubling
+                //the cycle point with the smallest x coordinate
+                Some(start) => recent.range(start..)
+                    .min_by_key(|r| r.point.x().unwrap())
+                    .copied()
+                    .unwrap_or(candidate)
+                    .double(),
+                None => candidate,
+            };
+            if recent.len() == CYCLE_MEMORY {
+                recent.pop_front();
+            }
+            recent.push_back(w);
+            walk_length += 1;
+        }
+        total_steps += walk_length;
+        if !w.is_distinguished(mask) {
+            continue;
+        }
+        if w.point.is_infinity() {
+            if let Some(k) = solve(&w, &WalkPoint { point: w.point, a: Scalar::zero(), b: Scalar::zero() }) {
+                return Some(k)
+            }
+            continue;
+        }
+        match distinguished.get(&w.point) {
+            Some(seen) => {
+                if let Some(k) = solve(seen, &w) {
+                    return Some(k)
+                }
+            },
+            None => {
+                distinguished.insert(w.point, w);
+            }
+        }
+    }
+    None
+}
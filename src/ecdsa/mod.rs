@@ -0,0 +1,8 @@
+//!ECDSA (SEC1 section 4.1) over any curve, on SHA-256 digests or on prehashed
+//!scalars, with random or RFC 6979 deterministic nonces and signatures as the pair
+//!(r, s), encoded raw or in DER.
+pub use signature::{bits_to_scalar, sign, sign_deterministic, sign_prehashed, verify, verify_der, verify_prehashed, Signature};
+pub use rfc6979::{rfc6979_nonce, rfc6979_nonces};
+
+mod signature;
+mod rfc6979;
@@ -0,0 +1,36 @@
+use ring::hmac::{sign, Key, HMAC_SHA256};
+
+use crate::{ecdsa::bits_to_scalar, types::{Scalar, EC, U256}};
+
+///Deterministic nonce of RFC 6979 section 3.2 with HMAC-SHA256 for the secret and
+///the digest of the message, the same inputs always give the same k in [1, N)
+pub fn rfc6979_nonce<E: EC>(secret: Scalar<E>, hash: &[u8]) -> Scalar<E> {
+    rfc6979_nonces(secret, hash).next().unwrap()
+}
+
+///Every k of RFC 6979 section 3.2 in order, the first one is rfc6979_nonce. Step h
+///moves on to the next k when one does not give a valid signature, so this never ends.
+pub fn rfc6979_nonces<E: EC>(secret: Scalar<E>, hash: &[u8]) -> impl Iterator<Item = Scalar<E>> {
+    let x = secret.to_be_bytes();
+    let h = bits_to_scalar::<E>(hash).to_be_bytes();
+    let hmac = |k: &[u8], data: &[&[u8]]| sign(&Key::new(HMAC_SHA256, k), &data.concat()).as_ref().to_vec();
+    let (mut k, mut v) = (vec![0u8; 32], vec![1u8; 32]);
+    k = hmac(&k, &[&v, &[0], &x, &h]);
+    v = hmac(&k, &[&v]);
+    k = hmac(&k, &[&v, &[1], &x, &h]);
+    v = hmac(&k, &[&v]);
+    let mut first = true;
+    std::iter::from_fn(move || loop {
+        if !first {
+            k = hmac(&k, &[&v, &[0]]);
+            v = hmac(&k, &[&v]);
+        }
+        first = false;
+        //N has at most 256 bits, so one block of HMAC output is enough
+        v = hmac(&k, &[&v]);
+        let candidate = U256::from_big_endian(&v) >> (256 - E::N.bits());
+        if !candidate.is_zero() && candidate < E::N {
+            return Some(Scalar::new(candidate))
+        }
+    })
+}
@@ -0,0 +1,119 @@
+use ring::digest::{digest, SHA256};
+
+use crate::{der::asn1::{integer, tlv, Reader, SEQUENCE}, ecdsa::rfc6979_nonces, types::{ECpoint, Scalar, Generator, EC, U256, U512}, utils::multiscalar_mul};
+
+///ECDSA signature (r, s)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature<E: EC> {
+    pub r: Scalar<E>,
+    pub s: Scalar<E>,
+}
+
+impl<E: EC> Signature<E> {
+    ///(r, -s) is valid as well, low s takes the one with s <= N / 2 as BIP146 does
+    pub fn normalize_s(&self) -> Self {
+        match self.s.unwrap() > E::N >> 1 {
+            true => Self { r: self.r, s: -self.s },
+            false => *self
+        }
+    }
+    ///r || s, both big endian of the byte length of N
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.r.to_be_bytes(), self.s.to_be_bytes()].concat()
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let len = E::N.bits().div_ceil(8);
+        if bytes.len() != 2 * len {
+            return None
+        }
+        Some(Self { r: Scalar::from_be_bytes(&bytes[..len])?, s: Scalar::from_be_bytes(&bytes[len..])? })
+    }
+    ///SEQUENCE { INTEGER r, INTEGER s } of X9.62 and RFC 3279
+    pub fn to_der(&self) -> Vec<u8> {
+        tlv(SEQUENCE, &[integer(&self.r.to_be_bytes()), integer(&self.s.to_be_bytes())].concat())
+    }
+    ///Strict DER, None for non minimal lengths or integers, negative integers, trailing
+    ///data, or r or s not below N
+    pub fn from_der(der: &[u8]) -> Option<Self> {
+        let mut outer = Reader::new(der);
+        let mut reader = Reader::new(outer.read(SEQUENCE).ok()?);
+        outer.finish().ok()?;
+        let len = E::N.bits().div_ceil(8);
+        let mut scalar = || {
+            let bytes = reader.read_unsigned().ok()?;
+            match bytes.len() <= len {
+                true => Scalar::from_be_bytes(&[vec![0; len - bytes.len()], bytes.to_vec()].concat()),
+                false => None
+            }
+        };
+        let (r, s) = (scalar()?, scalar()?);
+        reader.finish().ok()?;
+        Some(Self { r, s })
+    }
+}
+
+///bits2int of RFC 6979, the leftmost bits of the digest as many as N has, mod N.
+///Digests longer than 64 bytes are truncated to 64 bytes first.
+pub fn bits_to_scalar<E: EC>(hash: &[u8]) -> Scalar<E> {
+    let hash = &hash[..hash.len().min(64)];
+    let value = U512::from_big_endian(hash) >> (8 * hash.len()).saturating_sub(E::N.bits());
+    Scalar::new(U256::try_from(value % U512::from(E::N)).unwrap())
+}
+
+///r = (k * G).x mod N and s = k^-1 * (z + r * d), None if either is zero, so a fresh
+///nonce has to be picked. Reusing a nonce for two digests reveals the secret.
+pub fn sign_prehashed<E: Generator>(secret: Scalar<E>, z: Scalar<E>, nonce: Scalar<E>) -> Option<Signature<E>> {
+    let point = E::generator() * nonce;
+    if point.is_infinity() {
+        return None
+    }
+    let r = Scalar::new(point.x().unwrap());
+    let s = (z + r * secret) / nonce;
+    match r.is_zero() || s.is_zero() {
+        true => None,
+        false => Some(Signature { r, s })
+    }
+}
+
+///Signs the SHA-256 digest of msg with a random nonce
+pub fn sign<E: Generator>(secret: Scalar<E>, msg: &[u8]) -> Signature<E> {
+    let z = bits_to_scalar(digest(&SHA256, msg).as_ref());
+    loop {
+        if let Some(signature) = sign_prehashed(secret, z, Scalar::generate_secret()) {
+            return signature
+        }
+    }
+}
+
+///Signs the SHA-256 digest of msg with the RFC 6979 nonce. If r or s is zero, which
+///only small curves are likely to hit, the next nonce of step h is used.
+pub fn sign_deterministic<E: Generator>(secret: Scalar<E>, msg: &[u8]) -> Signature<E> {
+    let hash = digest(&SHA256, msg);
+    let z = bits_to_scalar(hash.as_ref());
+    let mut nonces = rfc6979_nonces(secret, hash.as_ref());
+    loop {
+        if let Some(signature) = nonces.next().and_then(|k| sign_prehashed(secret, z, k)) {
+            return signature
+        }
+    }
+}
+
+///Checks (z * s^-1 * G + r * s^-1 * Q).x mod N == r
+pub fn verify_prehashed<E: Generator>(public: &ECpoint<E>, z: Scalar<E>, signature: &Signature<E>) -> bool {
+    let Signature { r, s } = *signature;
+    if r.is_zero() || s.is_zero() || public.is_infinity() {
+        return false
+    }
+    let w = Scalar::one() / s;
+    let point = multiscalar_mul(&[z * w, r * w], &[E::generator(), *public]);
+    !point.is_infinity() && Scalar::new(point.x().unwrap()) == r
+}
+
+pub fn verify<E: Generator>(public: &ECpoint<E>, msg: &[u8], signature: &Signature<E>) -> bool {
+    verify_prehashed(public, bits_to_scalar(digest(&SHA256, msg).as_ref()), signature)
+}
+
+///Verifies a DER encoded signature, which has to be strict DER with r and s below N
+pub fn verify_der<E: Generator>(public: &ECpoint<E>, msg: &[u8], der: &[u8]) -> bool {
+    Signature::from_der(der).is_some_and(|signature| verify(public, msg, &signature))
+}
@@ -0,0 +1,46 @@
+use ring::aead;
+
+use crate::{ecdh::SharedSecret, ecies::Error};
+
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+
+///The AEAD sealing the payload, both take a 256 bit key, 96 bit nonce and 128 bit tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aead {
+    Aes256Gcm,
+    ChaCha20Poly1305,
+}
+
+impl Aead {
+    fn algorithm(&self) -> &'static aead::Algorithm {
+        match self {
+            Aead::Aes256Gcm => &aead::AES_256_GCM,
+            Aead::ChaCha20Poly1305 => &aead::CHACHA20_POLY1305,
+        }
+    }
+    ///HKDF info, binds the derived key to the algorithm
+    fn info(&self) -> &'static [u8] {
+        match self {
+            Aead::Aes256Gcm => b"ECIES AES-256-GCM",
+            Aead::ChaCha20Poly1305 => b"ECIES ChaCha20-Poly1305",
+        }
+    }
+    ///HKDF-SHA256 of the shared secret salted with the encoded ephemeral key
+    fn key(&self, shared: &SharedSecret, ephemeral: &[u8]) -> aead::LessSafeKey {
+        let mut key = [0u8; 32];
+        shared.hkdf_sha256(ephemeral, self.info(), &mut key).expect("32 bytes is a valid HKDF-SHA256 length");
+        aead::LessSafeKey::new(aead::UnboundKey::new(self.algorithm(), &key).expect("key length matches the algorithm"))
+    }
+    pub(crate) fn seal(&self, shared: &SharedSecret, ephemeral: &[u8], nonce: [u8; NONCE_LEN], aad: &[u8], in_out: &mut Vec<u8>) {
+        self.key(shared, ephemeral)
+            .seal_in_place_append_tag(aead::Nonce::assume_unique_for_key(nonce), aead::Aad::from(aad), in_out)
+            .expect("plaintext is within the AEAD limits");
+    }
+    pub(crate) fn open(&self, shared: &SharedSecret, ephemeral: &[u8], nonce: [u8; NONCE_LEN], aad: &[u8], in_out: &mut [u8]) -> Result<usize, Error> {
+        self.key(shared, ephemeral)
+            .open_in_place(aead::Nonce::assume_unique_for_key(nonce), aead::Aad::from(aad), in_out)
+            .map(|plaintext| plaintext.len())
+            .map_err(|_| Error::DecryptionFailed)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{ecdh::{PublicKey, StaticSecret}, ecies::{aead::{NONCE_LEN, TAG_LEN}, Aead, Error}, types::Generator};
+
+///Decrypts the output of encrypt. The ephemeral key may be compressed or not, it is
+///validated before the key agreement, so invalid or off curve points are rejected.
+pub fn decrypt<E: Generator>(recipient: &StaticSecret<E>, ciphertext: &[u8], aad: &[u8], aead: Aead) -> Result<Vec<u8>, Error> {
+    let len = E::P.bits().div_ceil(8);
+    let key_len = match ciphertext.first() {
+        Some(0x04) => 1 + 2 * len,
+        Some(0x02 | 0x03) => 1 + len,
+        Some(_) => return Err(Error::Ecdh(crate::ecdh::Error::InvalidEncoding)),
+        None => return Err(Error::InvalidLength)
+    };
+    if ciphertext.len() < key_len + NONCE_LEN + TAG_LEN {
+        return Err(Error::InvalidLength)
+    }
+    let (ephemeral, rest) = ciphertext.split_at(key_len);
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+    let shared = recipient.diffie_hellman(&PublicKey::from_sec1(ephemeral)?)?;
+    let mut in_out = sealed.to_vec();
+    let plaintext_len = aead.open(&shared, ephemeral, nonce.try_into().unwrap(), aad, &mut in_out)?;
+    in_out.truncate(plaintext_len);
+    Ok(in_out)
+}
@@ -0,0 +1,15 @@
+use crate::{ecdh::{EphemeralSecret, PublicKey}, ecies::{aead::NONCE_LEN, Aead, Error}, types::Generator};
+
+///Encrypts plaintext to the recipient's public key. A fresh ephemeral key is agreed
+///with the recipient, the AEAD key is HKDF-SHA256 of the shared secret salted with
+///the ephemeral key. Returns the uncompressed SEC1 ephemeral key || nonce ||
+///ciphertext || tag, aad is authenticated but not included.
+pub fn encrypt<E: Generator>(recipient: &PublicKey<E>, plaintext: &[u8], aad: &[u8], aead: Aead) -> Result<Vec<u8>, Error> {
+    let ephemeral = EphemeralSecret::<E>::random();
+    let ephemeral_public = ephemeral.public_key().to_sec1(false);
+    let shared = ephemeral.diffie_hellman(recipient)?;
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let mut sealed = plaintext.to_vec();
+    aead.seal(&shared, &ephemeral_public, nonce, aad, &mut sealed);
+    Ok([ephemeral_public, nonce.to_vec(), sealed].concat())
+}
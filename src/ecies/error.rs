@@ -0,0 +1,30 @@
+use crate::ecdh;
+
+///Reasons ECIES encryption or decryption can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///the ephemeral or recipient key is invalid, e.g. off curve
+    Ecdh(ecdh::Error),
+    ///the ciphertext is too short to hold the ephemeral key, nonce and tag
+    InvalidLength,
+    ///the tag does not authenticate the ciphertext and associated data
+    DecryptionFailed,
+}
+
+impl From<ecdh::Error> for Error {
+    fn from(value: ecdh::Error) -> Self {
+        Error::Ecdh(value)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Ecdh(e) => write!(f, "key agreement failed: {}", e),
+            Error::InvalidLength => write!(f, "ciphertext too short"),
+            Error::DecryptionFailed => write!(f, "decryption failed"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
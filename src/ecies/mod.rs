@@ -0,0 +1,12 @@
+//!Elliptic curve integrated encryption scheme, hybrid encryption to an ECpoint public
+//!key with ECDH, HKDF-SHA256 and an AEAD from ring. Wire format is the SEC1 ephemeral
+//!public key || 12 byte nonce || ciphertext || 16 byte tag.
+pub use aead::Aead;
+pub use error::Error;
+pub use encrypt::encrypt;
+pub use decrypt::decrypt;
+
+mod aead;
+mod error;
+mod encrypt;
+mod decrypt;
@@ -0,0 +1,65 @@
+use crate::types::{ECpoint, Scalar, Generator, EC};
+
+///EC-ElGamal ciphertext (r * G, M + r * Pub) of the message point M = m * G
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ciphertext<E: EC> {
+    pub c1: ECpoint<E>,
+    pub c2: ECpoint<E>,
+}
+
+impl<E: Generator> Ciphertext<E> {
+    ///Encryption of zero with no randomness, the neutral element of the addition
+    pub fn zero() -> Self {
+        Self { c1: ECpoint::Infinity, c2: ECpoint::Infinity }
+    }
+    ///Adds a fresh encryption of zero, the result decrypts to the same message but
+    ///can not be linked to the original ciphertext
+    pub fn rerandomize(&self, public: ECpoint<E>) -> Self {
+        let r = Scalar::generate_secret();
+        Self { c1: self.c1 + E::generator() * r, c2: self.c2 + public * r }
+    }
+}
+
+///Encryption of m1 + m2
+impl<E: EC> std::ops::Add for Ciphertext<E> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { c1: self.c1 + rhs.c1, c2: self.c2 + rhs.c2 }
+    }
+}
+
+impl<E: EC> std::ops::AddAssign for Ciphertext<E> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+///Encryption of -m
+impl<E: EC> std::ops::Neg for Ciphertext<E> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { c1: -self.c1, c2: -self.c2 }
+    }
+}
+
+///Encryption of m1 - m2
+impl<E: EC> std::ops::Sub for Ciphertext<E> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+///Encryption of k * m
+impl<E: EC> std::ops::Mul<Scalar<E>> for Ciphertext<E> {
+    type Output = Self;
+    fn mul(self, rhs: Scalar<E>) -> Self::Output {
+        Self { c1: self.c1 * rhs, c2: self.c2 * rhs }
+    }
+}
+
+impl<E: Generator> std::iter::Sum for Ciphertext<E> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, ciphertext| acc + ciphertext)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{ecdlp::baby_step_giant_step_bounded, elgamal::Ciphertext, types::{ECpoint, Scalar, Generator, EC, U256}};
+
+///Decrypts to the message point M = c2 - x * c1 = m * G
+pub fn decrypt_to_point<E: EC>(secret: Scalar<E>, ciphertext: &Ciphertext<E>) -> ECpoint<E> {
+    ciphertext.c2 - ciphertext.c1 * secret
+}
+
+///Decrypts to the integer message m, knowing 0 <= m < bound. The discrete log of the
+///message point is found with baby-step giant-step in O(sqrt(bound)) time and memory,
+///so this is only feasible for small messages like vote tallies. None if m is not
+///in range.
+pub fn decrypt<E: Generator, T: Into<U256>>(secret: Scalar<E>, ciphertext: &Ciphertext<E>, bound: T) -> Option<U256> {
+    baby_step_giant_step_bounded(E::generator(), decrypt_to_point(secret, ciphertext), bound)
+}
@@ -0,0 +1,13 @@
+use crate::{elgamal::Ciphertext, types::{ECpoint, Scalar, Generator}};
+
+///Encrypts the message m as (r * G, m * G + r * Pub) with a random r
+pub fn encrypt<E: Generator>(public: ECpoint<E>, m: Scalar<E>) -> Ciphertext<E> {
+    encrypt_with_randomness(public, m, Scalar::generate_secret())
+}
+
+///Encrypts the message m as (r * G, m * G + r * Pub). r has to be secret and never
+///reused, knowing it decrypts the ciphertext.
+pub fn encrypt_with_randomness<E: Generator>(public: ECpoint<E>, m: Scalar<E>, r: Scalar<E>) -> Ciphertext<E> {
+    let g = E::generator();
+    Ciphertext { c1: g * r, c2: g * m + public * r }
+}
@@ -0,0 +1,7 @@
+use crate::types::{ECpoint, Scalar, Generator};
+
+///Generates a key pair (x, Pub = x * G)
+pub fn keygen<E: Generator>() -> (Scalar<E>, ECpoint<E>) {
+    let secret = Scalar::generate_secret();
+    (secret, E::generator() * secret)
+}
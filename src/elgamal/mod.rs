@@ -0,0 +1,12 @@
+//!Additively homomorphic EC-ElGamal encryption. The message m is encrypted as the
+//!point m * G, so ciphertexts can be added and multiplied by scalars, and decryption
+//!back to an integer is only feasible for messages in a small known range.
+pub use ciphertext::Ciphertext;
+pub use keygen::keygen;
+pub use encrypt::{encrypt, encrypt_with_randomness};
+pub use decrypt::{decrypt, decrypt_to_point};
+
+mod ciphertext;
+mod keygen;
+mod encrypt;
+mod decrypt;
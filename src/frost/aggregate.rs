@@ -0,0 +1,41 @@
+use std::collections::BTreeMap;
+
+use crate::{frost::{compute_binding_factors, compute_group_commitment, verify_signature_share, Ciphersuite, Error, PublicKeyPackage, Signature, SigningCommitments}, types::Scalar};
+
+///Sums the signature shares into a Schnorr signature (R, z), at least min_signers of
+///them are needed. If the signature does not verify, the shares are checked one by
+///one to find the cheater.
+pub fn aggregate<C: Ciphersuite>(
+    commitments: &[SigningCommitments<C>],
+    msg: &[u8],
+    signature_shares: &BTreeMap<Scalar<C::Curve>, Scalar<C::Curve>>,
+    public_key_package: &PublicKeyPackage<C>,
+) -> Result<Signature<C>, Error<C::Curve>> {
+    let group_public_key = &public_key_package.group_public_key;
+    if signature_shares.len() < public_key_package.min_signers {
+        return Err(Error::InvalidParameters)
+    }
+    if commitments.len() != signature_shares.len() {
+        return Err(Error::MissingCommitment)
+    }
+    let binding_factors = compute_binding_factors(group_public_key, commitments, msg)?;
+    let r = compute_group_commitment(commitments, &binding_factors);
+    if r.is_infinity() {
+        return Err(Error::IdentityCommitment)
+    }
+    let mut z = Scalar::zero();
+    for c in commitments {
+        z += *signature_shares.get(&c.identifier).ok_or(Error::MissingCommitment)?;
+    }
+    let signature = Signature { r, z };
+    if signature.verify(msg, group_public_key) {
+        return Ok(signature)
+    }
+    for (identifier, share) in signature_shares {
+        let verifying_share = public_key_package.verifying_shares.get(identifier).ok_or(Error::InvalidIdentifier)?;
+        if !verify_signature_share(*identifier, verifying_share, share, commitments, group_public_key, msg) {
+            return Err(Error::InvalidSignatureShare(*identifier))
+        }
+    }
+    Err(Error::InvalidSignature)
+}
@@ -0,0 +1,62 @@
+use ring::digest::{digest, SHA256};
+
+use crate::{curves::Secp256k1, types::{ECpoint, Scalar, Generator}, utils::hash_to_scalar};
+
+///A FROST ciphersuite of RFC 9591, the group and the hash functions H1 to H5.
+///The defaults are the ones shared by the SHA-256 based suites: hash_to_field of
+///RFC 9380 with expand_message_xmd and SHA-256, SEC1 compressed elements and
+///big endian scalars.
+pub trait Ciphersuite: std::fmt::Debug + Copy + Eq {
+    type Curve: Generator;
+    ///contextString, prefix of every domain separation tag
+    const CONTEXT_STRING: &'static [u8];
+    ///binding factors
+    fn h1(m: &[u8]) -> Scalar<Self::Curve> {
+        hash_to_scalar(m, &[Self::CONTEXT_STRING, b"rho"].concat())
+    }
+    ///challenge
+    fn h2(m: &[u8]) -> Scalar<Self::Curve> {
+        hash_to_scalar(m, &[Self::CONTEXT_STRING, b"chal"].concat())
+    }
+    ///nonces
+    fn h3(m: &[u8]) -> Scalar<Self::Curve> {
+        hash_to_scalar(m, &[Self::CONTEXT_STRING, b"nonce"].concat())
+    }
+    ///message
+    fn h4(m: &[u8]) -> Vec<u8> {
+        digest(&SHA256, &[Self::CONTEXT_STRING, b"msg", m].concat()).as_ref().to_vec()
+    }
+    ///commitment list
+    fn h5(m: &[u8]) -> Vec<u8> {
+        digest(&SHA256, &[Self::CONTEXT_STRING, b"com", m].concat()).as_ref().to_vec()
+    }
+    ///challenge of the proofs of knowledge in the DKG, not part of RFC 9591
+    fn h_dkg(m: &[u8]) -> Scalar<Self::Curve> {
+        hash_to_scalar(m, &[Self::CONTEXT_STRING, b"dkg"].concat())
+    }
+    fn serialize_element(element: &ECpoint<Self::Curve>) -> Vec<u8> {
+        element.to_sec1(true)
+    }
+    ///None for invalid encodings and the identity
+    fn deserialize_element(bytes: &[u8]) -> Option<ECpoint<Self::Curve>> {
+        match ECpoint::from_sec1(bytes)? {
+            ECpoint::Infinity => None,
+            point => Some(point)
+        }
+    }
+    fn serialize_scalar(scalar: &Scalar<Self::Curve>) -> Vec<u8> {
+        scalar.to_be_bytes()
+    }
+    fn deserialize_scalar(bytes: &[u8]) -> Option<Scalar<Self::Curve>> {
+        Scalar::from_be_bytes(bytes)
+    }
+}
+
+///FROST(secp256k1, SHA-256)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Secp256k1Sha256;
+
+impl Ciphersuite for Secp256k1Sha256 {
+    type Curve = Secp256k1;
+    const CONTEXT_STRING: &'static [u8] = b"FROST-secp256k1-SHA256-v1";
+}
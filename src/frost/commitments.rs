@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use crate::{frost::{Ciphersuite, Error}, sss::lagrange_coefficient, types::{ECpoint, Scalar}};
+
+///Public nonce commitments of a participant, broadcast in round one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SigningCommitments<C: Ciphersuite> {
+    pub identifier: Scalar<C::Curve>,
+    pub hiding: ECpoint<C::Curve>,
+    pub binding: ECpoint<C::Curve>,
+}
+
+///Sorts the commitment list by identifier, rejecting zero and duplicate identifiers
+#[allow(clippy::type_complexity)]
+pub(crate) fn sorted<C: Ciphersuite>(commitments: &[SigningCommitments<C>])
+-> Result<BTreeMap<Scalar<C::Curve>, SigningCommitments<C>>, Error<C::Curve>> {
+    let mut sorted = BTreeMap::new();
+    for c in commitments {
+        if c.identifier.is_zero() || sorted.insert(c.identifier, *c).is_some() {
+            return Err(Error::InvalidIdentifier)
+        }
+    }
+    Ok(sorted)
+}
+
+///Identifier, hiding and binding commitment of every signer, sorted by identifier
+fn encode_group_commitment_list<C: Ciphersuite>(commitments: &BTreeMap<Scalar<C::Curve>, SigningCommitments<C>>) -> Vec<u8> {
+    commitments.values()
+        .flat_map(|c| [
+            C::serialize_scalar(&c.identifier),
+            C::serialize_element(&c.hiding),
+            C::serialize_element(&c.binding)
+        ].concat())
+        .collect()
+}
+
+///Binding factor of every signer, H1(group public key || H4(msg) || H5(commitment list) || identifier)
+#[allow(clippy::type_complexity)]
+pub fn compute_binding_factors<C: Ciphersuite>(group_public_key: &ECpoint<C::Curve>, commitments: &[SigningCommitments<C>], msg: &[u8])
+-> Result<BTreeMap<Scalar<C::Curve>, Scalar<C::Curve>>, Error<C::Curve>> {
+    let commitments = sorted(commitments)?;
+    let prefix = [
+        C::serialize_element(group_public_key),
+        C::h4(msg),
+        C::h5(&encode_group_commitment_list(&commitments))
+    ].concat();
+    Ok(commitments.keys()
+        .map(|&id| (id, C::h1(&[prefix.as_slice(), &C::serialize_scalar(&id)].concat())))
+        .collect())
+}
+
+///R, the sum of hiding + binding_factor * binding of every signer
+pub fn compute_group_commitment<C: Ciphersuite>(commitments: &[SigningCommitments<C>], binding_factors: &BTreeMap<Scalar<C::Curve>, Scalar<C::Curve>>) -> ECpoint<C::Curve> {
+    commitments.iter()
+        .map(|c| c.hiding + c.binding * binding_factors[&c.identifier])
+        .sum()
+}
+
+///H2(R || group public key || msg)
+pub fn compute_challenge<C: Ciphersuite>(group_commitment: &ECpoint<C::Curve>, group_public_key: &ECpoint<C::Curve>, msg: &[u8]) -> Scalar<C::Curve> {
+    C::h2(&[C::serialize_element(group_commitment), C::serialize_element(group_public_key), msg.to_vec()].concat())
+}
+
+///Lagrange coefficient of the signer at 0 over the identifiers of all signers
+pub(crate) fn interpolating_value<C: Ciphersuite>(commitments: &BTreeMap<Scalar<C::Curve>, SigningCommitments<C>>, identifier: Scalar<C::Curve>)
+-> Result<Scalar<C::Curve>, Error<C::Curve>> {
+    let identifiers = commitments.keys().copied().collect::<Vec<_>>();
+    lagrange_coefficient(identifier, &identifiers, Scalar::zero()).ok_or(Error::InvalidIdentifier)
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+
+use crate::{frost::{Ciphersuite, Error, KeyPackage, PublicKeyPackage}, sss::{evaluate_commitments, feldman_verify, Polynomial, Share}, types::{ECpoint, Scalar, Generator, EC}};
+
+///Broadcast by every participant in the first round of the DKG, the Feldman
+///commitment to its polynomial and a Schnorr proof of knowledge of its constant term
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round1Package<C: Ciphersuite> {
+    pub identifier: Scalar<C::Curve>,
+    pub commitment: Vec<ECpoint<C::Curve>>,
+    pub proof_of_knowledge: (ECpoint<C::Curve>, Scalar<C::Curve>),
+}
+
+///Kept by the participant between the first and the second round
+#[derive(PartialEq, Eq)]
+pub struct Round1Secret<C: Ciphersuite> {
+    identifier: Scalar<C::Curve>,
+    polynomial: Polynomial<C::Curve>,
+    commitment: Vec<ECpoint<C::Curve>>,
+    n: usize,
+}
+
+///Kept by the participant between the second and the third round
+#[derive(PartialEq, Eq)]
+pub struct Round2Secret<C: Ciphersuite> {
+    identifier: Scalar<C::Curve>,
+    own_share: Scalar<C::Curve>,
+    commitments: BTreeMap<Scalar<C::Curve>, Vec<ECpoint<C::Curve>>>,
+}
+
+impl<C: Ciphersuite> std::fmt::Debug for Round1Secret<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Round1Secret [curve {}]", <C::Curve as EC>::NAME)
+    }
+}
+
+impl<C: Ciphersuite> std::fmt::Debug for Round2Secret<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Round2Secret [curve {}]", <C::Curve as EC>::NAME)
+    }
+}
+
+///H_dkg(identifier || a_0 * G || R)
+fn pok_challenge<C: Ciphersuite>(identifier: &Scalar<C::Curve>, public: &ECpoint<C::Curve>, r: &ECpoint<C::Curve>) -> Scalar<C::Curve> {
+    C::h_dkg(&[C::serialize_scalar(identifier), C::serialize_element(public), C::serialize_element(r)].concat())
+}
+
+///First round of the Pedersen DKG as in the FROST paper. Picks a random polynomial
+///of degree t - 1 and proves knowledge of its constant term, so nobody can cancel
+///out the keys of the others.
+#[allow(clippy::type_complexity)]
+pub fn dkg_part1<C: Ciphersuite>(identifier: Scalar<C::Curve>, t: usize, n: usize)
+-> Result<(Round1Secret<C>, Round1Package<C>), Error<C::Curve>> {
+    if t == 0 || t > n || C::Curve::N <= n.into() {
+        return Err(Error::InvalidParameters)
+    }
+    if identifier.is_zero() {
+        return Err(Error::InvalidIdentifier)
+    }
+    let polynomial = Polynomial::random(Scalar::generate_secret(), t - 1);
+    let g = C::Curve::generator();
+    let commitment = polynomial.coefficients().iter().map(|&a| g * a).collect::<Vec<_>>();
+    let k = Scalar::generate_secret();
+    let r = g * k;
+    let mu = k + polynomial.coefficients()[0] * pok_challenge::<C>(&identifier, &commitment[0], &r);
+    let package = Round1Package { identifier, commitment: commitment.clone(), proof_of_knowledge: (r, mu) };
+    Ok((Round1Secret { identifier, polynomial, commitment, n }, package))
+}
+
+///Second round, checks the proofs of knowledge of the other n - 1 participants and
+///computes the secret share f_i(j) for each of them, to be sent privately
+#[allow(clippy::type_complexity)]
+pub fn dkg_part2<C: Ciphersuite>(secret: Round1Secret<C>, round1_packages: &[Round1Package<C>])
+-> Result<(Round2Secret<C>, BTreeMap<Scalar<C::Curve>, Scalar<C::Curve>>), Error<C::Curve>> {
+    if round1_packages.len() + 1 != secret.n {
+        return Err(Error::InvalidParameters)
+    }
+    let t = secret.commitment.len();
+    let g = C::Curve::generator();
+    let mut commitments = BTreeMap::from([(secret.identifier, secret.commitment)]);
+    let mut shares = BTreeMap::new();
+    for package in round1_packages {
+        let id = package.identifier;
+        if id.is_zero() || commitments.contains_key(&id) {
+            return Err(Error::InvalidIdentifier)
+        }
+        if package.commitment.len() != t {
+            return Err(Error::InvalidProofOfKnowledge(id))
+        }
+        let (r, mu) = package.proof_of_knowledge;
+        let c = pok_challenge::<C>(&id, &package.commitment[0], &r);
+        if r != g * mu - package.commitment[0] * c {
+            return Err(Error::InvalidProofOfKnowledge(id))
+        }
+        commitments.insert(id, package.commitment.clone());
+        shares.insert(id, secret.polynomial.evaluate(id));
+    }
+    let own_share = secret.polynomial.evaluate(secret.identifier);
+    Ok((Round2Secret { identifier: secret.identifier, own_share, commitments }, shares))
+}
+
+///Third round, checks the received shares against the senders' commitments. The
+///signing share is the sum of all shares, the group public key the sum of all
+///constant term commitments.
+#[allow(clippy::type_complexity)]
+pub fn dkg_part3<C: Ciphersuite>(secret: &Round2Secret<C>, received_shares: &BTreeMap<Scalar<C::Curve>, Scalar<C::Curve>>)
+-> Result<(KeyPackage<C>, PublicKeyPackage<C>), Error<C::Curve>> {
+    if received_shares.len() + 1 != secret.commitments.len() {
+        return Err(Error::InvalidParameters)
+    }
+    let mut signing_share = secret.own_share;
+    for (sender, &value) in received_shares {
+        let commitment = secret.commitments.get(sender).filter(|_| *sender != secret.identifier).ok_or(Error::InvalidIdentifier)?;
+        if !feldman_verify(&Share { index: secret.identifier, value }, commitment) {
+            return Err(Error::InvalidSecretShare(*sender))
+        }
+        signing_share += value;
+    }
+    let group_public_key = secret.commitments.values().map(|c| c[0]).sum();
+    let verifying_shares = secret.commitments.keys()
+        .map(|&id| (id, secret.commitments.values().map(|c| evaluate_commitments(c, id)).sum()))
+        .collect::<BTreeMap<_, ECpoint<C::Curve>>>();
+    let key_package = KeyPackage {
+        identifier: secret.identifier,
+        signing_share,
+        verifying_share: verifying_shares[&secret.identifier],
+        group_public_key,
+    };
+    let min_signers = secret.commitments[&secret.identifier].len();
+    Ok((key_package, PublicKeyPackage { verifying_shares, group_public_key, min_signers }))
+}
@@ -0,0 +1,42 @@
+use crate::types::{Scalar, EC};
+
+///Reasons a FROST operation can fail, identifiers point at the misbehaving participant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E: EC> {
+    ///threshold or number of participants out of range
+    InvalidParameters,
+    ///an identifier is zero or appears twice
+    InvalidIdentifier,
+    ///the commitment list has no entry for the signer
+    MissingCommitment,
+    ///the group commitment is the identity
+    IdentityCommitment,
+    ///a signature share does not verify
+    InvalidSignatureShare(Scalar<E>),
+    ///the aggregated signature does not verify although every share does
+    InvalidSignature,
+    ///a DKG proof of knowledge does not verify
+    InvalidProofOfKnowledge(Scalar<E>),
+    ///a DKG secret share does not match the sender's commitment
+    InvalidSecretShare(Scalar<E>),
+    ///bytes are not a valid encoding
+    InvalidEncoding,
+}
+
+impl<E: EC> std::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidParameters => write!(f, "invalid threshold or number of participants"),
+            Error::InvalidIdentifier => write!(f, "invalid or duplicate identifier"),
+            Error::MissingCommitment => write!(f, "missing commitment of the signer"),
+            Error::IdentityCommitment => write!(f, "group commitment is the identity"),
+            Error::InvalidSignatureShare(id) => write!(f, "invalid signature share of participant {}", id),
+            Error::InvalidSignature => write!(f, "invalid aggregated signature"),
+            Error::InvalidProofOfKnowledge(id) => write!(f, "invalid proof of knowledge of participant {}", id),
+            Error::InvalidSecretShare(id) => write!(f, "invalid secret share from participant {}", id),
+            Error::InvalidEncoding => write!(f, "invalid encoding"),
+        }
+    }
+}
+
+impl<E: EC + std::fmt::Debug> std::error::Error for Error<E> {}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use crate::{frost::{Ciphersuite, Error, KeyPackage, PublicKeyPackage}, sss::Polynomial, types::{ECpoint, Scalar, Generator, EC}};
+
+///Key generation by a trusted dealer who knows the group secret key. It is split
+///into n shares with identifiers 1..=n, any t of them can sign. Also returns the
+///VSS commitment a_j * G to the coefficients, see sss::feldman_verify.
+#[allow(clippy::type_complexity)]
+pub fn trusted_dealer_keygen<C: Ciphersuite>(secret: Scalar<C::Curve>, t: usize, n: usize)
+-> Result<(Vec<KeyPackage<C>>, PublicKeyPackage<C>, Vec<ECpoint<C::Curve>>), Error<C::Curve>> {
+    if t == 0 {
+        return Err(Error::InvalidParameters)
+    }
+    let polynomial = Polynomial::random(secret, t - 1);
+    trusted_dealer_keygen_with_coefficients(polynomial.coefficients(), n)
+}
+
+///Trusted dealer key generation with the given polynomial coefficients, the first
+///one being the group secret key. Meant for test vectors, the other coefficients
+///have to be random and secret.
+#[allow(clippy::type_complexity)]
+pub fn trusted_dealer_keygen_with_coefficients<C: Ciphersuite>(coefficients: &[Scalar<C::Curve>], n: usize)
+-> Result<(Vec<KeyPackage<C>>, PublicKeyPackage<C>, Vec<ECpoint<C::Curve>>), Error<C::Curve>> {
+    let t = coefficients.len();
+    if t == 0 || t > n || C::Curve::N <= n.into() {
+        return Err(Error::InvalidParameters)
+    }
+    let polynomial = Polynomial::new(coefficients.to_vec());
+    let g = C::Curve::generator();
+    let group_public_key = g * coefficients[0];
+    let key_packages = (1..=n as u64)
+        .map(Scalar::new)
+        .map(|identifier| {
+            let signing_share = polynomial.evaluate(identifier);
+            KeyPackage { identifier, signing_share, verifying_share: g * signing_share, group_public_key }
+        })
+        .collect::<Vec<KeyPackage<C>>>();
+    let verifying_shares = key_packages.iter()
+        .map(|k| (k.identifier, k.verifying_share))
+        .collect::<BTreeMap<_, _>>();
+    let commitment = coefficients.iter().map(|&a| g * a).collect();
+    Ok((key_packages, PublicKeyPackage { verifying_shares, group_public_key, min_signers: t }, commitment))
+}
@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+
+use crate::{frost::Ciphersuite, types::{ECpoint, Scalar}};
+
+///Everything a participant needs to sign
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPackage<C: Ciphersuite> {
+    pub identifier: Scalar<C::Curve>,
+    ///the participant's share of the group secret key
+    pub signing_share: Scalar<C::Curve>,
+    ///signing_share * G
+    pub verifying_share: ECpoint<C::Curve>,
+    pub group_public_key: ECpoint<C::Curve>,
+}
+
+///Public data to verify signature shares and signatures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKeyPackage<C: Ciphersuite> {
+    pub verifying_shares: BTreeMap<Scalar<C::Curve>, ECpoint<C::Curve>>,
+    pub group_public_key: ECpoint<C::Curve>,
+    ///the threshold t, how many signature shares a signature needs
+    pub min_signers: usize,
+}
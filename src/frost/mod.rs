@@ -0,0 +1,24 @@
+//!FROST, Flexible Round-Optimized Schnorr Threshold signatures as in RFC 9591.
+//!Any t of n participants produce a Schnorr signature under the group public key
+//!in two rounds, without ever reconstructing the group secret key.
+pub use ciphersuite::{Ciphersuite, Secp256k1Sha256};
+pub use error::Error;
+pub use keys::{KeyPackage, PublicKeyPackage};
+pub use keygen::{trusted_dealer_keygen, trusted_dealer_keygen_with_coefficients};
+pub use dkg::{dkg_part1, dkg_part2, dkg_part3, Round1Package, Round1Secret, Round2Secret};
+pub use commitments::{compute_binding_factors, compute_challenge, compute_group_commitment, SigningCommitments};
+pub use round1::{commit, commit_with_randomness, nonce_generate_with_randomness, SigningNonces};
+pub use round2::{sign, verify_signature_share};
+pub use aggregate::aggregate;
+pub use signature::Signature;
+
+mod ciphersuite;
+mod error;
+mod keys;
+mod keygen;
+mod dkg;
+mod commitments;
+mod round1;
+mod round2;
+mod aggregate;
+mod signature;
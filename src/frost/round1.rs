@@ -0,0 +1,44 @@
+use crate::{frost::{Ciphersuite, KeyPackage, SigningCommitments}, types::{Scalar, Generator, EC}};
+
+///Secret nonces of a participant for one signing session. Not Clone, sign consumes
+///them so they can not be used twice.
+#[derive(PartialEq, Eq)]
+pub struct SigningNonces<C: Ciphersuite> {
+    pub(crate) hiding: Scalar<C::Curve>,
+    pub(crate) binding: Scalar<C::Curve>,
+    pub(crate) commitments: SigningCommitments<C>,
+}
+
+impl<C: Ciphersuite> std::fmt::Debug for SigningNonces<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SigningNonces [curve {}]", <C::Curve as EC>::NAME)
+    }
+}
+
+impl<C: Ciphersuite> SigningNonces<C> {
+    pub fn commitments(&self) -> SigningCommitments<C> {
+        self.commitments
+    }
+}
+
+///H3(random_bytes || secret), the secret is mixed in so a bad random number
+///generator alone does not leak the key
+pub fn nonce_generate_with_randomness<C: Ciphersuite>(random_bytes: &[u8; 32], secret: &Scalar<C::Curve>) -> Scalar<C::Curve> {
+    C::h3(&[random_bytes.as_slice(), &C::serialize_scalar(secret)].concat())
+}
+
+///Round one, generates the hiding and binding nonces and their commitments. The
+///commitments are sent to the coordinator, the nonces kept for round two.
+pub fn commit<C: Ciphersuite>(key_package: &KeyPackage<C>) -> (SigningNonces<C>, SigningCommitments<C>) {
+    commit_with_randomness(key_package, &rand::random(), &rand::random())
+}
+
+///Round one with the given randomness, meant for test vectors
+pub fn commit_with_randomness<C: Ciphersuite>(key_package: &KeyPackage<C>, hiding_randomness: &[u8; 32], binding_randomness: &[u8; 32])
+-> (SigningNonces<C>, SigningCommitments<C>) {
+    let hiding = nonce_generate_with_randomness::<C>(hiding_randomness, &key_package.signing_share);
+    let binding = nonce_generate_with_randomness::<C>(binding_randomness, &key_package.signing_share);
+    let g = C::Curve::generator();
+    let commitments = SigningCommitments { identifier: key_package.identifier, hiding: g * hiding, binding: g * binding };
+    (SigningNonces { hiding, binding, commitments }, commitments)
+}
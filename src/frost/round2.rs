@@ -0,0 +1,42 @@
+use crate::{frost::{commitments::{interpolating_value, sorted}, compute_binding_factors, compute_challenge, compute_group_commitment, Ciphersuite, Error, KeyPackage, SigningCommitments, SigningNonces}, types::{ECpoint, Scalar, Generator}};
+
+///Round two, computes the signature share
+///hiding + binding * binding_factor + lambda * signing_share * challenge.
+///The nonces are consumed, the commitment list has to contain their commitments.
+pub fn sign<C: Ciphersuite>(msg: &[u8], nonces: SigningNonces<C>, key_package: &KeyPackage<C>, commitments: &[SigningCommitments<C>])
+-> Result<Scalar<C::Curve>, Error<C::Curve>> {
+    let sorted = sorted(commitments)?;
+    if sorted.get(&key_package.identifier) != Some(&nonces.commitments) {
+        return Err(Error::MissingCommitment)
+    }
+    let binding_factors = compute_binding_factors(&key_package.group_public_key, commitments, msg)?;
+    let group_commitment = compute_group_commitment(commitments, &binding_factors);
+    if group_commitment.is_infinity() {
+        return Err(Error::IdentityCommitment)
+    }
+    let lambda = interpolating_value(&sorted, key_package.identifier)?;
+    let challenge = compute_challenge::<C>(&group_commitment, &key_package.group_public_key, msg);
+    Ok(nonces.hiding + nonces.binding * binding_factors[&key_package.identifier] + lambda * key_package.signing_share * challenge)
+}
+
+///Checks z_i * G == hiding + binding * binding_factor + verifying_share * lambda * challenge
+pub fn verify_signature_share<C: Ciphersuite>(
+    identifier: Scalar<C::Curve>,
+    verifying_share: &ECpoint<C::Curve>,
+    signature_share: &Scalar<C::Curve>,
+    commitments: &[SigningCommitments<C>],
+    group_public_key: &ECpoint<C::Curve>,
+    msg: &[u8],
+) -> bool {
+    let check = || -> Result<bool, Error<C::Curve>> {
+        let sorted = sorted(commitments)?;
+        let commitment = sorted.get(&identifier).ok_or(Error::MissingCommitment)?;
+        let binding_factors = compute_binding_factors(group_public_key, commitments, msg)?;
+        let group_commitment = compute_group_commitment(commitments, &binding_factors);
+        let lambda = interpolating_value(&sorted, identifier)?;
+        let challenge = compute_challenge::<C>(&group_commitment, group_public_key, msg);
+        let commitment_share = commitment.hiding + commitment.binding * binding_factors[&identifier];
+        Ok(C::Curve::generator() * *signature_share == commitment_share + *verifying_share * (lambda * challenge))
+    };
+    check().unwrap_or(false)
+}
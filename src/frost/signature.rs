@@ -0,0 +1,31 @@
+use crate::{frost::{compute_challenge, Ciphersuite, Error}, types::{ECpoint, Scalar, Generator, EC}};
+
+///Schnorr signature (R, z), verifies with the group public key PK as z * G == R + c * PK
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Signature<C: Ciphersuite> {
+    pub r: ECpoint<C::Curve>,
+    pub z: Scalar<C::Curve>,
+}
+
+impl<C: Ciphersuite> Signature<C> {
+    ///Plain Schnorr verification with c = H2(R || PK || msg), nothing reveals
+    ///that the signature was produced by a threshold of participants
+    pub fn verify(&self, msg: &[u8], public_key: &ECpoint<C::Curve>) -> bool {
+        if self.r.is_infinity() || public_key.is_infinity() {
+            return false
+        }
+        let challenge = compute_challenge::<C>(&self.r, public_key, msg);
+        let check = C::Curve::generator() * self.z - self.r - *public_key * challenge;
+        check.mul_integer(C::Curve::H).is_infinity()
+    }
+    ///SerializeElement(R) || SerializeScalar(z)
+    pub fn serialize(&self) -> Vec<u8> {
+        [C::serialize_element(&self.r), C::serialize_scalar(&self.z)].concat()
+    }
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C::Curve>> {
+        let split = bytes.len().checked_sub(C::Curve::N.bits().div_ceil(8)).ok_or(Error::InvalidEncoding)?;
+        let r = C::deserialize_element(&bytes[..split]).ok_or(Error::InvalidEncoding)?;
+        let z = C::deserialize_scalar(&bytes[split..]).ok_or(Error::InvalidEncoding)?;
+        Ok(Self { r, z })
+    }
+}
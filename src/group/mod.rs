@@ -0,0 +1,8 @@
+//!Tools to inspect the group of points of small curves, e.g. to generate
+//!training material. Everything here enumerates all points, so it is only
+//!usable for small P.
+pub use points::{points, CurvePoints};
+pub use structure::GroupStructure;
+
+mod points;
+mod structure;
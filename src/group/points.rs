@@ -0,0 +1,46 @@
+use crate::types::{ECpoint, Zp, EC, U256};
+
+///Iterator over all points of the curve E, starting with the point at infinity,
+///followed by the points ordered by x and then by y coordinate.
+pub struct CurvePoints<E: EC> {
+    x: U256,
+    infinity_done: bool,
+    pending: Option<ECpoint<E>>,
+}
+
+///Enumerates all points of the curve E, see CurvePoints
+pub fn points<E: EC>() -> CurvePoints<E> {
+    CurvePoints { x: U256::zero(), infinity_done: false, pending: None }
+}
+
+impl<E: EC> Iterator for CurvePoints<E> {
+    type Item = ECpoint<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.infinity_done {
+            self.infinity_done = true;
+            return Some(ECpoint::Infinity)
+        }
+        if let Some(point) = self.pending.take() {
+            return Some(point)
+        }
+        while self.x < E::P {
+            let x = Zp::<E>::new(self.x);
+            self.x += U256::one();
+            let rhs = x.pow(3) + Zp::new(E::A) * x + Zp::new(E::B);
+            match rhs.sqrt() {
+                Some((y, _)) if y.is_zero() => return ECpoint::new(x, y),
+                Some((y1, y2)) => {
+                    let (y1, y2) = match y1.unwrap() < y2.unwrap() {
+                        true => (y1, y2),
+                        false => (y2, y1)
+                    };
+                    self.pending = ECpoint::new(x, y2);
+                    return ECpoint::new(x, y1)
+                },
+                None => continue,
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{group::points, types::{ECpoint, EC, U256}, utils::{find_factors, is_prime}};
+
+///The abelian group of points of a small curve, which is always isomorphic
+///to Z/n1 x Z/n2 where n2 divides n1.
+pub struct GroupStructure<E: EC> {
+    points: Vec<ECpoint<E>>,
+    orders: Vec<U256>,
+    n1: U256,
+    n2: U256,
+}
+
+impl<E: EC> GroupStructure<E> {
+    ///Enumerates all points of E and computes the order of each of them
+    pub fn analyze() -> Self {
+        let points = points::<E>().collect::<Vec<_>>();
+        let n = U256::from(points.len());
+        let primes = find_factors(n).into_iter().skip(1).collect::<Vec<_>>();
+        let orders = points.iter()
+            .map(|point| {
+                //start with the group order and remove prime factors while
+                //the point is still annihilated
+                let mut order = n;
+                for p in &primes {
+                    if order % *p == 0.into() && point.mul_integer(order / *p).is_infinity() {
+                        order /= *p;
+                    }
+                }
+                order
+            })
+            .collect::<Vec<_>>();
+        //the exponent of the group, the largest order of a point, is n1
+        let n1 = orders.iter().copied().max().unwrap_or(U256::one());
+        GroupStructure { points, orders, n1, n2: n / n1 }
+    }
+    ///All points of the curve, including infinity
+    pub fn points(&self) -> &[ECpoint<E>] {
+        &self.points
+    }
+    ///Number of points of the curve, including infinity
+    pub fn n_points(&self) -> U256 {
+        self.points.len().into()
+    }
+    ///Returns (n1, n2) such that the group is isomorphic to Z/n1 x Z/n2,
+    ///n2 is 1 if the group is cyclic
+    pub fn invariants(&self) -> (U256, U256) {
+        (self.n1, self.n2)
+    }
+    pub fn is_cyclic(&self) -> bool {
+        self.n2 == U256::one()
+    }
+    ///Order of the given point, None if it does not lie on the curve
+    pub fn order_of(&self, point: &ECpoint<E>) -> Option<U256> {
+        self.points.iter()
+            .position(|p| p == point)
+            .map(|i| self.orders[i])
+    }
+    ///Maps each order to the number of points having it
+    pub fn order_distribution(&self) -> BTreeMap<U256, usize> {
+        self.orders.iter().fold(BTreeMap::new(), |mut acc, order| {
+            *acc.entry(*order).or_insert(0) += 1;
+            acc
+        })
+    }
+    ///Lists every subgroup of prime order p as (p, [infinity, G, 2G, ..., (p - 1)G])
+    ///for some generator G. If p^2 divides the group order there may be more
+    ///than one subgroup of order p.
+    pub fn prime_order_subgroups(&self) -> Vec<(U256, Vec<ECpoint<E>>)> {
+        let mut subgroups: Vec<(U256, Vec<ECpoint<E>>)> = vec![];
+        for (point, order) in self.points.iter().zip(self.orders.iter()) {
+            let is_prime_order = is_prime(*order);
+            let is_known = subgroups.iter().any(|(_, subgroup)| subgroup.contains(point));
+            if is_prime_order && !is_known {
+                let subgroup = std::iter::successors(Some(ECpoint::Infinity), |p| {
+                    Some(*p + *point).filter(|next| !next.is_infinity())
+                }).collect();
+                subgroups.push((*order, subgroup));
+            }
+        }
+        subgroups
+    }
+    ///Returns the Cayley table of the group, entry [i][j] is the index of
+    ///points()[i] + points()[j] in points()
+    pub fn cayley_table(&self) -> Vec<Vec<usize>> {
+        let index = self.points.iter()
+            .enumerate()
+            .map(|(i, p)| (*p, i))
+            .collect::<HashMap<_, _>>();
+        self.points.iter()
+            .map(|a| self.points.iter().map(|b| index[&(*a + *b)]).collect())
+            .collect()
+    }
+    ///Renders the Cayley table as text, points are printed as (x, y), the
+    ///point at infinity as O
+    pub fn cayley_table_dump(&self) -> String {
+        let label = |p: &ECpoint<E>| match p {
+            ECpoint::Infinity => "O".to_string(),
+            ECpoint::Point(_) => format!("({}, {})", p.x(), p.y()),
+        };
+        let labels = self.points.iter().map(label).collect::<Vec<_>>();
+        let width = labels.iter().map(|l| l.len()).max().unwrap_or(1);
+        let mut dump = format!("{:>width$} |", "+");
+        for l in &labels {
+            dump += &format!(" {:>width$}", l);
+        }
+        dump += &format!("\n{}\n", "-".repeat((width + 1) * (labels.len() + 1) + 1));
+        for (i, row) in self.cayley_table().iter().enumerate() {
+            dump += &format!("{:>width$} |", labels[i]);
+            for j in row {
+                dump += &format!(" {:>width$}", labels[*j]);
+            }
+            dump += "\n";
+        }
+        dump
+    }
+}
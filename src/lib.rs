@@ -1,10 +1,36 @@
 
 pub mod types;
 pub mod utils;
+pub mod ecdlp;
+pub mod group;
+pub mod pairing;
+pub mod curves;
+pub mod ecdh;
+pub mod ecies;
+pub mod elgamal;
+pub mod sss;
+pub mod frost;
+pub mod schnorr;
+pub mod musig;
+pub mod proofs;
+pub mod sigma;
+pub mod bulletproofs;
+pub mod ringsig;
+pub mod ecdsa;
+pub mod adaptor;
+pub mod vrf;
+pub mod oprf;
+pub mod blind_schnorr;
+pub mod bip32;
+pub mod bitcoin;
+pub mod taproot;
+pub mod der;
+#[cfg(feature = "ring-interop")]
+pub mod ring_interop;
 
 #[cfg(test)]
 mod tests {
-    use crate::{types::*, utils::{find_divisors, is_prime}};
+    use crate::{types::*, utils::{find_divisors, is_prime, mod_inverse, hash_to_point, expand_message_xmd, multiscalar_mul, hash_to_curve_sswu, ripemd160, base58_encode, base58check_decode, base58check_encode, base64_decode, base64_encode}, ecdlp, group, pairing, curves::{Curve25519, Secp256k1, P256}, ecdh, ecies, elgamal, sss, frost, schnorr, musig, proofs, sigma, bulletproofs, ringsig, ecdsa, adaptor, vrf, oprf, blind_schnorr, bip32, bitcoin, taproot, der};
     
     #[derive(Debug, Default, Clone, Copy, PartialEq)]
     struct EllipticCurve;
@@ -15,6 +41,10 @@ mod tests {
         const N: U256 = U256([127, 0, 0, 0]);
         const P: U256 = U256([127, 0, 0, 0]);
     }
+    impl Generator for EllipticCurve {
+        const GX: U256 = U256([38, 0, 0, 0]);
+        const GY: U256 = U256([53, 0, 0, 0]);
+    }
     type Zp = crate::types::Zp<EllipticCurve>;   
     type ECpoint = crate::types::ECpoint<EllipticCurve>;
     type Scalar = crate::types::Scalar<EllipticCurve>;
@@ -30,28 +60,165 @@ mod tests {
     }
     type ZpH = crate::types::Zp<HugeDummyCurve>;
 
-    #[derive(Debug, Default, Clone, Copy, PartialEq)]
-    struct Secp256k1;
-    impl EC for Secp256k1 {
-        const NAME: &'static str = "Secp256k1";     
-        const A: U256 = U256([0;4]);
-        const B: U256 = U256([7, 0, 0, 0]);
-        const N: U256 = U256([
-            13822214165235122497,
-            13451932020343611451,
-            18446744073709551614,
-            18446744073709551615,
-        ]);
-        const P: U256 = U256([
-            18446744069414583343,
-            18446744073709551615,
-            18446744073709551615,
-            18446744073709551615,
-        ]);
-    }
     type ZpSecp256k1 = crate::types::Zp<Secp256k1>;
     type ECpointSecp256k1 = crate::types::ECpoint<Secp256k1>;
     type ScalarSecp256k1 = crate::types::Scalar<Secp256k1>;
+
+    ///y^2 = x^3 + 18 with a 32 bit prime order
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct PrimeOrderCurve32;
+    impl EC for PrimeOrderCurve32 {
+        const NAME: &'static str = "Curve3951741139p";
+        const A: U256 = U256([0;4]);
+        const B: U256 = U256([18, 0, 0, 0]);
+        const N: U256 = U256([3951813247, 0, 0, 0]);
+        const P: U256 = U256([3951741139, 0, 0, 0]);
+    }
+    impl Generator for PrimeOrderCurve32 {
+        const GX: U256 = U256([1169563122, 0, 0, 0]);
+        const GY: U256 = U256([1597210383, 0, 0, 0]);
+    }
+    type ECpoint32 = crate::types::ECpoint<PrimeOrderCurve32>;
+    type Scalar32 = crate::types::Scalar<PrimeOrderCurve32>;
+
+    ///y^2 = x^3 + 17 with a 40 bit order of 3 * 7 * 19^3 * 577 * 7789
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct SmoothOrderCurve40;
+    impl EC for SmoothOrderCurve40 {
+        const NAME: &'static str = "Curve647349315127p";
+        const A: U256 = U256([0;4]);
+        const B: U256 = U256([17, 0, 0, 0]);
+        const N: U256 = U256([647347707867, 0, 0, 0]);
+        const P: U256 = U256([647349315127, 0, 0, 0]);
+    }
+    impl Generator for SmoothOrderCurve40 {
+        const GX: U256 = U256([277457665483, 0, 0, 0]);
+        const GY: U256 = U256([316177736028, 0, 0, 0]);
+    }
+    type ECpoint40 = crate::types::ECpoint<SmoothOrderCurve40>;
+    type Scalar40 = crate::types::Scalar<SmoothOrderCurve40>;
+
+    ///y^2 = x^3 + 1 mod 7, a non cyclic group Z/6 x Z/2
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct Curve7p;
+    impl EC for Curve7p {
+        const NAME: &'static str = "Curve7p";
+        const A: U256 = U256([0;4]);
+        const B: U256 = U256([1, 0, 0, 0]);
+        const N: U256 = U256([3, 0, 0, 0]);
+        const P: U256 = U256([7, 0, 0, 0]);
+        const H: U256 = U256([4, 0, 0, 0]);
+    }
+    impl Generator for Curve7p {
+        const GX: U256 = U256([0, 0, 0, 0]);
+        const GY: U256 = U256([1, 0, 0, 0]);
+    }
+
+    ///y^2 = x^3 + 1 mod 12583211, supersingular as p = 2 mod 3, with 12 * 1048601 points
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct SupersingularCurve;
+    impl EC for SupersingularCurve {
+        const NAME: &'static str = "Curve12583211p";
+        const A: U256 = U256([0;4]);
+        const B: U256 = U256([1, 0, 0, 0]);
+        const N: U256 = U256([1048601, 0, 0, 0]);
+        const P: U256 = U256([12583211, 0, 0, 0]);
+        const H: U256 = U256([12, 0, 0, 0]);
+    }
+    impl Generator for SupersingularCurve {
+        const GX: U256 = U256([8169214, 0, 0, 0]);
+        const GY: U256 = U256([3613380, 0, 0, 0]);
+    }
+    type ZpS = crate::types::Zp<SupersingularCurve>;
+    //tower Fp2 = Fp[u] / (u^2 + 1), Fp6 = Fp2[v] / (v^3 - (3 + u)), Fp12 = Fp6[w] / (w^2 - v)
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct MinusOne;
+    impl NonResidue<ZpS> for MinusOne {
+        fn non_residue() -> ZpS {
+            -ZpS::one()
+        }
+    }
+    type Fp2S = Fp2<SupersingularCurve, MinusOne>;
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct ThreePlusU;
+    impl NonResidue<Fp2S> for ThreePlusU {
+        fn non_residue() -> Fp2S {
+            Fp2S::new(3, 1)
+        }
+    }
+    type Fp6S = Fp6<SupersingularCurve, MinusOne, ThreePlusU>;
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct V;
+    impl NonResidue<Fp6S> for V {
+        fn non_residue() -> Fp6S {
+            Fp6S::new(Fp2S::zero(), Fp2S::one(), Fp2S::zero())
+        }
+    }
+    type Fp12S = Fp12<SupersingularCurve, MinusOne, ThreePlusU, V>;
+    //the curve over Fp2, with the same equation
+    impl CurveOver<Fp2S> for SupersingularCurve {
+        fn a() -> Fp2S {
+            Fp2S::zero()
+        }
+        fn b() -> Fp2S {
+            Fp2S::one()
+        }
+    }
+    type ECpointS = crate::types::ECpoint<SupersingularCurve>;
+    type ECpointS2 = crate::types::ECpoint<SupersingularCurve, Fp2S>;
+    type ScalarS = crate::types::Scalar<SupersingularCurve>;
+    ///Quadratic twist of SupersingularCurve over Fp2, y^2 = x^3 + (3 + u)^3
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct SupersingularTwist;
+    impl EC for SupersingularTwist {
+        const NAME: &'static str = "Curve12583211p quadratic twist";
+        const A: U256 = U256([0;4]);
+        const B: U256 = U256([1, 0, 0, 0]);
+        const N: U256 = U256([1048601, 0, 0, 0]);
+        const P: U256 = U256([12583211, 0, 0, 0]);
+        const H: U256 = U256([12, 0, 0, 0]);
+    }
+    impl Generator for SupersingularTwist {
+        const GX: U256 = U256([8169214, 0, 0, 0]);
+        const GY: U256 = U256([3613380, 0, 0, 0]);
+    }
+    impl CurveOver<Fp2S> for SupersingularTwist {
+        fn a() -> Fp2S {
+            Fp2S::zero()
+        }
+        fn b() -> Fp2S {
+            ThreePlusU::non_residue().pow(3)
+        }
+    }
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+    fn random_fp2() -> Fp2S {
+        Fp2S::new(ZpS::new(rand::random::<u64>()), ZpS::new(rand::random::<u64>()))
+    }
+    fn random_fp6() -> Fp6S {
+        Fp6S::new(random_fp2(), random_fp2(), random_fp2())
+    }
+    fn random_fp12() -> Fp12S {
+        Fp12S::new(random_fp6(), random_fp6())
+    }
+    ///Point of order 1048601 of SupersingularCurve and its image under the distortion
+    ///map (x, y) -> (zeta * x, y), zeta a primitive cube root of unity in Fp2
+    fn pairing_points() -> (ECpointS, ECpointS2) {
+        let g = SupersingularCurve::generator();
+        let (sqrt_minus_3, _) = (-Fp2S::from(3)).sqrt().unwrap();
+        let zeta = (sqrt_minus_3 - Fp2S::one()) / Fp2S::from(2);
+        let distorted = ECpointS2::new(zeta * Fp2S::from(g.x()), Fp2S::from(g.y())).unwrap();
+        (g, distorted)
+    }
+    fn random_point_over_fp2<E: CurveOver<Fp2S>>() -> crate::types::ECpoint<E, Fp2S> {
+        loop {
+            let x = random_fp2();
+            if let Some((y, _)) = (x.pow(3) + E::a() * x + E::b()).sqrt() {
+                return crate::types::ECpoint::new(x, y).unwrap()
+            }
+        }
+    }
     #[test]
     fn test_zp_new(){
         let a = Zp::new(130);
@@ -68,6 +235,7 @@ mod tests {
         assert_eq!((a + b).unwrap(), U256::MAX - 2);
     }
     #[test]
+    #[allow(clippy::misrefactored_assign_op)]
     fn test_zp_add_assign(){
         let mut a  = Zp::new(127);
         a += a + Zp::new(2);
@@ -141,7 +309,7 @@ mod tests {
         assert_eq!(None, ec_point);
         let (x, y) = (0, 0);
         let ec_point = ECpoint::new(x, y);
-        assert_eq!(false, ec_point.is_some());
+        assert!(ec_point.is_none());
     }
     #[test]
     fn test_ecpoint_on_curve() {
@@ -206,6 +374,7 @@ mod tests {
         assert_eq!(a + a - a - a - a, minus_a);
     }
     #[test]
+    #[allow(clippy::erasing_op)]
     fn skalar_multiplication_with_zero() {
         let a = ECpoint::new(38, 53).unwrap();
         assert_eq!(a * 0, ECpoint::Infinity);
@@ -279,9 +448,9 @@ mod tests {
              .map(|n| Zp::new(*n))
              .collect::<Vec<_>>();
         let b = (0..127)
-            .map(|n| Zp::new(n))
+            .map(Zp::new)
             .map(|zp| (zp, zp.is_quadratic_residue()))
-            .filter(|(_, is_residue)| *is_residue == true)
+            .filter(|(_, is_residue)| *is_residue)
             .map(|(zp, _)| zp)
             .collect::<Vec<_>>();
         assert_eq!(a, b);
@@ -316,4 +485,1437 @@ mod tests {
         let res = find_divisors(2048);
         assert_eq!(res, answer);
     }
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(7, 40), Some(23.into()));
+        assert_eq!(mod_inverse(6, 40), None);
+    }
+    #[test]
+    fn test_group_points() {
+        let points = group::points::<EllipticCurve>().collect::<Vec<_>>();
+        assert_eq!(U256::from(points.len()), EllipticCurve.n_curve_points::<EllipticCurve>());
+        assert_eq!(points[0], ECpoint::Infinity);
+        assert!(points.contains(&ECpoint::new(123, 109).unwrap()));
+        let points = group::points::<Curve7p>().skip(1).map(|p| (p.x().unwrap(), p.y().unwrap())).collect::<Vec<_>>();
+        let expected = [(0, 1), (0, 6), (1, 3), (1, 4), (2, 3), (2, 4), (3, 0), (4, 3), (4, 4), (5, 0), (6, 0)]
+            .map(|(x, y)| (U256::from(x), U256::from(y)));
+        assert_eq!(points, expected);
+    }
+    #[test]
+    fn test_group_structure() {
+        let group = group::GroupStructure::<Curve7p>::analyze();
+        assert_eq!(group.n_points(), 12.into());
+        assert_eq!(group.invariants(), (6.into(), 2.into()));
+        assert!(!group.is_cyclic());
+        let distribution = group.order_distribution().into_iter().collect::<Vec<_>>();
+        assert_eq!(distribution, [(1.into(), 1), (2.into(), 3), (3.into(), 2), (6.into(), 6)]);
+        let subgroups = group.prime_order_subgroups();
+        assert_eq!(subgroups.iter().filter(|(p, _)| *p == 2.into()).count(), 3);
+        assert_eq!(subgroups.iter().filter(|(p, _)| *p == 3.into()).count(), 1);
+        assert!(subgroups.iter().all(|(p, points)| U256::from(points.len()) == *p));
+        let table = group.cayley_table();
+        //infinity is the identity, every row is a permutation
+        assert_eq!(table[0], (0..12).collect::<Vec<_>>());
+        assert!(table.iter().all(|row| (0..12).all(|i| row.contains(&i))));
+        assert_eq!(group.cayley_table_dump().lines().count(), 14);
+
+        let group = group::GroupStructure::<EllipticCurve>::analyze();
+        assert!(group.is_cyclic());
+        assert_eq!(group.invariants(), (127.into(), 1.into()));
+        assert_eq!(group.order_of(&ECpoint::new(38, 53).unwrap()), Some(127.into()));
+    }
+    #[test]
+    fn test_fp2_arithmetic() {
+        let u = Fp2S::new(0, 1);
+        assert_eq!(u * u, -Fp2S::one());
+        let (a, b, c) = (random_fp2(), random_fp2(), random_fp2());
+        assert_eq!((a + b) * c, a * c + b * c);
+        assert_eq!(a * a.inverse().unwrap(), Fp2S::one());
+        assert_eq!(a / b * b, a);
+        assert_eq!(Fp2S::zero().inverse(), None);
+        assert_eq!(a.frobenius(), a.pow(SupersingularCurve::P));
+        assert_eq!(a.frobenius(), a.conjugate());
+        assert_eq!(a.frobenius_power(2), a);
+        let (r, minus_r) = a.square().sqrt().unwrap();
+        assert!(r == a || minus_r == a);
+        //elements of Fp are all squares in Fp2
+        assert!(Fp2S::from(ZpS::non_square()).is_square());
+        assert!(Fp2S::non_square().sqrt().is_none());
+        assert_eq!(Fp2S::order(), vec![12583211u64 * 12583211]);
+    }
+    #[test]
+    fn test_fp6_fp12_arithmetic() {
+        let (a, b, c) = (random_fp6(), random_fp6(), random_fp6());
+        assert_eq!((a + b) * c, a * c + b * c);
+        assert_eq!(a * a.inverse().unwrap(), Fp6S::one());
+        assert_eq!(a.frobenius(), a.pow(SupersingularCurve::P));
+        assert_eq!(a.frobenius_power(6), a);
+        let v = V::non_residue();
+        assert_eq!(v * v * v, Fp6S::from(ThreePlusU::non_residue()));
+        let (r, minus_r) = a.square().sqrt().unwrap();
+        assert!(r == a || minus_r == a);
+        assert!(Fp6S::non_square().sqrt().is_none());
+
+        let (a, b, c) = (random_fp12(), random_fp12(), random_fp12());
+        assert_eq!((a + b) * c, a * c + b * c);
+        assert_eq!(a * a.inverse().unwrap(), Fp12S::one());
+        assert_eq!(a.frobenius(), a.pow(SupersingularCurve::P));
+        assert_eq!(a.frobenius_power(12), a);
+        let (r, minus_r) = a.square().sqrt().unwrap();
+        assert!(r == a || minus_r == a);
+    }
+    #[test]
+    fn test_ecpoint_over_extension_field() {
+        let p = U256::from(12583211);
+        //points of the curve over Fp are points over Fp2 too
+        let g = ECpointS::new(2, 3).unwrap();
+        let g2 = ECpointS2::new(Fp2S::from(g.x()), Fp2S::from(g.y())).unwrap();
+        assert_eq!(Fp2S::from(g.mul_integer(5).x()), g2.mul_integer(5).x());
+        assert_eq!(Fp2S::from(g.mul_integer(5).y()), g2.mul_integer(5).y());
+        //y^2 = x^3 + 1 is supersingular so it has (p + 1)^2 points over Fp2,
+        //its quadratic twist (p - 1)^2
+        let q = random_point_over_fp2::<SupersingularCurve>();
+        assert_eq!(q.mul_integer((p + 1) * (p + 1)), ECpointS2::Infinity);
+        assert_eq!(q + q + q, q * 3);
+        let t = random_point_over_fp2::<SupersingularTwist>();
+        assert_eq!(t.mul_integer((p - 1) * (p - 1)), crate::types::ECpoint::Infinity);
+        assert_ne!(t.mul_integer((p + 1) * (p + 1)), crate::types::ECpoint::Infinity);
+    }
+    #[test]
+    fn test_ecdlp_baby_step_giant_step() {
+        let g = ECpoint::new(38, 53).unwrap();
+        for k in 0..127 {
+            assert_eq!(ecdlp::baby_step_giant_step(g, g * k), Some(Scalar::new(k)));
+        }
+        let g = ECpoint32::new(1169563122, 1597210383).unwrap();
+        let k = Scalar32::generate_secret();
+        assert_eq!(ecdlp::baby_step_giant_step(g, g * k), Some(k));
+        //small logarithm on a curve too big for a full search
+        let g = ECpoint40::new(277457665483u64, 316177736028u64).unwrap();
+        assert_eq!(ecdlp::baby_step_giant_step_bounded(g, g * 1_000_000, 1 << 20), Some(1_000_000.into()));
+        assert_eq!(ecdlp::baby_step_giant_step_bounded(g, g * 2_000_000, 1 << 20), None);
+    }
+    #[test]
+    fn test_ecdlp_pollard_rho() {
+        let g = ECpoint::new(38, 53).unwrap();
+        let k = Scalar::new(99);
+        assert_eq!(ecdlp::pollard_rho(g, g * k), Some(k));
+        let g = ECpoint32::new(1169563122, 1597210383).unwrap();
+        let k = Scalar32::generate_secret();
+        assert_eq!(ecdlp::pollard_rho(g, g * k), Some(k));
+        assert_eq!(ecdlp::pollard_rho(g, ECpoint32::Infinity), Some(Scalar32::zero()));
+    }
+    #[test]
+    fn test_ecdlp_pohlig_hellman() {
+        let g = ECpoint40::new(277457665483u64, 316177736028u64).unwrap();
+        for _ in 0..5 {
+            let k = Scalar40::generate_secret();
+            assert_eq!(ecdlp::pohlig_hellman(g, g * k), Some(k));
+        }
+        //prime order, the reduction does not help but still gives the right answer
+        let g = ECpoint::new(38, 53).unwrap();
+        assert_eq!(ecdlp::pohlig_hellman(g, g * 42), Some(Scalar::new(42)));
+    }
+    #[test]
+    fn test_pairing_bilinearity() {
+        let r = SupersingularCurve::N;
+        let (g, s) = pairing_points();
+        let p: ECpointS2 = g.lift().unwrap();
+        assert_ne!(p, ECpointS2::Infinity);
+        assert_eq!(p.mul_integer(r), ECpointS2::Infinity);
+        assert_eq!(s.mul_integer(r), ECpointS2::Infinity);
+        let tate = pairing::tate_pairing(p, s, r).unwrap();
+        let weil = pairing::weil_pairing(p, s, r).unwrap();
+        //non-degenerate, values are r-th roots of unity
+        assert!(!tate.is_one() && !weil.is_one());
+        assert!(tate.pow(r).is_one() && weil.pow(r).is_one());
+        assert!(pairing::weil_pairing(p, p, r).unwrap().is_one());
+        assert!(pairing::tate_pairing(p, p.mul_integer(7), r).unwrap().is_one());
+        //e(aP, bQ) = e(P, Q)^(ab)
+        let (a, b) = (ScalarS::generate_secret(), ScalarS::generate_secret());
+        let (ap, bs) = (p.mul_integer(a.unwrap()), s.mul_integer(b.unwrap()));
+        assert_eq!(pairing::tate_pairing(ap, bs, r).unwrap(), tate.pow((a * b).unwrap()));
+        assert_eq!(pairing::weil_pairing(ap, bs, r).unwrap(), weil.pow((a * b).unwrap()));
+        //e(P + P', Q) = e(P, Q) * e(P', Q)
+        let p2 = p.mul_integer(b.unwrap());
+        assert_eq!(pairing::weil_pairing(p + p2, s, r).unwrap(), weil * pairing::weil_pairing(p2, s, r).unwrap());
+        assert_eq!(pairing::embedding_degree::<SupersingularCurve>(12), Some(2));
+        assert_eq!(pairing::embedding_degree::<PrimeOrderCurve32>(12), None);
+    }
+    #[test]
+    fn test_ecdlp_mov_attack() {
+        let (g, s) = pairing_points();
+        let k = ScalarS::generate_secret();
+        assert_eq!(ecdlp::mov_attack(g, g * k, s), Some(k));
+        //S in the same subgroup as G makes the pairing degenerate
+        assert_eq!(ecdlp::mov_attack(g, g * k, g.lift::<Fp2S>().unwrap()), None);
+    }
+    #[test]
+    fn test_montgomery_and_edwards_models() {
+        type Zp25519 = crate::types::Zp<Curve25519>;
+        let dec = |s| U256::from_dec_str(s).unwrap();
+        //Ed25519 base point maps to the Curve25519 base point u = 9
+        let b = EdwardsPoint::<Curve25519>::new(
+            dec("15112221349535400772501151409588531511454012693041857206046113283949847762202"),
+            Zp25519::new(4) / Zp25519::new(5)
+        ).unwrap();
+        let m = b.to_montgomery();
+        assert_eq!(m, MontgomeryPoint::new(9, dec("14781619447589544791020593568409986887264606134616475288964881837755586237401")).unwrap());
+        assert_eq!(m.to_edwards(), Some(b));
+        let w = b.to_weierstrass();
+        assert_eq!(w.to_montgomery(), m);
+        assert_eq!(w.to_edwards(), Some(b));
+        //the maps are group homomorphisms
+        assert_eq!(b + b + b, b.mul_integer(3));
+        assert_eq!((b + b.mul_integer(7)).to_montgomery(), m + m.mul_integer(7));
+        assert_eq!(b.mul_integer(12345).to_montgomery(), m.mul_integer(12345));
+        assert_eq!(b.mul_integer(12345).to_weierstrass(), w.mul_integer(12345));
+        assert_eq!(MontgomeryPoint::ladder(Zp25519::new(9), 12345), m.mul_integer(12345).u());
+        assert!(b.mul_integer(Curve25519::N).is_identity());
+        assert_eq!(MontgomeryPoint::<Curve25519>::ladder(Zp25519::new(9), Curve25519::N), Zp25519::zero());
+        //(0, -1) has order 2 and maps to (0, 0)
+        let t = EdwardsPoint::<Curve25519>::new(0, -1).unwrap();
+        assert!((t + t).is_identity());
+        assert_eq!(t.to_montgomery(), MontgomeryPoint::new(0, 0).unwrap());
+        assert_eq!(t.to_montgomery().to_edwards(), Some(t));
+        assert_eq!(b - b, EdwardsPoint::identity());
+        //RFC 7748 5.2 first X25519 vector, clamped scalar and decoded u
+        let k = dec("31029842492115040904895560451863089656472772604678260265531221036453811406496");
+        let u = dec("34426434033919594451155107781188821651316167215306631574996226621102155684838");
+        assert_eq!(
+            MontgomeryPoint::<Curve25519>::ladder(Zp25519::new(u), k).unwrap(),
+            dec("37325765543539916631701301279660700968428932651319597985674090122993663859395")
+        );
+        //RFC 8032 7.1 test 1, clamped SHA-512 of the secret key times the base point
+        let a = dec("36144925721603087658594284515452164870581325872720374094707712194495455132720");
+        let public = b.mul_integer(a);
+        assert_eq!(public.y().unwrap(), dec("11903303657706407974989296177215005343713679411332034699907763981919547054807"));
+        assert!(!public.x().unwrap().bit(0));
+    }
+    #[test]
+    fn test_ecdh() {
+        //P-256 key agreement, expected values computed with OpenSSL
+        let alice = ecdh::StaticSecret::<P256>::from_be_bytes(&hex("c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433")).unwrap();
+        assert_eq!(alice.public_key().to_sec1(false), hex("04dad0b65394221cf9b051e1feca5787d098dfe637fc90b9ef945d0c37725811805271a0461cdb8252d61f1c456fa3e59ab1f45b33accf5f58389e0577b8990bb3"));
+        let bob = ecdh::PublicKey::<P256>::from_sec1(&hex("0313f235e310cda779ad9b3b166d6a2c8c77ae76773ffecae7c7bffc5e5b893608")).unwrap();
+        assert_eq!(ecdh::PublicKey::from_sec1(&bob.to_sec1(false)), Ok(bob));
+        let shared = alice.diffie_hellman(&bob).unwrap();
+        assert_eq!(shared.raw_secret_bytes(), hex("1ce8472a41d73b5b745c75fa1ffc3263fc70bb748b008f2b84d272aba95f2152"));
+        let mut key = [0u8; 32];
+        shared.hkdf_sha256(b"salt", b"ecdh test", &mut key).unwrap();
+        assert_eq!(key.to_vec(), hex("46f90d135dd84e751b8ff51c46c4b75e9541821eee2a2c854f402d84bd9833a0"));
+        assert_eq!(shared.hkdf_sha256(b"", b"", &mut [0u8; 255 * 32 + 1]), Err(ecdh::Error::InvalidLength));
+        //ephemeral against static on a curve with cofactor 12
+        let ephemeral = ecdh::EphemeralSecret::<SupersingularCurve>::random();
+        let recipient = ecdh::StaticSecret::<SupersingularCurve>::random();
+        let ephemeral_public = ephemeral.public_key();
+        assert_eq!(ephemeral.diffie_hellman(&recipient.public_key()), recipient.diffie_hellman(&ephemeral_public));
+        //invalid keys
+        let mut off_curve = bob.to_sec1(false);
+        off_curve[64] ^= 1;
+        assert_eq!(ecdh::PublicKey::<P256>::from_sec1(&off_curve), Err(ecdh::Error::InvalidEncoding));
+        assert_eq!(ecdh::PublicKey::<P256>::from_sec1(&[0x00]), Err(ecdh::Error::PointAtInfinity));
+        assert_eq!(ecdh::PublicKey::<P256>::from_sec1(&off_curve[..33]), Err(ecdh::Error::InvalidEncoding));
+        let order_2 = crate::types::ECpoint::<Curve7p>::new(6, 0).unwrap();
+        assert_eq!(ecdh::PublicKey::from_point(order_2), Err(ecdh::Error::NotInSubgroup));
+        //y = 0 is even, it has no encoding with the 0x03 prefix
+        assert_eq!(crate::types::ECpoint::<Curve7p>::from_sec1(&[0x02, 6]), Some(order_2));
+        assert_eq!(crate::types::ECpoint::<Curve7p>::from_sec1(&[0x03, 6]), None);
+        assert!(ecdh::PublicKey::from_point(Curve7p::generator()).is_ok());
+        assert_eq!(ecdh::StaticSecret::<P256>::from_be_bytes(&[0; 32]), Err(ecdh::Error::ZeroScalar));
+        let mut n = [0u8; 32];
+        P256::N.to_big_endian(&mut n);
+        assert_eq!(ecdh::StaticSecret::<P256>::from_be_bytes(&n), Err(ecdh::Error::InvalidEncoding));
+    }
+    #[test]
+    fn test_x25519() {
+        let bytes = |s| <[u8; 32]>::try_from(hex(s)).unwrap();
+        //RFC 7748 5.2 and 6.1
+        assert_eq!(
+            ecdh::x25519(bytes("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"), bytes("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c")),
+            bytes("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+        let alice = bytes("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = bytes("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = ecdh::x25519(alice, ecdh::X25519_BASEPOINT);
+        let bob_public = ecdh::x25519(bob, ecdh::X25519_BASEPOINT);
+        assert_eq!(alice_public, bytes("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"));
+        assert_eq!(bob_public, bytes("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f"));
+        let shared = bytes("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(ecdh::x25519(alice, bob_public), shared);
+        assert_eq!(ecdh::x25519(bob, alice_public), shared);
+        //small order u gives all zeros
+        assert_eq!(ecdh::x25519(alice, [0; 32]), [0; 32]);
+    }
+    #[test]
+    fn test_ecies() {
+        let recipient = ecdh::StaticSecret::<P256>::from_be_bytes(&hex("c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433")).unwrap();
+        //generated with pyca/cryptography, ECDH with the compressed ephemeral key of test_ecdh,
+        //HKDF-SHA256 salted with that key, then AES-256-GCM or ChaCha20-Poly1305 with nonce 00..0b
+        let aes = hex("0313f235e310cda779ad9b3b166d6a2c8c77ae76773ffecae7c7bffc5e5b893608000102030405060708090a0b5a41cae8192ff24ead6d5d9a608886eb1fef7cc981e8e33164119bea9ed5");
+        let chacha = hex("0313f235e310cda779ad9b3b166d6a2c8c77ae76773ffecae7c7bffc5e5b893608000102030405060708090a0b274450993c5a63481a3893df07e93c64d95e8e95219072e371c20f2a9304");
+        assert_eq!(ecies::decrypt(&recipient, &aes, b"header", ecies::Aead::Aes256Gcm).unwrap(), b"attack at dawn");
+        assert_eq!(ecies::decrypt(&recipient, &chacha, b"header", ecies::Aead::ChaCha20Poly1305).unwrap(), b"attack at dawn");
+        //wrong algorithm, associated data or a flipped bit fail to authenticate
+        assert_eq!(ecies::decrypt(&recipient, &aes, b"header", ecies::Aead::ChaCha20Poly1305), Err(ecies::Error::DecryptionFailed));
+        assert_eq!(ecies::decrypt(&recipient, &aes, b"", ecies::Aead::Aes256Gcm), Err(ecies::Error::DecryptionFailed));
+        let mut tampered = aes.clone();
+        tampered[50] ^= 1;
+        assert_eq!(ecies::decrypt(&recipient, &tampered, b"header", ecies::Aead::Aes256Gcm), Err(ecies::Error::DecryptionFailed));
+        //invalid ephemeral keys and truncated input
+        let mut off_curve = ecdh::PublicKey::<P256>::from_sec1(&aes[..33]).unwrap().to_sec1(false);
+        off_curve[64] ^= 1;
+        off_curve.extend_from_slice(&aes[33..]);
+        assert_eq!(ecies::decrypt(&recipient, &off_curve, b"header", ecies::Aead::Aes256Gcm), Err(ecies::Error::Ecdh(ecdh::Error::InvalidEncoding)));
+        assert_eq!(ecies::decrypt(&recipient, &aes[..60], b"header", ecies::Aead::Aes256Gcm), Err(ecies::Error::InvalidLength));
+        //round trip with a random ephemeral key
+        for aead in [ecies::Aead::Aes256Gcm, ecies::Aead::ChaCha20Poly1305] {
+            let ciphertext = ecies::encrypt(&recipient.public_key(), b"", b"", aead).unwrap();
+            assert_eq!(ciphertext.len(), 65 + 12 + 16);
+            assert_eq!(ecies::decrypt(&recipient, &ciphertext, b"", aead).unwrap(), b"");
+        }
+        let recipient = ecdh::StaticSecret::<SupersingularCurve>::random();
+        let message = [7u8; 1000];
+        let ciphertext = ecies::encrypt(&recipient.public_key(), &message, b"aad", ecies::Aead::ChaCha20Poly1305).unwrap();
+        assert_eq!(ecies::decrypt(&recipient, &ciphertext, b"aad", ecies::Aead::ChaCha20Poly1305).unwrap(), message);
+    }
+    #[test]
+    fn test_elgamal() {
+        let (secret, public) = elgamal::keygen::<PrimeOrderCurve32>();
+        //private tally, sum of encrypted 0 / 1 votes
+        let votes = [1u64, 0, 1, 1, 0, 1, 1, 0, 0, 1];
+        let ballots = votes.iter().map(|&v| elgamal::encrypt(public, Scalar32::new(v))).collect::<Vec<_>>();
+        let tally: elgamal::Ciphertext<PrimeOrderCurve32> = ballots.iter().copied().sum();
+        assert_eq!(elgamal::decrypt(secret, &tally, 100), Some(6.into()));
+        //scalar multiplication and subtraction
+        assert_eq!(elgamal::decrypt(secret, &(tally * Scalar32::new(1000)), 1 << 16), Some(6000.into()));
+        assert_eq!(elgamal::decrypt(secret, &(tally - ballots[0]), 100), Some(5.into()));
+        assert_eq!(elgamal::decrypt_to_point(secret, &-tally), PrimeOrderCurve32::generator() * -Scalar32::new(6));
+        //re-randomization changes the ciphertext but not the message
+        let rerandomized = tally.rerandomize(public);
+        assert_ne!(rerandomized, tally);
+        assert_eq!(elgamal::decrypt(secret, &rerandomized, 100), Some(6.into()));
+        //deterministic with fixed randomness, bounded decryption fails out of range
+        let r = Scalar32::new(12345);
+        let c = elgamal::encrypt_with_randomness(public, Scalar32::new(500), r);
+        assert_eq!(c, elgamal::encrypt_with_randomness(public, Scalar32::new(500), r));
+        assert_eq!(c.c1, PrimeOrderCurve32::generator() * r);
+        assert_eq!(elgamal::decrypt(secret, &c, 500), None);
+        assert_eq!(elgamal::decrypt(secret, &c, 501), Some(500.into()));
+        let (other_secret, _) = elgamal::keygen::<PrimeOrderCurve32>();
+        assert_ne!(elgamal::decrypt_to_point(other_secret, &c), PrimeOrderCurve32::generator() * 500);
+    }
+    #[test]
+    fn test_shamir_secret_sharing() {
+        type ScalarK = crate::types::Scalar<Secp256k1>;
+        //f(x) = 3 + 2x + x^2
+        let f = sss::Polynomial::new(vec![ScalarK::new(3), ScalarK::new(2), ScalarK::new(1)]);
+        assert_eq!(f.evaluate(ScalarK::new(5)), ScalarK::new(38));
+        let points = [1u64, 2, 3].map(|x| (ScalarK::new(x), f.evaluate(ScalarK::new(x))));
+        assert_eq!(sss::lagrange_interpolate(&points, ScalarK::new(10)), Some(ScalarK::new(123)));
+        assert_eq!(sss::lagrange_interpolate(&[points[0], points[0]], ScalarK::zero()), None);
+        let secret = ScalarK::generate_secret();
+        let shares = sss::split(secret, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+        //any 3 shares recover the secret, 2 do not
+        for (i, j, k) in [(0, 1, 2), (0, 2, 4), (1, 3, 4), (4, 3, 0)] {
+            assert_eq!(sss::combine(&[shares[i], shares[j], shares[k]]), Some(secret));
+        }
+        assert_eq!(sss::combine(&shares), Some(secret));
+        assert_ne!(sss::combine(&shares[..2]), Some(secret));
+        assert_eq!(sss::combine(&[shares[0], shares[0], shares[1]]), None);
+        assert_eq!(sss::combine::<Secp256k1>(&[]), None);
+        assert_eq!(sss::split(secret, 1, 1).unwrap()[0].value, secret);
+        assert_eq!(sss::split(secret, 0, 5), None);
+        assert_eq!(sss::split(secret, 6, 5), None);
+        assert_eq!(sss::split(Scalar::new(1), 2, 127), None);
+    }
+    #[test]
+    fn test_verifiable_secret_sharing() {
+        let secret = Scalar32::generate_secret();
+        let (shares, commitments) = sss::feldman_split(secret, 3, 5).unwrap();
+        assert_eq!(commitments[0], PrimeOrderCurve32::generator() * secret);
+        assert!(shares.iter().all(|share| sss::feldman_verify(share, &commitments)));
+        let mut bad = shares[2];
+        bad.value += Scalar32::one();
+        assert!(!sss::feldman_verify(&bad, &commitments));
+        assert_eq!(sss::combine(&shares[2..]), Some(secret));
+        //Pedersen with an independent generator
+        let h = hash_to_point::<PrimeOrderCurve32>(b"Pedersen VSS generator H");
+        let (shares, commitments) = sss::pedersen_split(secret, 2, 4, h).unwrap();
+        assert!(shares.iter().all(|share| sss::pedersen_verify(share, &commitments, h)));
+        assert_ne!(commitments[0], PrimeOrderCurve32::generator() * secret);
+        let mut bad = shares[1];
+        bad.blinding += Scalar32::one();
+        assert!(!sss::pedersen_verify(&bad, &commitments, h));
+        assert!(!sss::pedersen_verify(&shares[1], &commitments, PrimeOrderCurve32::generator()));
+        assert_eq!(sss::combine(&[shares[3].share, shares[0].share]), Some(secret));
+    }
+    #[test]
+    fn test_hash_to_point() {
+        let h = hash_to_point::<PrimeOrderCurve32>(b"data");
+        assert_eq!(h, hash_to_point::<PrimeOrderCurve32>(b"data"));
+        assert_ne!(h, hash_to_point::<PrimeOrderCurve32>(b"other data"));
+        assert!(!h.y().unwrap().bit(0));
+        //the cofactor is cleared
+        let h = hash_to_point::<SupersingularCurve>(b"data");
+        assert!(!h.is_infinity());
+        assert_eq!(h.mul_integer(SupersingularCurve::N), ECpointS::Infinity);
+    }
+    #[test]
+    fn test_expand_message_xmd() {
+        //RFC 9380 K.1
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        assert_eq!(expand_message_xmd(b"", dst, 0x20), Some(hex("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")));
+        assert_eq!(expand_message_xmd(b"abc", dst, 0x20), Some(hex("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")));
+        assert_eq!(expand_message_xmd(b"", dst, 0x80), Some(hex("af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced")));
+        assert_eq!(expand_message_xmd(b"", dst, 255 * 32 + 1), None);
+    }
+    #[test]
+    fn test_frost_rfc9591() {
+        //RFC 9591 E.5, FROST(secp256k1, SHA-256)
+        type C = frost::Secp256k1Sha256;
+        type ScalarK = crate::types::Scalar<Secp256k1>;
+        let scalar = |s| ScalarK::from_be_bytes(&hex(s)).unwrap();
+        let bytes = |s| <[u8; 32]>::try_from(hex(s)).unwrap();
+        let coefficients = [
+            scalar("0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114"),
+            scalar("fbf85eadae3058ea14f19148bb72b45e4399c0b16028acaf0395c9b03c823579"),
+        ];
+        let (key_packages, public_key_package, commitment) = frost::trusted_dealer_keygen_with_coefficients::<C>(&coefficients, 3).unwrap();
+        let group_public_key = public_key_package.group_public_key;
+        assert_eq!(group_public_key.to_sec1(true), hex("02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f"));
+        assert_eq!(commitment[0], group_public_key);
+        let shares = [
+            "08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c",
+            "04f0feac2edcedc6ce1253b7fab8c86b856a797f44d83d82a385554e6e401984",
+            "00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc",
+        ];
+        for (key_package, share) in key_packages.iter().zip(shares) {
+            assert_eq!(key_package.signing_share, scalar(share));
+        }
+        let msg = hex("74657374");
+        let (nonces1, commitments1) = frost::commit_with_randomness(&key_packages[0],
+            &bytes("7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2"),
+            &bytes("47acab018f116020c10cb9b9abdc7ac10aae1b48ca6e36dc15acb6ec9be5cdc5"));
+        let (nonces3, commitments3) = frost::commit_with_randomness(&key_packages[2],
+            &bytes("e6cc56ccbd0502b3f6f831d91e2ebd01c4de0479e0191b66895a4ffd9b68d544"),
+            &bytes("7203d55eb82a5ca0d7d83674541ab55f6e76f1b85391d2c13706a89a064fd5b9"));
+        assert_eq!(commitments1.hiding.to_sec1(true), hex("03c699af97d26bb4d3f05232ec5e1938c12f1e6ae97643c8f8f11c9820303f1904"));
+        assert_eq!(commitments1.binding.to_sec1(true), hex("02fa2aaccd51b948c9dc1a325d77226e98a5a3fe65fe9ba213761a60123040a45e"));
+        assert_eq!(commitments3.hiding.to_sec1(true), hex("03077507ba327fc074d2793955ef3410ee3f03b82b4cdc2370f71d865beb926ef6"));
+        assert_eq!(commitments3.binding.to_sec1(true), hex("02ad53031ddfbbacfc5fbda3d3b0c2445c8e3e99cbc4ca2db2aa283fa68525b135"));
+        let commitments = [commitments3, commitments1];
+        let binding_factors = frost::compute_binding_factors(&group_public_key, &commitments, &msg).unwrap();
+        assert_eq!(binding_factors[&ScalarK::new(1)], scalar("3e08fe561e075c653cbfd46908a10e7637c70c74f0a77d5fd45d1a750c739ec6"));
+        assert_eq!(binding_factors[&ScalarK::new(3)], scalar("93f79041bb3fd266105be251adaeb5fd7f8b104fb554a4ba9a0becea48ddbfd7"));
+        let share1 = frost::sign(&msg, nonces1, &key_packages[0], &commitments).unwrap();
+        let share3 = frost::sign(&msg, nonces3, &key_packages[2], &commitments).unwrap();
+        assert_eq!(share1, scalar("c4fce1775a1e141fb579944166eab0d65eefe7b98d480a569bbbfcb14f91c197"));
+        assert_eq!(share3, scalar("0160fd0d388932f4826d2ebcd6b9eaba734f7c71cf25b4279a4ca2581e47b18d"));
+        assert!(frost::verify_signature_share(ScalarK::new(1), &key_packages[0].verifying_share, &share1, &commitments, &group_public_key, &msg));
+        assert!(!frost::verify_signature_share(ScalarK::new(3), &key_packages[2].verifying_share, &share1, &commitments, &group_public_key, &msg));
+        let signature_shares = [(ScalarK::new(1), share1), (ScalarK::new(3), share3)].into();
+        let signature = frost::aggregate(&commitments, &msg, &signature_shares, &public_key_package).unwrap();
+        let expected = hex("0205b6d04d3774c8929413e3c76024d54149c372d57aae62574ed74319b5ea14d0c65dde8492a7471437e6c2fe3da49b90d23f642b5c6dbe7e36089f096dd97324");
+        assert_eq!(signature.serialize(), expected);
+        assert_eq!(frost::Signature::<C>::deserialize(&expected), Ok(signature));
+        assert!(signature.verify(&msg, &group_public_key));
+        assert!(!signature.verify(b"other message", &group_public_key));
+        //a cheating signer is identified
+        let bad_shares = [(ScalarK::new(1), share1), (ScalarK::new(3), share3 + ScalarK::one())].into();
+        assert_eq!(frost::aggregate(&commitments, &msg, &bad_shares, &public_key_package), Err(frost::Error::InvalidSignatureShare(ScalarK::new(3))));
+    }
+    #[test]
+    fn test_frost_dkg() {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        struct TestSuite;
+        impl frost::Ciphersuite for TestSuite {
+            type Curve = PrimeOrderCurve32;
+            const CONTEXT_STRING: &'static [u8] = b"FROST-test-SHA256";
+        }
+        let ids = [1u64, 2, 3].map(Scalar32::new);
+        let round1 = ids.map(|id| frost::dkg_part1::<TestSuite>(id, 2, 3).unwrap());
+        let packages = round1.iter().map(|(_, package)| package.clone()).collect::<Vec<_>>();
+        let others = |i: usize| packages.iter().filter(|p| p.identifier != ids[i]).cloned().collect::<Vec<_>>();
+        //a forged proof of knowledge is rejected
+        let mut forged = others(0);
+        forged[0].proof_of_knowledge.1 += Scalar32::one();
+        let (secret, _) = frost::dkg_part1::<TestSuite>(ids[0], 2, 3).unwrap();
+        //Debug does not print the secret polynomial
+        assert_eq!(format!("{secret:?}"), format!("Round1Secret [curve {}]", PrimeOrderCurve32::NAME));
+        assert_eq!(frost::dkg_part2(secret, &forged).unwrap_err(), frost::Error::InvalidProofOfKnowledge(ids[1]));
+        let round2 = round1.into_iter().enumerate()
+            .map(|(i, (secret, _))| frost::dkg_part2(secret, &others(i)).unwrap())
+            .collect::<Vec<_>>();
+        let received = |i: usize| round2.iter().enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(j, (_, shares))| (ids[j], shares[&ids[i]]))
+            .collect::<std::collections::BTreeMap<_, _>>();
+        let mut bad = received(0);
+        *bad.get_mut(&ids[2]).unwrap() += Scalar32::one();
+        assert_eq!(frost::dkg_part3(&round2[0].0, &bad), Err(frost::Error::InvalidSecretShare(ids[2])));
+        let keys = (0..3).map(|i| frost::dkg_part3(&round2[i].0, &received(i)).unwrap()).collect::<Vec<_>>();
+        let public_key_package = keys[0].1.clone();
+        assert!(keys.iter().all(|(_, public)| *public == public_key_package));
+        assert_eq!(public_key_package.min_signers, 2);
+        let group_secret = sss::combine(&[0, 2].map(|i| sss::Share { index: ids[i], value: keys[i].0.signing_share })).unwrap();
+        assert_eq!(PrimeOrderCurve32::generator() * group_secret, public_key_package.group_public_key);
+        //sign with participants 2 and 3
+        let msg = b"threshold message";
+        let (nonces2, commitments2) = frost::commit(&keys[1].0);
+        let (nonces3, commitments3) = frost::commit(&keys[2].0);
+        assert_eq!(format!("{nonces3:?}"), format!("SigningNonces [curve {}]", PrimeOrderCurve32::NAME));
+        let commitments = [commitments2, commitments3];
+        assert_eq!(frost::sign(msg, frost::commit(&keys[1].0).0, &keys[1].0, &commitments), Err(frost::Error::MissingCommitment));
+        let signature_shares = [
+            (ids[1], frost::sign(msg, nonces2, &keys[1].0, &commitments).unwrap()),
+            (ids[2], frost::sign(msg, nonces3, &keys[2].0, &commitments).unwrap()),
+        ].into();
+        let signature = frost::aggregate(&commitments, msg, &signature_shares, &public_key_package).unwrap();
+        assert!(signature.verify(msg, &public_key_package.group_public_key));
+        assert_eq!(frost::Signature::<TestSuite>::deserialize(&signature.serialize()), Ok(signature));
+        //trusted dealer on the same suite
+        let (key_packages, public_key_package, _) = frost::trusted_dealer_keygen::<TestSuite>(group_secret, 2, 3).unwrap();
+        assert_eq!(public_key_package.group_public_key, PrimeOrderCurve32::generator() * group_secret);
+        assert_eq!(key_packages.len(), 3);
+        assert_eq!(frost::trusted_dealer_keygen::<TestSuite>(group_secret, 4, 3).unwrap_err(), frost::Error::InvalidParameters);
+        //a single signer is below the threshold of 2
+        assert_eq!(public_key_package.min_signers, 2);
+        let (nonces, commitments) = frost::commit(&key_packages[0]);
+        let share = frost::sign(msg, nonces, &key_packages[0], &[commitments]).unwrap();
+        let signature_shares = [(key_packages[0].identifier, share)].into();
+        assert_eq!(frost::aggregate(&[commitments], msg, &signature_shares, &public_key_package), Err(frost::Error::InvalidParameters));
+    }
+    #[test]
+    fn test_bip340() {
+        type ScalarK = crate::types::Scalar<Secp256k1>;
+        let bytes32 = |s| <[u8; 32]>::try_from(hex(s)).unwrap();
+        let bytes64 = |s| <[u8; 64]>::try_from(hex(s)).unwrap();
+        //BIP340 test vectors 0 and 1
+        let signature = schnorr::sign(ScalarK::new(3), &[0; 32], &[0; 32]).unwrap();
+        assert_eq!(signature, bytes64("e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0"));
+        let public_key = schnorr::public_key(ScalarK::new(3));
+        assert_eq!(public_key, bytes32("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"));
+        assert!(schnorr::verify(&public_key, &[0; 32], &signature));
+        let secret = ScalarK::from_be_bytes(&hex("b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef")).unwrap();
+        let msg = hex("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89");
+        let mut aux = [0; 32];
+        aux[31] = 1;
+        let signature = schnorr::sign(secret, &msg, &aux).unwrap();
+        assert_eq!(signature, bytes64("6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a"));
+        let public_key = schnorr::public_key(secret);
+        assert!(schnorr::verify(&public_key, &msg, &signature));
+        assert!(!schnorr::verify(&public_key, &[0; 32], &signature));
+        let mut bad = signature;
+        bad[63] ^= 1;
+        assert!(!schnorr::verify(&public_key, &msg, &bad));
+        assert_eq!(schnorr::sign(ScalarK::zero(), &msg, &aux), None);
+        assert_eq!(ECpointSecp256k1::lift_x(Secp256k1::P), None);
+    }
+    #[test]
+    fn test_musig2() {
+        type ScalarK = crate::types::Scalar<Secp256k1>;
+        let point = |s| ECpointSecp256k1::from_sec1(&hex(s)).unwrap();
+        let bytes32 = |s| <[u8; 32]>::try_from(hex(s)).unwrap();
+        //BIP327 key_agg_vectors.json
+        let pk = [
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            "023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66",
+        ].map(point);
+        for (indices, expected) in [
+            (vec![0, 1, 2], "90539eede565f5d054f32cc0c220126889ed1e5d193baf15aef344fe59d4610c"),
+            (vec![2, 1, 0], "6204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b"),
+            (vec![0, 0, 0], "b436e3bad62b8cd409969a224731c193d051162d8c5ae8b109306127da3aa935"),
+        ] {
+            let keys = indices.iter().map(|&i| pk[i]).collect::<Vec<_>>();
+            assert_eq!(musig::KeyAggContext::new(&keys).unwrap().x_only_public_key(), bytes32(expected));
+        }
+        assert_eq!(musig::key_sort(&[pk[1], pk[2], pk[0]]), vec![pk[2], pk[0], pk[1]]);
+        assert!(ECpointSecp256k1::from_sec1(&hex("020000000000000000000000000000000000000000000000000000000000000005")).is_none());
+        assert_eq!(musig::KeyAggContext::new(&[pk[0], ECpointSecp256k1::Infinity]), Err(musig::Error::InvalidPublicKey(1)));
+        //BIP327 nonce_gen_vectors.json, first case
+        let (secnonce, pubnonce) = musig::nonce_gen_with_randomness(&[0x0f; 32], Some(ScalarK::from_be_bytes(&[0x02; 32]).unwrap()),
+            &point("024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766"), Some(&[0x07; 32]), Some(&[0x01; 32]), Some(&[0x08; 32]));
+        assert_eq!(secnonce.to_bytes().to_vec(), hex("b114e502beaa4e301dd08a50264172c84e41650e6cb726b410c0694d59effb6495b5caf28d045b973d63e3c99a44b807bde375fd6cb39e46dc4a511708d0e9d2024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766"));
+        assert_eq!(musig::SecNonce::from_bytes(&secnonce.to_bytes()).unwrap().to_bytes(), secnonce.to_bytes());
+        assert_eq!(format!("{secnonce:?}"), "SecNonce [curve Secp256k1]");
+        assert_eq!(musig::PubNonce::from_bytes(&pubnonce.to_bytes()), Ok(pubnonce));
+        //BIP327 sign_verify_vectors.json and tweak_vectors.json
+        let secret = ScalarK::from_be_bytes(&hex("7fb9e0e687ada1eebf7ecfe2f21e73ebdb51a7d450948dfe8d76d7f2d1007671")).unwrap();
+        let secnonce = || musig::SecNonce::from_bytes(&hex("508b81a611f100a6b2b6b29656590898af488bcf2e1f55cf22e5cfb84421fe61fa27fd49b1d50085b481285e1ca205d55c82cc1b31ff5cd54a489829355901f703935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9").try_into().unwrap()).unwrap();
+        let keys = [
+            "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba661",
+        ].map(point);
+        let pubnonces = [
+            "0337c87821afd50a8644d820a8f3e02e499c931865c2360fb43d0a0d20dafe07ea0287bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480",
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f817980279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "032de2662628c90b03f5e720284eb52ff7d71f4284f627b68a853d78c78e1ffe9303e4c5524e83ffe1493b9077cf1ca6beb2090c93d930321071ad40b2f44e599046",
+        ].map(|s| musig::PubNonce::from_bytes(&hex(s).try_into().unwrap()).unwrap());
+        let aggnonce = musig::nonce_agg(&pubnonces);
+        assert_eq!(aggnonce.to_bytes().to_vec(), hex("028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9"));
+        assert_eq!(musig::AggNonce::from_bytes(&aggnonce.to_bytes()), Ok(aggnonce));
+        let msg = hex("f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf");
+        let session = musig::Session::new(aggnonce, musig::KeyAggContext::new(&keys).unwrap(), &msg);
+        let partial_signature = session.sign(secnonce(), secret).unwrap();
+        assert_eq!(partial_signature.to_be_bytes(), hex("012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb"));
+        assert!(session.verify_partial_signature(&partial_signature, &pubnonces[0], &keys[0]));
+        assert!(!session.verify_partial_signature(&partial_signature, &pubnonces[1], &keys[0]));
+        let session = musig::Session::new(musig::nonce_agg(&[pubnonces[1], pubnonces[0], pubnonces[2]]), musig::KeyAggContext::new(&[keys[1], keys[0], keys[2]]).unwrap(), &msg);
+        assert_eq!(session.sign(secnonce(), secret).unwrap().to_be_bytes(), hex("9ff2f7aaa856150cc8819254218d3adeeb0535269051897724f9db3789513a52"));
+        assert_eq!(session.sign(secnonce(), ScalarK::new(1)), Err(musig::Error::InvalidSecretNonce));
+        let tweak_keys = [keys[1], point("02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"), keys[0]];
+        let tweak_nonces = musig::nonce_agg(&[pubnonces[1], pubnonces[2], pubnonces[0]]);
+        let tweaks = [
+            "e8f791ff9225a2af0102afff4a9a723d9612a682a25ebe79802b263cdfcd83bb",
+            "ae2ea797cc0fe72ac5b97b97f3c6957d7e4199a167a58eb08bcaffda70ac0455",
+            "f52ecbc565b3d8bea2dfd5b75a4f457e54369809322e4120831626f290fa87e0",
+            "1969ad73cc177fa0b4fced6df1f7bf9907e665fde9ba196a74fed0a3cf5aef9d",
+        ].map(bytes32);
+        for (is_xonly, expected) in [
+            (vec![true], "e28a5c66e61e178c2ba19db77b6cf9f7e2f0f56c17918cd13135e60cc848fe91"),
+            (vec![false], "38b0767798252f21bf5702c48028b095428320f73a4b14db1e25de58543d2d2d"),
+            (vec![false, false, true, true], "45abd206e61e3df2ec9e264a6fec8292141a633c28586388235541f9ade75435"),
+        ] {
+            let key_agg = is_xonly.iter().zip(&tweaks)
+                .try_fold(musig::KeyAggContext::new(&tweak_keys).unwrap(), |ctx, (&x, t)| ctx.apply_tweak(t, x))
+                .unwrap();
+            let session = musig::Session::new(tweak_nonces, key_agg, &msg);
+            assert_eq!(session.sign(secnonce(), secret).unwrap().to_be_bytes(), hex(expected));
+        }
+        let mut n = [0u8; 32];
+        Secp256k1::N.to_big_endian(&mut n);
+        assert_eq!(musig::KeyAggContext::new(&keys).unwrap().apply_tweak(&n, false), Err(musig::Error::InvalidTweak));
+        //two signers with a taproot style x-only tweak, the result is a valid BIP340 signature
+        let secrets = [ScalarK::generate_secret(), ScalarK::generate_secret()];
+        let public_keys = musig::key_sort(&secrets.map(|s| Secp256k1::generator() * s));
+        let key_agg = musig::KeyAggContext::new(&public_keys).unwrap().apply_tweak(&tweaks[0], true).unwrap();
+        let msg = b"musig2 message";
+        let nonces = secrets.map(|s| musig::nonce_gen(Some(s), &(Secp256k1::generator() * s), Some(&key_agg.x_only_public_key()), Some(msg), None));
+        let session = musig::Session::new(musig::nonce_agg(&[nonces[0].1, nonces[1].1]), key_agg.clone(), msg);
+        let [(secnonce0, pubnonce0), (secnonce1, _)] = nonces;
+        let partial_signatures = [session.sign(secnonce0, secrets[0]).unwrap(), session.sign(secnonce1, secrets[1]).unwrap()];
+        assert!(session.verify_partial_signature(&partial_signatures[0], &pubnonce0, &(Secp256k1::generator() * secrets[0])));
+        let signature = session.aggregate(&partial_signatures);
+        assert!(schnorr::verify(&key_agg.x_only_public_key(), msg, &signature));
+        assert!(!schnorr::verify(&key_agg.x_only_public_key(), msg, &session.aggregate(&partial_signatures[..1])));
+    }
+    #[test]
+    fn test_schnorr_proof() {
+        let g = PrimeOrderCurve32::generator();
+        let secret = Scalar32::generate_secret();
+        let public = g * secret;
+        let proof = proofs::SchnorrProof::prove(&mut proofs::Transcript::new(b"test"), secret);
+        assert!(proof.verify(&mut proofs::Transcript::new(b"test"), &public));
+        assert!(!proof.verify(&mut proofs::Transcript::new(b"other domain"), &public));
+        assert!(!proof.verify(&mut proofs::Transcript::new(b"test"), &(public + g)));
+        assert_eq!(proofs::SchnorrProof::from_bytes(&proof.to_bytes()), Some(proof));
+        assert_eq!(proofs::SchnorrProof::<PrimeOrderCurve32>::from_bytes(&proof.to_bytes()[1..]), None);
+        //batch verification
+        let secrets = [0; 4].map(|_| Scalar32::generate_secret());
+        let publics = secrets.map(|k| g * k);
+        let mut proofs = secrets.map(|k| proofs::SchnorrProof::prove(&mut proofs::Transcript::new(b"batch"), k));
+        assert!(proofs::SchnorrProof::verify_batch(&mut [0; 4].map(|_| proofs::Transcript::new(b"batch")), &publics, &proofs));
+        proofs[2].response += Scalar32::one();
+        assert!(!proofs::SchnorrProof::verify_batch(&mut [0; 4].map(|_| proofs::Transcript::new(b"batch")), &publics, &proofs));
+        assert_eq!(multiscalar_mul(&[Scalar32::new(3), Scalar32::new(5)], &[g, g * 2]), g * 13);
+        //challenges depend on everything absorbed before
+        let mut t1 = proofs::Transcript::new(b"test");
+        let mut t2 = t1.clone();
+        t1.append_message(b"a", b"bc");
+        t2.append_message(b"ab", b"c");
+        assert_ne!(t1.challenge_scalar::<PrimeOrderCurve32>(b"c"), t2.challenge_scalar::<PrimeOrderCurve32>(b"c"));
+        assert_ne!(t1.challenge_scalar::<PrimeOrderCurve32>(b"c"), t1.challenge_scalar::<PrimeOrderCurve32>(b"c"));
+    }
+    #[test]
+    fn test_dleq_proof() {
+        let g = PrimeOrderCurve32::generator();
+        let h = hash_to_point::<PrimeOrderCurve32>(b"DLEQ generator H");
+        let secret = Scalar32::generate_secret();
+        let (a, b) = (g * secret, h * secret);
+        let proof = proofs::DleqProof::prove(&mut proofs::Transcript::new(b"test"), &h, secret);
+        assert!(proof.verify(&mut proofs::Transcript::new(b"test"), &h, &a, &b));
+        assert!(!proof.verify(&mut proofs::Transcript::new(b"test"), &h, &a, &(b + h)));
+        assert!(!proof.verify(&mut proofs::Transcript::new(b"test"), &h, &(a + g), &(b + h)));
+        assert_eq!(proofs::DleqProof::from_bytes(&proof.to_bytes()), Some(proof));
+        //the ECDH shared secret k * B was computed with the secret key of A = k * G
+        let bob = g * Scalar32::generate_secret();
+        let proof = proofs::DleqProof::prove(&mut proofs::Transcript::new(b"ecdh"), &bob, secret);
+        assert!(proof.verify(&mut proofs::Transcript::new(b"ecdh"), &bob, &a, &(bob * secret)));
+        //batch verification
+        let secrets = [0; 3].map(|_| Scalar32::generate_secret());
+        let statements = secrets.map(|k| (g * k, h * k));
+        let mut proofs = secrets.map(|k| proofs::DleqProof::prove(&mut proofs::Transcript::new(b"batch"), &h, k));
+        assert!(proofs::DleqProof::verify_batch(&mut [0; 3].map(|_| proofs::Transcript::new(b"batch")), &h, &statements, &proofs));
+        proofs[1].commitment_h += h;
+        assert!(!proofs::DleqProof::verify_batch(&mut [0; 3].map(|_| proofs::Transcript::new(b"batch")), &h, &statements, &proofs));
+    }
+    #[test]
+    fn test_sigma_protocols() {
+        let g = PrimeOrderCurve32::generator();
+        let h = hash_to_point::<PrimeOrderCurve32>(b"sigma generator H");
+        //the Pedersen commitment C = v * G + r * H and the ElGamal ciphertext
+        //(s * G, v * G + s * PK) hide the same value v, witness (v, r, s)
+        let public = elgamal::keygen::<PrimeOrderCurve32>().1;
+        let (v, r, s) = (Scalar32::new(42), Scalar32::generate_secret(), Scalar32::generate_secret());
+        let c = g * v + h * r;
+        let ciphertext = elgamal::encrypt_with_randomness(public, v, s);
+        let mut relation = sigma::LinearRelation::new(3);
+        relation.add_equation(c, &[(0, g), (1, h)])
+            .add_equation(ciphertext.c1, &[(2, g)])
+            .add_equation(ciphertext.c2, &[(0, g), (2, public)]);
+        let statement = sigma::Statement::Linear(relation.clone());
+        let witness = sigma::Witness::Linear(vec![v, r, s]);
+        let proof = statement.prove(&mut proofs::Transcript::new(b"test"), &witness).unwrap();
+        assert!(statement.verify(&mut proofs::Transcript::new(b"test"), &proof));
+        assert!(!statement.verify(&mut proofs::Transcript::new(b"other"), &proof));
+        assert_eq!(statement.prove(&mut proofs::Transcript::new(b"test"), &sigma::Witness::Linear(vec![v + Scalar32::one(), r, s])), None);
+        let mut other = relation.clone();
+        other.add_equation(c, &[(0, g)]);
+        assert!(!sigma::Statement::Linear(other).verify(&mut proofs::Transcript::new(b"test"), &proof));
+        //AND of a discrete log and a DLEQ
+        let (x, y) = (Scalar32::generate_secret(), Scalar32::generate_secret());
+        let and = sigma::Statement::And(vec![
+            sigma::Statement::Linear(sigma::LinearRelation::discrete_log(g, g * x)),
+            sigma::Statement::Linear(sigma::LinearRelation::dleq(g, g * y, h, h * y)),
+        ]);
+        let witness = sigma::Witness::And(vec![sigma::Witness::Linear(vec![x]), sigma::Witness::Linear(vec![y])]);
+        let proof = and.prove(&mut proofs::Transcript::new(b"test"), &witness).unwrap();
+        assert_eq!(proof.commitments.len(), 3);
+        assert!(and.verify(&mut proofs::Transcript::new(b"test"), &proof));
+        //OR, knowing the discrete log of either key is enough and the proofs look alike
+        let or = sigma::Statement::Or(vec![
+            sigma::Statement::Linear(sigma::LinearRelation::discrete_log(g, g * x)),
+            sigma::Statement::Linear(sigma::LinearRelation::pedersen_opening(g, h, c)),
+        ]);
+        for witness in [sigma::Witness::Or(0, Box::new(sigma::Witness::Linear(vec![x]))), sigma::Witness::Or(1, Box::new(sigma::Witness::Linear(vec![v, r])))] {
+            let proof = or.prove(&mut proofs::Transcript::new(b"test"), &witness).unwrap();
+            assert!(or.verify(&mut proofs::Transcript::new(b"test"), &proof));
+            let mut bad = proof.clone();
+            if let sigma::Response::Or(branches) = &mut bad.response {
+                branches[0].0 += Scalar32::one();
+                branches[1].0 -= Scalar32::one();
+            }
+            assert!(!or.verify(&mut proofs::Transcript::new(b"test"), &bad));
+        }
+        assert_eq!(or.prove(&mut proofs::Transcript::new(b"test"), &sigma::Witness::Or(0, Box::new(sigma::Witness::Linear(vec![y])))), None);
+        //nested, (log x AND log y) OR Pedersen opening
+        let nested = sigma::Statement::Or(vec![and, or]);
+        let proof = nested.prove(&mut proofs::Transcript::new(b"test"), &sigma::Witness::Or(0, Box::new(witness))).unwrap();
+        assert!(nested.verify(&mut proofs::Transcript::new(b"test"), &proof));
+        let mut t1 = proofs::Transcript::new(b"test");
+        let mut t2 = t1.clone();
+        t1.append_zp(b"x", &crate::types::Zp::<PrimeOrderCurve32>::new(1));
+        t2.append_zp(b"x", &crate::types::Zp::<PrimeOrderCurve32>::new(2));
+        assert_ne!(t1, t2);
+    }
+    #[test]
+    fn test_bulletproofs() {
+        let pc_gens = bulletproofs::PedersenGens::<Secp256k1>::default();
+        let bp_gens = bulletproofs::BulletproofGens::<Secp256k1>::new(16);
+        //inner product argument on its own, P = <a, G> + <b, H> + <a, b> * Q
+        let q = hash_to_point::<Secp256k1>(b"inner product Q");
+        let (a, b) = ((1..=4).map(ScalarSecp256k1::new).collect::<Vec<_>>(), (5..=8).map(ScalarSecp256k1::new).collect::<Vec<_>>());
+        let (g, h) = (&bp_gens.g_vec[..4], &bp_gens.h_vec[..4]);
+        let p = multiscalar_mul(&[a.as_slice(), &b, &[ScalarSecp256k1::new(70)]].concat(), &[g, h, &[q]].concat());
+        let ipp = bulletproofs::InnerProductProof::prove(&mut proofs::Transcript::new(b"test"), &q, &[ScalarSecp256k1::one(); 4], g.to_vec(), h.to_vec(), a, b);
+        assert_eq!(ipp.l_vec.len(), 2);
+        assert!(ipp.verify(&mut proofs::Transcript::new(b"test"), &q, &p, g, h));
+        assert!(!ipp.verify(&mut proofs::Transcript::new(b"test"), &q, &(p + q), g, h));
+        //single 16 bit value
+        let blinding = ScalarSecp256k1::generate_secret();
+        let (proof, commitment) = bulletproofs::RangeProof::prove_single(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), 65535, blinding, 16).unwrap();
+        assert_eq!(commitment, pc_gens.commit(ScalarSecp256k1::new(65535), blinding));
+        assert!(proof.verify_single(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &commitment, 16));
+        assert!(!proof.verify_single(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"other"), &commitment, 16));
+        assert!(!proof.verify_single(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &(commitment + pc_gens.b), 16));
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), (4 + 2 * 4) * 33 + 5 * 32);
+        assert_eq!(bulletproofs::RangeProof::from_bytes(&bytes), Some(proof.clone()));
+        assert_eq!(bulletproofs::RangeProof::<Secp256k1>::from_bytes(&bytes[1..]), None);
+        //64 L, R pairs decode fine but do not fit 16 bits, verifying must not overflow
+        let ipp_start = 4 * 33 + 3 * 32;
+        let malformed = [&bytes[..ipp_start], &q.to_sec1(true).repeat(2 * 64), &bytes[bytes.len() - 2 * 32..]].concat();
+        let malformed = bulletproofs::RangeProof::<Secp256k1>::from_bytes(&malformed).unwrap();
+        assert!(!malformed.verify_single(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &commitment, 16));
+        let mut tampered = proof;
+        tampered.t_x += ScalarSecp256k1::one();
+        assert!(!tampered.verify_single(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &commitment, 16));
+        //aggregated, two 8 bit values
+        let blindings = [ScalarSecp256k1::generate_secret(), ScalarSecp256k1::generate_secret()];
+        let (proof, commitments) = bulletproofs::RangeProof::prove_multiple(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &[8, 255], &blindings, 8).unwrap();
+        assert!(proof.verify_multiple(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &commitments, 8));
+        assert!(!proof.verify_multiple(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &[commitments[1], commitments[0]], 8));
+        //-v commits to a huge value and cannot be proven, nor can 256 in 8 bits
+        let negative = pc_gens.commit(-ScalarSecp256k1::new(8), blindings[0]);
+        assert!(!proof.verify_multiple(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), &[negative, commitments[1]], 8));
+        let prove = |values: &[u64], blindings: &[ScalarSecp256k1], n| bulletproofs::RangeProof::prove_multiple(&bp_gens, &pc_gens, &mut proofs::Transcript::new(b"test"), values, blindings, n).err();
+        assert_eq!(prove(&[256, 0], &blindings, 8), Some(bulletproofs::Error::ValueOutOfRange));
+        assert_eq!(prove(&[1, 2, 3], &[blindings[0]; 3], 8), Some(bulletproofs::Error::InvalidAggregation));
+        assert_eq!(prove(&[1], &blindings, 8), Some(bulletproofs::Error::WrongNumBlindingFactors));
+        assert_eq!(prove(&[1], &blindings[..1], 12), Some(bulletproofs::Error::InvalidBitsize));
+        assert_eq!(prove(&[1], &blindings[..1], 32), Some(bulletproofs::Error::InvalidGeneratorsLength));
+    }
+    #[test]
+    fn test_ring_signatures() {
+        let g = PrimeOrderCurve32::generator();
+        let secrets = [0; 4].map(|_| Scalar32::generate_secret());
+        let ring = secrets.map(|x| g * x);
+        //LSAG, any member can sign and the key image is the same in every ring
+        for (index, &secret) in secrets.iter().enumerate() {
+            let signature = ringsig::LsagSignature::sign(b"spend", &ring, index, secret).unwrap();
+            assert_eq!(signature.key_image, ringsig::key_image(secret));
+            assert!(signature.verify(b"spend", &ring));
+            assert!(!signature.verify(b"other", &ring));
+            assert!(!signature.verify(b"spend", &ring[..3]));
+        }
+        let signature = ringsig::LsagSignature::sign(b"spend", &ring, 1, secrets[1]).unwrap();
+        let other = ringsig::LsagSignature::sign(b"spend again", &[ring[3], ring[1]], 1, secrets[1]).unwrap();
+        assert!(other.verify(b"spend again", &[ring[3], ring[1]]));
+        assert!(signature.is_linked(&other));
+        assert!(!signature.is_linked(&ringsig::LsagSignature::sign(b"spend", &ring, 2, secrets[2]).unwrap()));
+        let mut forged = signature.clone();
+        forged.key_image = ringsig::key_image(secrets[0]);
+        assert!(!forged.verify(b"spend", &ring));
+        assert_eq!(ringsig::LsagSignature::sign(b"spend", &ring, 0, secrets[1]), None);
+        assert_eq!(ringsig::LsagSignature::sign(b"spend", &ring, 4, secrets[1]), None);
+        //single member ring on secp256k1
+        let secret = ScalarSecp256k1::generate_secret();
+        let ring256 = [Secp256k1::generator() * secret];
+        assert!(ringsig::LsagSignature::sign(b"spend", &ring256, 0, secret).unwrap().verify(b"spend", &ring256));
+        //CLSAG, the amount commitments C_i = v_i * H + r_i * G and the pseudo output
+        //C' = v * H + r' * G commit to the same amount v
+        let h = hash_to_point::<PrimeOrderCurve32>(b"amount generator");
+        let blindings = [0; 4].map(|_| Scalar32::generate_secret());
+        let ring = (0..4).map(|i| (ring[i], h * Scalar32::new(10 + i as u64) + g * blindings[i])).collect::<Vec<_>>();
+        let pseudo_blinding = Scalar32::generate_secret();
+        let pseudo_output = h * Scalar32::new(12) + g * pseudo_blinding;
+        let signature = ringsig::ClsagSignature::sign(b"spend", &ring, &pseudo_output, 2, secrets[2], blindings[2] - pseudo_blinding).unwrap();
+        assert_eq!(signature.key_image, ringsig::key_image(secrets[2]));
+        assert!(signature.verify(b"spend", &ring, &pseudo_output));
+        assert!(!signature.verify(b"other", &ring, &pseudo_output));
+        assert!(!signature.verify(b"spend", &ring, &(pseudo_output + h)));
+        assert!(signature.is_linked(&ringsig::ClsagSignature::sign(b"spend", &ring[1..], &pseudo_output, 1, secrets[2], blindings[2] - pseudo_blinding).unwrap()));
+        let mut forged = signature.clone();
+        forged.commitment_image += g;
+        assert!(!forged.verify(b"spend", &ring, &pseudo_output));
+        //the amount of member 1 differs from the pseudo output
+        assert_eq!(ringsig::ClsagSignature::sign(b"spend", &ring, &pseudo_output, 1, secrets[1], blindings[1] - pseudo_blinding), None);
+    }
+    #[test]
+    fn test_ecdsa() {
+        //signature of "sample" with SHA-256 made by OpenSSL through pyca/cryptography
+        let secret = ScalarSecp256k1::from_be_bytes(&hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")).unwrap();
+        let public = ECpointSecp256k1::from_sec1(&hex("042c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae64564b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085")).unwrap();
+        assert_eq!(Secp256k1::generator() * secret, public);
+        let signature = ecdsa::Signature::from_bytes(&hex("9d34dcfb9358794022f1ac1d1af52f05c42dd3cb46b91833cc0ad06d04d1fb2d9b961c4962d8df440f06fe839c84081708739d0ac5c23e9e95eba72361e5248d")).unwrap();
+        assert!(ecdsa::verify(&public, b"sample", &signature));
+        assert!(ecdsa::verify(&public, b"sample", &signature.normalize_s()));
+        assert!(signature.normalize_s().s.unwrap() <= Secp256k1::N >> 1);
+        assert!(!ecdsa::verify(&public, b"other", &signature));
+        let ours = ecdsa::sign(secret, b"sample");
+        assert!(ecdsa::verify(&public, b"sample", &ours));
+        assert_eq!(ecdsa::Signature::from_bytes(&ours.to_bytes()), Some(ours));
+        let g = PrimeOrderCurve32::generator();
+        let secret = Scalar32::generate_secret();
+        assert!(ecdsa::verify(&(g * secret), b"msg", &ecdsa::sign(secret, b"msg")));
+        assert_eq!(ecdsa::sign_prehashed(secret, Scalar32::one(), Scalar32::zero()), None);
+        //on Curve127p the first RFC 6979 nonce k = 15 for d = 90 gives s = 0, step h
+        //moves on to k = 58 and the signature (57, 51)
+        let hash = ring::digest::digest(&ring::digest::SHA256, b"msg");
+        let z = ecdsa::bits_to_scalar(hash.as_ref());
+        assert_eq!(ecdsa::rfc6979_nonces(Scalar::new(90), hash.as_ref()).take(2).collect::<Vec<_>>(), [Scalar::new(15), Scalar::new(58)]);
+        assert_eq!(ecdsa::sign_prehashed(Scalar::new(90), z, Scalar::new(15)), None);
+        let signature = ecdsa::sign_deterministic(Scalar::new(90), b"msg");
+        assert_eq!((signature.r, signature.s), (Scalar::new(57), Scalar::new(51)));
+        assert!(ecdsa::verify(&(EllipticCurve::generator() * Scalar::new(90)), b"msg", &signature));
+        //RFC 6979 A.2.5, P-256 with SHA-256
+        let secret = crate::types::Scalar::<P256>::from_be_bytes(&hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")).unwrap();
+        for (msg, k, expected) in [
+            (b"sample".as_slice(), "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60", "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"),
+            (b"test", "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0", "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083"),
+        ] {
+            let hash = ring::digest::digest(&ring::digest::SHA256, msg);
+            assert_eq!(ecdsa::rfc6979_nonce(secret, hash.as_ref()).to_be_bytes(), hex(k));
+            let signature = ecdsa::sign_deterministic(secret, msg);
+            assert_eq!(signature.to_bytes(), hex(expected));
+            assert!(ecdsa::verify(&(P256::generator() * secret), msg, &signature));
+        }
+    }
+    #[test]
+    fn test_adaptor_signatures() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+        let g = Secp256k1::generator();
+        //random pre-signatures complete to valid signatures that reveal t. The keys and
+        //messages come from a fixed seed so a failure can be replayed, the nonces the
+        //signers pick are still random.
+        let mut rng = StdRng::seed_from_u64(41);
+        for _ in 0..32 {
+            let (secret, t) = (ScalarSecp256k1::new(U256(rng.gen())), ScalarSecp256k1::new(U256(rng.gen())));
+            let msg = rng.gen::<[u8; 32]>();
+            let (public, adaptor) = (schnorr::public_key(secret), g * t);
+            let pre = adaptor::SchnorrPreSignature::sign(secret, &msg, &adaptor).unwrap();
+            assert!(pre.verify(&public, &msg, &adaptor));
+            assert!(!pre.verify(&public, &msg, &(adaptor + g)));
+            assert!(!schnorr::verify(&public, &msg, &pre.to_bytes()));
+            let signature = pre.complete(t);
+            assert!(schnorr::verify(&public, &msg, &signature));
+            assert_eq!(pre.extract(&signature, &adaptor), Some(t));
+            assert_eq!(adaptor::SchnorrPreSignature::from_bytes(&pre.to_bytes()), Some(pre));
+            let pre = adaptor::EcdsaPreSignature::sign(secret, &msg, &adaptor).unwrap();
+            let public = g * secret;
+            assert!(pre.verify(&public, &msg, &adaptor));
+            assert!(!pre.verify(&public, b"other", &adaptor));
+            assert!(!pre.verify(&public, &msg, &(adaptor + g)));
+            let signature = pre.complete(t);
+            assert!(ecdsa::verify(&public, &msg, &signature));
+            assert_eq!(pre.extract(&signature, &adaptor), Some(t));
+            assert_eq!(pre.extract(&signature.normalize_s(), &adaptor), Some(t));
+        }
+        //the ECDSA variant is generic, on a 32 bit curve many more cases are cheap
+        let g32 = PrimeOrderCurve32::generator();
+        for _ in 0..512 {
+            let (secret, t) = (Scalar32::new(rng.gen::<u32>()), Scalar32::new(rng.gen::<u32>()));
+            let msg = rng.gen::<[u8; 32]>();
+            let (public, adaptor) = (g32 * secret, g32 * t);
+            let pre = adaptor::EcdsaPreSignature::sign(secret, &msg, &adaptor).unwrap();
+            assert!(pre.verify(&public, &msg, &adaptor));
+            assert!(!pre.verify(&public, &msg, &(adaptor + g32)));
+            let signature = pre.complete(t);
+            assert!(ecdsa::verify(&public, &msg, &signature));
+            assert_eq!(pre.extract(&signature, &adaptor), Some(t));
+            assert_eq!(pre.extract(&signature.normalize_s(), &adaptor), Some(t));
+        }
+        //atomic swap, Alice pre-signs her payment to Bob under T, Bob completes it with
+        //t and publishing it lets Alice complete Bob's payment, pre-signed under T too
+        let (alice, bob, t) = (ScalarSecp256k1::generate_secret(), ScalarSecp256k1::generate_secret(), ScalarSecp256k1::generate_secret());
+        let adaptor = g * t;
+        let alice_pre = adaptor::SchnorrPreSignature::sign(alice, b"alice pays bob", &adaptor).unwrap();
+        let bob_pre = adaptor::EcdsaPreSignature::sign(bob, b"bob pays alice", &adaptor).unwrap();
+        assert!(alice_pre.verify(&schnorr::public_key(alice), b"alice pays bob", &adaptor));
+        assert!(bob_pre.verify(&(g * bob), b"bob pays alice", &adaptor));
+        let alice_signature = alice_pre.complete(t);
+        let learned = alice_pre.extract(&alice_signature, &adaptor).unwrap();
+        assert!(ecdsa::verify(&(g * bob), b"bob pays alice", &bob_pre.complete(learned)));
+        assert_eq!(alice_pre.extract(&bob_pre.complete(learned).to_bytes().try_into().unwrap(), &adaptor), None);
+    }
+    #[test]
+    fn test_ecvrf() {
+        //RFC 9380 J.1.1, P256_XMD:SHA-256_SSWU_RO_
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+        let point = hash_to_curve_sswu::<P256>(b"", dst, crate::types::Zp::new(-10));
+        assert_eq!(point.to_sec1(false), hex("042c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e48a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415"));
+        let secret = crate::types::Scalar::<P256>::from_be_bytes(&hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")).unwrap();
+        let public = P256::generator() * secret;
+        assert_eq!(public.to_sec1(true), hex("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"));
+        //RFC 9381 B.1, examples 10 and 11
+        assert_eq!(<vrf::P256Sha256Tai as vrf::Suite>::encode_to_curve(&public.to_sec1(true), b"sample").to_sec1(true), hex("0272a877532e9ac193aff4401234266f59900a4a9e3fc3cfc6a4b7e467a15d06d4"));
+        for (alpha, pi, beta) in [
+            (b"sample".as_slice(), "035b5c726e8c0e2c488a107c600578ee75cb702343c153cb1eb8dec77f4b5071b4a53f0a46f018bc2c56e58d383f2305e0975972c26feea0eb122fe7893c15af376b33edf7de17c6ea056d4d82de6bc02f", "a3ad7b0ef73d8fc6655053ea22f9bede8c743f08bbed3d38821f0e16474b505e"),
+            (b"test", "034dac60aba508ba0c01aa9be80377ebd7562c4a52d74722e0abae7dc3080ddb56c19e067b15a8a8174905b13617804534214f935b94c2287f797e393eb0816969d864f37625b443f30f1a5a33f2b3c854", "a284f94ceec2ff4b3794629da7cbafa49121972671b466cab4ce170aa365f26d"),
+        ] {
+            let proof = vrf::Proof::<vrf::P256Sha256Tai>::prove(secret, alpha);
+            assert_eq!(proof.to_bytes(), hex(pi));
+            assert_eq!(proof.verify(&public, alpha).map(Vec::from), Some(hex(beta)));
+            assert_eq!(vrf::Proof::from_bytes(&hex(pi)), Some(proof));
+        }
+        //RFC 9381 B.2, example 13
+        let proof = vrf::Proof::<vrf::P256Sha256Sswu>::prove(secret, b"sample");
+        assert_eq!(proof.to_bytes(), hex("0331d984ca8fece9cbb9a144c0d53df3c4c7a33080c1e02ddb1a96a365394c7888782fffde7b842c38c20c08de6ec6c2e7027a97000f2c9fa4425d5c03e639fb48fde58114d755985498d7eb234cf4aed9"));
+        assert_eq!(proof.verify(&public, b"sample").map(Vec::from), Some(hex("21e66dc9747430f17ed9efeda054cf4a264b097b9e8956a1787526ed00dc664b")));
+        assert_eq!(proof.verify(&public, b"test"), None);
+        assert_eq!(proof.verify(&(public + P256::generator()), b"sample"), None);
+        let mut bad = proof;
+        bad.s += crate::types::Scalar::one();
+        assert_eq!(bad.verify(&public, b"sample"), None);
+        assert_eq!(vrf::Proof::<vrf::P256Sha256Sswu>::from_bytes(&proof.to_bytes()[1..]), None);
+    }
+    #[test]
+    fn test_oprf() {
+        type ScalarP256 = crate::types::Scalar<P256>;
+        let (seed, info) = ([0xa3; 32], b"test key");
+        let blinds = ["3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364", "f9db001266677f62c095021db018cd8cbb55941d4073698ce45c405d1348b7b1"]
+            .map(|b| ScalarP256::from_be_bytes(&hex(b)).unwrap());
+        let inputs = [hex("00"), hex("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a")];
+        //RFC 9497 A.3.1, OPRF(P-256, SHA-256) test vector 1
+        let server = oprf::Server::<oprf::P256Sha256>::derive_key_pair(oprf::Mode::Oprf, &seed, info).unwrap();
+        assert_eq!(server.secret_key().to_be_bytes(), hex("159749d750713afe245d2d39ccfaae8381c53ce92d098a9375ee70739c7ac0bf"));
+        let client = oprf::Client::<oprf::P256Sha256>::new(oprf::Mode::Oprf);
+        let blinded = client.blind_with_randomness(&inputs[0], blinds[0]).unwrap();
+        assert_eq!(blinded.to_sec1(true), hex("03723a1e5c09b8b9c18d1dcbca29e8007e95f14f4732d9346d490ffc195110368d"));
+        let evaluated = server.blind_evaluate(&blinded);
+        assert_eq!(evaluated.to_sec1(true), hex("030de02ffec47a1fd53efcdd1c6faf5bdc270912b8749e783c7ca75bb412958832"));
+        let output = client.finalize(&inputs[0], blinds[0], &evaluated);
+        assert_eq!(output, hex("a0b34de5fa4c5b6da07e72af73cc507cceeb48981b97b7285fc375345fe495dd"));
+        assert_eq!(server.evaluate(&inputs[0]), Ok(output));
+        //the output does not depend on the blind
+        let (blind, blinded) = client.blind(&inputs[1]).unwrap();
+        assert_eq!(Ok(client.finalize(&inputs[1], blind, &server.blind_evaluate(&blinded))), server.evaluate(&inputs[1]));
+        //RFC 9497 A.3.2, VOPRF(P-256, SHA-256) test vector 1, the proof randomness is the second blind
+        let server = oprf::Server::<oprf::P256Sha256>::derive_key_pair(oprf::Mode::Voprf, &seed, info).unwrap();
+        assert_eq!(server.secret_key().to_be_bytes(), hex("ca5d94c8807817669a51b196c34c1b7f8442fde4334a7121ae4736364312fca6"));
+        assert_eq!(server.public_key().to_sec1(true), hex("03e17e70604bcabe198882c0a1f27a92441e774224ed9c702e51dd17038b102462"));
+        let client = oprf::Client::<oprf::P256Sha256>::new(oprf::Mode::Voprf);
+        let blinded = client.blind_with_randomness(&inputs[0], blinds[0]).unwrap();
+        assert_eq!(blinded.to_sec1(true), hex("02dd05901038bb31a6fae01828fd8d0e49e35a486b5c5d4b4994013648c01277da"));
+        let (evaluated, proof) = server.blind_evaluate_batch_with_randomness(&[blinded], blinds[1]);
+        assert_eq!(evaluated[0].to_sec1(true), hex("0209f33cab60cf8fe69239b0afbcfcd261af4c1c5632624f2e9ba29b90ae83e4a2"));
+        assert_eq!(proof.to_bytes(), hex("e7c2b3c5c954c035949f1f74e6bce2ed539a3be267d1481e9ddb178533df4c2664f69d065c604a4fd953e100b856ad83804eb3845189babfa5a702090d6fc5fa"));
+        assert_eq!(oprf::Proof::from_bytes(&proof.to_bytes()), Some(proof));
+        let outputs = client.finalize_batch(&server.public_key(), &[inputs[0].as_slice()], &blinds[..1], &[blinded], &evaluated, &proof).unwrap();
+        assert_eq!(outputs, vec![hex("0412e8f78b02c415ab3a288e228978376f99927767ff37c5718d420010a645a1")]);
+        //a batch gives the same outputs as evaluating each input
+        let blinded = [0, 1].map(|i| client.blind_with_randomness(&inputs[i], blinds[i]).unwrap());
+        let (evaluated, proof) = server.blind_evaluate_batch(&blinded);
+        let inputs = [inputs[0].as_slice(), &inputs[1]];
+        let outputs = client.finalize_batch(&server.public_key(), &inputs, &blinds, &blinded, &evaluated, &proof).unwrap();
+        assert_eq!(outputs, inputs.map(|input| server.evaluate(input).unwrap()));
+        //a server evaluating with another key is caught
+        let other = oprf::Server::<oprf::P256Sha256>::new(oprf::Mode::Voprf, ScalarP256::generate_secret());
+        let (evaluated, proof) = other.blind_evaluate_batch(&blinded);
+        assert_eq!(client.finalize_batch(&server.public_key(), &inputs, &blinds, &blinded, &evaluated, &proof), Err(oprf::Error::Verify));
+        assert!(client.finalize_batch(&other.public_key(), &inputs, &blinds, &blinded, &evaluated, &proof).is_ok());
+        assert_eq!(client.finalize_batch(&other.public_key(), &inputs, &blinds, &blinded, &[evaluated[1], evaluated[0]], &proof), Err(oprf::Error::Verify));
+    }
+    #[test]
+    fn test_blind_schnorr() {
+        let signer = blind_schnorr::Signer::new(Scalar32::generate_secret()).unwrap();
+        let public = signer.public_key();
+        let (session, commitment) = signer.commit();
+        //Debug does not print the key or the nonce
+        assert_eq!(format!("{signer:?} {session:?}"), format!("Signer [curve {0}] SignerSession [curve {0}]", PrimeOrderCurve32::NAME));
+        let (user, challenge) = blind_schnorr::UserSession::blind(&public, &commitment, b"coin").unwrap();
+        let response = session.respond(challenge);
+        let signature = user.unblind(response).unwrap();
+        assert!(signature.verify(&public, b"coin"));
+        assert!(!signature.verify(&public, b"other coin"));
+        assert!(!signature.verify(&(public + PrimeOrderCurve32::generator()), b"coin"));
+        //the signer's view of the session does not show up in the signature
+        assert_ne!(signature.r, commitment);
+        assert_ne!(signature.s, response);
+        assert_eq!(blind_schnorr::Signature::from_bytes(&signature.to_bytes()), Some(signature));
+        //a wrong response is caught when unblinding
+        let (session, commitment) = signer.commit();
+        let (user, challenge) = blind_schnorr::UserSession::blind(&public, &commitment, b"coin").unwrap();
+        assert_eq!(user.unblind(session.respond(challenge + Scalar32::one())), None);
+        assert_eq!(blind_schnorr::UserSession::blind(&public, &ECpoint32::Infinity, b"coin"), None);
+        assert_eq!(blind_schnorr::Signer::new(Scalar32::zero()), None);
+        //secp256k1
+        let signer = blind_schnorr::Signer::new(ScalarSecp256k1::generate_secret()).unwrap();
+        let (session, commitment) = signer.commit();
+        let (user, challenge) = blind_schnorr::UserSession::blind(&signer.public_key(), &commitment, b"coin").unwrap();
+        let signature = user.unblind(session.respond(challenge)).unwrap();
+        assert!(signature.verify(&signer.public_key(), b"coin"));
+    }
+    #[test]
+    fn test_bip32() {
+        //RIPEMD-160 paper, the padding edge cases were checked against Python's hashlib
+        assert_eq!(ripemd160(b"").to_vec(), hex("9c1185a5c5e9fc54612808977ee8f548b2258d31"));
+        assert_eq!(ripemd160(b"abc").to_vec(), hex("8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"));
+        assert_eq!(ripemd160(b"message digest").to_vec(), hex("5d0689ef49d2fae572b881b123a85ffa21595f36"));
+        assert_eq!(ripemd160(&b"1234567890".repeat(8)).to_vec(), hex("9b752e45573d4b39f4dbd3323cab82bf63326bfb"));
+        assert_eq!(ripemd160(&[b'a'; 55]).to_vec(), hex("0d8a8c9063a48576a7c97e9f95253a6e53ff6765"));
+        assert_eq!(ripemd160(&[b'a'; 56]).to_vec(), hex("e72334b46c83cc70bef979e15453706c95b888be"));
+        assert_eq!(ripemd160(&[b'a'; 64]).to_vec(), hex("9dfb7d374ad924f3f88de96291c33e9abed53e32"));
+        assert_eq!(base58_encode(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+        assert_eq!(base58_encode(&hex("0000287fb4cd")), "11233QC4");
+        assert_eq!(base58check_decode(&base58check_encode(&hex("00010203"))), Some(hex("00010203")));
+        //BIP32 test vector 1
+        let master = bip32::ExtendedPrivateKey::new_master(&hex("000102030405060708090a0b0c0d0e0f")).unwrap();
+        let expected = [
+            ("m", "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8", "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"),
+            ("m/0H", "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw", "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7"),
+            ("m/0H/1", "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ", "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs"),
+            ("m/0H/1/2H", "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5", "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM"),
+            ("m/0H/1/2H/2", "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV", "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334"),
+            ("m/0H/1/2H/2/1000000000", "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy", "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76"),
+        ];
+        for (path, xpub, xprv) in expected {
+            let key = master.derive_path(&path.parse().unwrap()).unwrap();
+            assert_eq!(key.to_string(), xprv);
+            assert_eq!(key.to_public().to_string(), xpub);
+            assert_eq!(xprv.parse(), Ok(key));
+            assert_eq!(xpub.parse(), Ok(key.to_public()));
+        }
+        //normal children from the public key alone match, hardened ones are refused
+        let account = master.derive_path(&"m/0'/1/2'".parse().unwrap()).unwrap();
+        let path: bip32::DerivationPath = "m/2/1000000000".parse().unwrap();
+        assert_eq!(account.to_public().derive_path(&path), Ok(account.derive_path(&path).unwrap().to_public()));
+        assert_eq!(account.to_public().derive_child(bip32::HARDENED), Err(bip32::Error::HardenedFromPublic));
+        assert_eq!(account.derive_child(0).unwrap().parent_fingerprint, account.fingerprint());
+        assert_eq!(master.fingerprint().to_vec(), hex("3442193e"));
+        //BIP32 test vector 2, m/0 and m/0/2147483647H
+        let master = bip32::ExtendedPrivateKey::new_master(&hex("fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542")).unwrap();
+        assert_eq!(master.to_public().derive_child(0).unwrap().to_string(), "xpub69H7F5d8KSRgmmdJg2KhpAK8SR3DjMwAdkxj3ZuxV27CprR9LgpeyGmXUbC6wb7ERfvrnKZjXoUmmDznezpbZb7ap6r1D3tgFxHmwMkQTPH");
+        assert_eq!(master.derive_path(&"m/0/2147483647h".parse().unwrap()).unwrap().to_string(), "xprv9wSp6B7kry3Vj9m1zSnLvN3xH8RdsPP1Mh7fAaR7aRLcQMKTR2vidYEeEg2mUCTAwCd6vnxVrcjfy2kRgVsFawNzmjuHc2YmYRmagcEPdU9");
+        //paths and malformed keys
+        let path: bip32::DerivationPath = "m/84'/0'/0'/0/1".parse().unwrap();
+        assert_eq!(path.0, vec![84 | bip32::HARDENED, bip32::HARDENED, bip32::HARDENED, 0, 1]);
+        assert_eq!(path.to_string(), "m/84'/0'/0'/0/1");
+        for path in ["", "84'/0", "m/", "m/x", "m/+1", "m/2147483648", "m/1''"] {
+            assert_eq!(path.parse::<bip32::DerivationPath>(), Err(bip32::Error::InvalidPath));
+        }
+        assert_eq!(bip32::ExtendedPrivateKey::new_master(&[0; 15]), Err(bip32::Error::InvalidSeedLength));
+        let xpub = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet9";
+        assert_eq!(xpub.parse::<bip32::ExtendedPublicKey>(), Err(bip32::Error::InvalidEncoding));
+        let data = base58check_decode(expected[0].1).unwrap();
+        let with_parent = base58check_encode(&[&data[..5], &[1, 2, 3, 4], &data[9..]].concat());
+        assert_eq!(with_parent.parse::<bip32::ExtendedPublicKey>(), Err(bip32::Error::InvalidEncoding));
+        let uncompressed_prefix = base58check_encode(&[&data[..45], &[4], &data[46..]].concat());
+        assert_eq!(uncompressed_prefix.parse::<bip32::ExtendedPublicKey>(), Err(bip32::Error::InvalidEncoding));
+        assert_eq!(expected[0].1.parse::<bip32::ExtendedPrivateKey>(), Err(bip32::Error::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_bitcoin() {
+        use bitcoin::{Address, Network, Payload, PrivateKey, SilentPaymentAddress};
+        //WIF of the key from the Bitcoin wiki, uncompressed and compressed
+        let secret = ScalarSecp256k1::from_be_bytes(&hex("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d")).unwrap();
+        let key = PrivateKey { secret, network: Network::Mainnet, compressed: false };
+        assert_eq!(key.to_string(), "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ");
+        assert_eq!(PrivateKey::new(secret, Network::Mainnet).to_string(), "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617");
+        assert_eq!("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ".parse(), Ok(key));
+        let testnet = PrivateKey::new(ScalarSecp256k1::generate_secret(), Network::Testnet);
+        assert_eq!(testnet.to_string().parse(), Ok(testnet));
+        assert_eq!("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK".parse::<PrivateKey>(), Err(bitcoin::Error::InvalidBase58Check));
+        assert_eq!(base58check_encode(&[0x80; 32]).parse::<PrivateKey>(), Err(bitcoin::Error::InvalidLength));
+        assert_eq!(base58check_encode(&[&[0x80], [0xff; 32].as_slice()].concat()).parse::<PrivateKey>(), Err(bitcoin::Error::InvalidKey));
+        //addresses of the generator, the public key of 1
+        let g = Secp256k1::generator();
+        assert_eq!(PrivateKey::new(ScalarSecp256k1::one(), Network::Mainnet).public_key_bytes(), hex("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"));
+        let expected = [
+            (Address::p2pkh(&g, true, Network::Mainnet), "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"),
+            (Address::p2pkh(&g, false, Network::Mainnet), "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm"),
+            (Address::p2wpkh(&g, Network::Mainnet), "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"),
+            (Address::p2wpkh(&g, Network::Testnet), "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"),
+        ];
+        for (address, encoded) in expected {
+            assert_eq!(address.to_string(), encoded);
+            assert_eq!(encoded.parse(), Ok(address));
+        }
+        assert_eq!(Address::p2wpkh(&g, Network::Mainnet).script_pubkey(), hex("0014751e76e8199196d454941c45d1b3a323f1433bd6"));
+        assert_eq!("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4".parse(), Ok(Address::p2wpkh(&g, Network::Mainnet)));
+        //BIP86 first receiving address, a key path only P2TR output
+        let internal_key = ECpointSecp256k1::lift_x(U256::from_big_endian(&hex("cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115"))).unwrap();
+        let address = Address::p2tr(&internal_key, None, Network::Mainnet).unwrap();
+        assert_eq!(address.payload, Payload::P2tr(hex("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c").try_into().unwrap()));
+        assert_eq!(address.to_string(), "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr");
+        assert_eq!("bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr".parse(), Ok(address));
+        //the y parity of the internal key does not matter
+        assert_eq!(Address::p2tr(&-internal_key, None, Network::Mainnet), Some(address));
+        //BIP350 invalid addresses, a checksum of the wrong variant for the version
+        assert_eq!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh".parse::<Address>(), Err(bitcoin::Error::InvalidBech32));
+        assert_eq!("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd".parse::<Address>(), Err(bitcoin::Error::InvalidBech32));
+        assert_eq!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5".parse::<Address>(), Err(bitcoin::Error::InvalidBech32));
+        assert_eq!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7KV8F3T4".parse::<Address>(), Err(bitcoin::Error::InvalidBech32));
+        assert_eq!("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ".parse::<Address>(), Err(bitcoin::Error::InvalidBase58Check));
+        //BIP173 and BIP350 invalid human readable parts, and bc1q that only starts like bc
+        for encoded in ["tc1qw508d6qejxtdg4y5r3zarvary0c5xw7kg3g4ty", "tc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq5zuyut", "bc1q1qwwwwwwwwwwwwwwwwwwwwwwwwwwwwwwwwlevc7t"] {
+            assert_eq!(encoded.parse::<Address>(), Err(bitcoin::Error::InvalidVersion));
+        }
+        for encoded in [
+            "\x201nwldj5", "\x7f1axkwrx", "\u{80}1eym55h", "pzry9x0s0muk", "1pzry9x0s0muk", "10a06t8", "1qzzfhee", "A1G7SGD8",
+            "\x201xj0phk", "\x7f1g6xzxy", "\u{80}1vctc34", "qyrz8wqd2c9m", "1qyrz8wqd2c9m", "16plkw9", "1p2gdwpf", "M1VUXWEZ",
+        ] {
+            assert_eq!(bitcoin::bech32_decode(encoded, 90), Err(bitcoin::Error::InvalidBech32));
+        }
+        //P2TR program that is not an x coordinate on the curve, x = 5 has no point
+        let mut x = [0; 32];
+        x[31] = 5;
+        let off_curve = Address { network: Network::Mainnet, payload: Payload::P2tr(x) };
+        assert_eq!(off_curve.to_string().parse::<Address>(), Err(bitcoin::Error::InvalidKey));
+        //BIP352 test vectors, the address of the receiver without labels
+        let key = |secret: &str| g * ScalarSecp256k1::from_be_bytes(&hex(secret)).unwrap();
+        let sp = SilentPaymentAddress {
+            network: Network::Mainnet,
+            scan_key: key("0f694e068028a717f8af6b9411f9a133dd3565258714cc226594b34db90c1f2c"),
+            spend_key: key("9d6ad855ce3417ef84e836892e5a56392bfba05fa5d97ccea30e266f540e08b3"),
+        };
+        let encoded = "sp1qqgste7k9hx0qftg6qmwlkqtwuy6cycyavzmzj85c6qdfhjdpdjtdgqjuexzk6murw56suy3e0rd2cgqvycxttddwsvgxe2usfpxumr70xc9pkqwv";
+        assert_eq!(sp.to_string(), encoded);
+        assert_eq!(encoded.parse(), Ok(sp));
+        let testnet = SilentPaymentAddress { network: Network::Testnet, ..sp };
+        assert!(testnet.to_string().starts_with("tsp1q"));
+        assert_eq!(testnet.to_string().parse(), Ok(testnet));
+        assert_eq!(encoded.replace("kqwv", "kqww").parse::<SilentPaymentAddress>(), Err(bitcoin::Error::InvalidBech32));
+    }
+
+    #[test]
+    fn test_taproot() {
+        use taproot::{ControlBlock, TapLeaf, TapTree};
+        use bitcoin::{Address, Network};
+        let lift = |x: &str| ECpointSecp256k1::lift_x(U256::from_big_endian(&hex(x))).unwrap();
+        let x_only = |p: &ECpointSecp256k1| -> [u8; 32] { p.to_sec1(true)[1..].try_into().unwrap() };
+        //BIP341 wallet vectors, key path only and a single tapscript leaf
+        let internal_key = lift("d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d");
+        assert_eq!(taproot::tap_tweak(&x_only(&internal_key), None).unwrap().to_be_bytes(), hex("b86e7be8f39bab32a6f2c0443abbc210f0edac0e2c53d501b36b64437d9c6c70"));
+        let output_key = taproot::tweak_public_key(&internal_key, None).unwrap();
+        assert_eq!(x_only(&output_key).to_vec(), hex("53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343"));
+        assert_eq!(Address::p2tr(&internal_key, None, Network::Mainnet).unwrap().to_string(), "bc1p2wsldez5mud2yam29q22wgfh9439spgduvct83k3pm50fcxa5dps59h4z5");
+        let internal_key = lift("187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27");
+        let leaf = TapLeaf::new(hex("20d85a959b0290bf19bb89ed43c916be835475d013da4b362117393e25a48229b8ac"));
+        let tree = TapTree::Leaf(leaf.clone());
+        assert_eq!(tree.merkle_root().to_vec(), hex("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21"));
+        let output_key = taproot::tweak_public_key(&internal_key, Some(&tree.merkle_root())).unwrap();
+        assert_eq!(x_only(&output_key).to_vec(), hex("147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3"));
+        assert_eq!(Address::p2tr(&internal_key, Some(&tree.merkle_root()), Network::Mainnet).unwrap().to_string(), "bc1pz37fc4cn9ah8anwm4xqqhvxygjf9rjf2resrw8h8w4tmvcs0863sa2e586");
+        let control_block = ControlBlock::new(&internal_key, &tree, &leaf).unwrap();
+        assert_eq!(control_block.to_bytes(), hex("c1187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27"));
+        assert!(control_block.verify(&x_only(&output_key), &leaf));
+        //BIP341 wallet vectors, a leaf with another internal key and two leaves of different versions
+        let internal_key = lift("93478e9488f956df2396be2ce6c5cced75f900dfa18e7dabd2428aae78451820");
+        let leaf = TapLeaf::new(hex("20b617298552a72ade070667e86ca63b8f5789a9fe8731ef91202a91c9f3459007ac"));
+        let tree = TapTree::Leaf(leaf.clone());
+        assert_eq!(tree.merkle_root().to_vec(), hex("c525714a7f49c28aedbbba78c005931a81c234b2f6c99a73e4d06082adc8bf2b"));
+        let output_key = taproot::tweak_public_key(&internal_key, Some(&tree.merkle_root())).unwrap();
+        assert_eq!(x_only(&output_key).to_vec(), hex("e4d810fd50586274face62b8a807eb9719cef49c04177cc6b76a9a4251d5450e"));
+        assert_eq!(Address::p2tr(&internal_key, Some(&tree.merkle_root()), Network::Mainnet).unwrap().to_string(), "bc1punvppl2stp38f7kwv2u2spltjuvuaayuqsthe34hd2dyy5w4g58qqfuag5");
+        let internal_key = lift("ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592");
+        let (a, b) = (
+            TapLeaf::new(hex("20387671353e273264c495656e27e39ba899ea8fee3bb69fb2a680e22093447d48ac")),
+            TapLeaf { version: 0xfa, script: hex("06424950333431") },
+        );
+        assert_eq!(a.hash().to_vec(), hex("8ad69ec7cf41c2a4001fd1f738bf1e505ce2277acdcaa63fe4765192497f47a7"));
+        assert_eq!(b.hash().to_vec(), hex("f224a923cd0021ab202ab139cc56802ddb92dcfc172b9212261a539df79a112a"));
+        let tree = TapTree::branch(TapTree::Leaf(a.clone()), TapTree::Leaf(b.clone()));
+        assert_eq!(tree.merkle_root().to_vec(), hex("6c2dc106ab816b73f9d07e3cd1ef2c8c1256f519748e0813e4edd2405d277bef"));
+        let output_key = x_only(&taproot::tweak_public_key(&internal_key, Some(&tree.merkle_root())).unwrap());
+        assert_eq!(output_key.to_vec(), hex("712447206d7a5238acc7ff53fbe94a3b64539ad291c7cdbc490b7577e4b17df5"));
+        assert_eq!(Address::p2tr(&internal_key, Some(&tree.merkle_root()), Network::Mainnet).unwrap().to_string(), "bc1pwyjywgrd0ffr3tx8laflh6228dj98xkjj8rum0zfpd6h0e930h6saqxrrm");
+        let control_block = ControlBlock::new(&internal_key, &tree, &b).unwrap();
+        assert_eq!(control_block.to_bytes(), hex("faee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf37865928ad69ec7cf41c2a4001fd1f738bf1e505ce2277acdcaa63fe4765192497f47a7"));
+        assert_eq!(ControlBlock::new(&internal_key, &tree, &a).unwrap().to_bytes(), hex("c0ee4fe085983462a184015d1f782d6a5f8b9c2b60130aff050ce221ecf3786592f224a923cd0021ab202ab139cc56802ddb92dcfc172b9212261a539df79a112a"));
+        assert_eq!(ControlBlock::from_bytes(&control_block.to_bytes()), Some(control_block.clone()));
+        for leaf in [&a, &b] {
+            assert!(ControlBlock::new(&internal_key, &tree, leaf).unwrap().verify(&output_key, leaf));
+        }
+        assert!(!control_block.verify(&output_key, &a));
+        assert!(!ControlBlock { output_key_parity: true, ..control_block.clone() }.verify(&output_key, &b));
+        assert!(!ControlBlock { leaf_version: 0xc0, ..control_block.clone() }.verify(&output_key, &b));
+        assert_eq!(ControlBlock::new(&internal_key, &tree, &TapLeaf::new(vec![0x52])), None);
+        assert_eq!(ControlBlock::from_bytes(&control_block.to_bytes()[..64]), None);
+        //the tweaked secret key signs for the output key whatever the parity of the internal key
+        for secret in [ScalarSecp256k1::generate_secret(), ScalarSecp256k1::generate_secret()] {
+            for merkle_root in [None, Some(tree.merkle_root())] {
+                let tweaked = taproot::tweak_secret_key(secret, merkle_root.as_ref()).unwrap();
+                let output_key = taproot::tweak_public_key(&(Secp256k1::generator() * secret), merkle_root.as_ref()).unwrap();
+                assert_eq!(Secp256k1::generator() * tweaked, output_key);
+                let signature = schnorr::sign(tweaked, b"spend", &[0; 32]).unwrap();
+                assert!(schnorr::verify(&x_only(&output_key), b"spend", &signature));
+            }
+        }
+        assert_eq!(taproot::tweak_secret_key(ScalarSecp256k1::zero(), None), None);
+    }
+
+    #[test]
+    fn test_der() {
+        //RFC 4648 base64 vectors
+        for (data, encoded) in [("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"), ("foob", "Zm9vYg=="), ("foobar", "Zm9vYmFy")] {
+            assert_eq!(base64_encode(data.as_bytes()), encoded);
+            assert_eq!(base64_decode(encoded), Some(data.as_bytes().to_vec()));
+        }
+        for invalid in ["Zg=", "Zh==", "Z===", "Zg==Zm8=", "Zm9v!A=="] {
+            assert_eq!(base64_decode(invalid), None);
+        }
+        //keys generated by OpenSSL, they decode to the secret OpenSSL printed and encode
+        //back to the same bytes
+        fn check<E: der::NamedCurve>(files: [&str; 4], secret: &str, signature: &[u8]) {
+            let secret = crate::types::Scalar::<E>::from_be_bytes(&hex(secret)).unwrap();
+            let public = E::generator() * secret;
+            let sec1 = der::pem_decode(files[0], der::SEC1_LABEL).unwrap();
+            assert_eq!(der::sec1_from_der::<E>(&sec1), Ok(secret));
+            assert_eq!(der::sec1_to_der(&secret), sec1);
+            assert_eq!(der::pem_encode(der::SEC1_LABEL, &sec1), files[0]);
+            let pkcs8 = der::pem_decode(files[1], der::PKCS8_LABEL).unwrap();
+            assert_eq!(der::pkcs8_from_der::<E>(&pkcs8), Ok(secret));
+            assert_eq!(der::pkcs8_to_der(&secret), pkcs8);
+            let spki = der::pem_decode(files[2], der::SPKI_LABEL).unwrap();
+            assert_eq!(der::spki_from_der::<E>(&spki), Ok(public));
+            assert_eq!(der::spki_to_der(&public, false), Some(spki));
+            let compressed = der::pem_decode(files[3], der::SPKI_LABEL).unwrap();
+            assert_eq!(der::spki_from_der::<E>(&compressed), Ok(public));
+            assert_eq!(der::spki_to_der(&public, true), Some(compressed));
+            //openssl dgst -sha256 -sign
+            let signature = ecdsa::Signature::<E>::from_der(signature).unwrap();
+            assert!(ecdsa::verify(&public, b"signed by OpenSSL", &signature));
+            assert!(ecdsa::Signature::from_der(&signature.to_der()) == Some(signature));
+            //a PEM of the wrong kind is refused
+            assert_eq!(der::pem_decode(files[0], der::PKCS8_LABEL), Err(der::Error::InvalidPem));
+        }
+        let secp256k1 = [
+            include_str!("../testdata/secp256k1_sec1.pem"),
+            include_str!("../testdata/secp256k1_pkcs8.pem"),
+            include_str!("../testdata/secp256k1_spki.pem"),
+            include_str!("../testdata/secp256k1_spki_compressed.pem"),
+        ];
+        check::<Secp256k1>(secp256k1, "8173acbfb84eda20f7435bc78f9a6ebeb751a9c26172ba70a228cfd06986fc66", include_bytes!("../testdata/secp256k1_signature.der"));
+        let p256 = [
+            include_str!("../testdata/p256_sec1.pem"),
+            include_str!("../testdata/p256_pkcs8.pem"),
+            include_str!("../testdata/p256_spki.pem"),
+            include_str!("../testdata/p256_spki_compressed.pem"),
+        ];
+        check::<P256>(p256, "2ba09b29f769317d1d8a96e50166fea8faff7e0475eb801091baf1bd880f36eb", include_bytes!("../testdata/p256_signature.der"));
+        //a key of one curve read as the other
+        let sec1 = der::pem_decode(p256[0], der::SEC1_LABEL).unwrap();
+        assert_eq!(der::sec1_from_der::<Secp256k1>(&sec1), Err(der::Error::CurveMismatch));
+        let spki = der::pem_decode(secp256k1[2], der::SPKI_LABEL).unwrap();
+        assert_eq!(der::spki_from_der::<P256>(&spki), Err(der::Error::CurveMismatch));
+        //tampered encodings: trailing data, a long form length that fits the short form
+        //and points off the curve
+        assert_eq!(der::spki_from_der::<Secp256k1>(&[spki.clone(), vec![0]].concat()), Err(der::Error::InvalidDer));
+        assert_eq!(der::spki_from_der::<Secp256k1>(&[&[0x30, 0x81], &spki[1..]].concat()), Err(der::Error::InvalidDer));
+        let mut off_curve = spki.clone();
+        *off_curve.last_mut().unwrap() ^= 1;
+        assert_eq!(der::spki_from_der::<Secp256k1>(&off_curve), Err(der::Error::InvalidKey));
+        let mut wrong_public = sec1.clone();
+        *wrong_public.last_mut().unwrap() ^= 1;
+        assert_eq!(der::sec1_from_der::<P256>(&wrong_public), Err(der::Error::InvalidKey));
+        assert_eq!(der::spki_to_der(&crate::types::ECpoint::<P256>::Infinity, true), None);
+        //a secret without its leading zero byte, as some encoders write it
+        let secret = ScalarSecp256k1::new(U256::from(0xabcdefu64));
+        let minimal = [&[0x30, 0x08, 0x02, 0x01, 0x01, 0x04, 0x03], hex("abcdef").as_slice()].concat();
+        assert_eq!(der::sec1_from_der::<Secp256k1>(&minimal), Ok(secret));
+        assert_eq!(der::sec1_from_der::<Secp256k1>(&der::sec1_to_der(&secret)), Ok(secret));
+        //signatures with a negative or non minimal integer
+        let signature = ecdsa::Signature::<Secp256k1> { r: ScalarSecp256k1::new(0x80u64), s: ScalarSecp256k1::new(1u64) };
+        assert_eq!(signature.to_der(), hex("300702020080020101"));
+        assert_eq!(ecdsa::Signature::<Secp256k1>::from_der(&hex("3006020180020101")), None);
+        assert_eq!(ecdsa::Signature::<Secp256k1>::from_der(&hex("300702020001020101")), None);
+    }
+
+    //thousands of signatures each way, best run as cargo test --release --features ring-interop
+    #[cfg(feature = "ring-interop")]
+    #[test]
+    fn test_ring_interop() {
+        use ring::{rand::{SecureRandom, SystemRandom}, signature::{KeyPair, UnparsedPublicKey, ECDSA_P256_SHA256_FIXED}};
+        use crate::ring_interop;
+        type ScalarP256 = crate::types::Scalar<P256>;
+        let rng = SystemRandom::new();
+        let mut secret = ScalarP256::generate_secret();
+        let mut key_pair = ring_interop::to_ring_key_pair(&secret).unwrap();
+        for i in 0..2000 {
+            if i % 100 == 0 {
+                secret = ScalarP256::generate_secret();
+                key_pair = ring_interop::to_ring_key_pair(&secret).unwrap();
+                assert_eq!(ring_interop::from_ring_public_key(&key_pair), Some(P256::generator() * secret));
+            }
+            let public = P256::generator() * secret;
+            let mut msg = vec![0; i % 97];
+            rng.fill(&mut msg).unwrap();
+            //signed here, verified by ring in both encodings
+            let signature = match i % 2 {
+                0 => ecdsa::sign(secret, &msg),
+                _ => ecdsa::sign_deterministic(secret, &msg)
+            };
+            assert!(ring_interop::verify_with_ring(&public, &msg, &signature));
+            assert!(ring_interop::verify_der_with_ring(&public, &msg, &signature));
+            assert!(!ring_interop::verify_with_ring(&public, &[msg.as_slice(), b"!"].concat(), &signature));
+            //signed by ring, verified here
+            let ring_signature = ring_interop::from_ring_signature(&key_pair.sign(&rng, &msg).unwrap()).unwrap();
+            assert!(ecdsa::verify(&public, &msg, &ring_signature));
+            assert!(!ecdsa::verify(&public, &[msg.as_slice(), b"!"].concat(), &ring_signature));
+        }
+        //the public key ring derives is the one ring verifies with
+        let ring_public = UnparsedPublicKey::new(&ECDSA_P256_SHA256_FIXED, key_pair.public_key().as_ref());
+        assert!(ring_public.verify(b"msg", &ecdsa::sign(secret, b"msg").to_bytes()).is_ok());
+        assert!(ring_interop::to_ring_public_key(&crate::types::ECpoint::<P256>::Infinity).is_none());
+    }
+
+    ///Runs the tests of a Wycheproof file, valid ones have to be accepted, invalid ones
+    ///rejected and acceptable ones may go either way. Counts of passed and failed tests
+    ///per flag.
+    fn run_wycheproof(json: &str, accepts: impl Fn(&serde_json::Value, &serde_json::Value) -> bool) -> std::collections::BTreeMap<String, (usize, usize)> {
+        let file: serde_json::Value = serde_json::from_str(json).unwrap();
+        let mut report = std::collections::BTreeMap::new();
+        for group in file["testGroups"].as_array().unwrap() {
+            for test in group["tests"].as_array().unwrap() {
+                let accepted = accepts(group, test);
+                let passed = match test["result"].as_str().unwrap() {
+                    "valid" => accepted,
+                    "invalid" => !accepted,
+                    _ => true
+                };
+                if !passed {
+                    println!("tcId {} failed: {}", test["tcId"], test["comment"]);
+                }
+                let flags = test["flags"].as_array().unwrap().iter().map(|f| f.as_str().unwrap().to_string());
+                for flag in flags.chain(std::iter::once("all".to_string())) {
+                    let (ok, failed) = report.entry(flag).or_insert((0, 0));
+                    *if passed { ok } else { failed } += 1;
+                }
+            }
+        }
+        report
+    }
+
+    ///The key is decoded from its SubjectPublicKeyInfo, which has to match the
+    ///uncompressed point, then the DER signature of the message is verified
+    fn wycheproof_ecdsa<E: der::NamedCurve>(group: &serde_json::Value, test: &serde_json::Value) -> bool {
+        let key = &group["publicKey"];
+        let Ok(public) = der::spki_from_der::<E>(&hex(group["publicKeyDer"].as_str().unwrap())) else {
+            return false
+        };
+        assert_eq!(crate::types::ECpoint::from_sec1(&hex(key["uncompressed"].as_str().unwrap())), Some(public));
+        ecdsa::verify_der(&public, &hex(test["msg"].as_str().unwrap()), &hex(test["sig"].as_str().unwrap()))
+    }
+
+    type WycheproofCheck = fn(&serde_json::Value, &serde_json::Value) -> bool;
+    ///Prints the report of a file by flag, the number of failed tests
+    fn wycheproof_failures(name: &str, json: &str, accepts: WycheproofCheck) -> usize {
+        let mut failures = 0;
+        for (flag, (passed, failed)) in run_wycheproof(json, accepts) {
+            println!("{name} {flag}: {passed} passed, {failed} failed");
+            failures += failed;
+        }
+        failures
+    }
+    ///The private key is an ASN.1 integer that may carry a sign byte, the public key is a
+    ///SubjectPublicKeyInfo or a bare SEC1 point
+    fn wycheproof_ecdh<E: der::NamedCurve>(group: &serde_json::Value, test: &serde_json::Value) -> bool {
+        let public = hex(test["public"].as_str().unwrap());
+        let public = match group["encoding"].as_str().unwrap() {
+            "asn" => ecdh::PublicKey::<E>::from_spki_der(&public),
+            _ => ecdh::PublicKey::<E>::from_sec1(&public)
+        };
+        let private = hex(test["private"].as_str().unwrap());
+        let private = &private[private.iter().take_while(|&&b| b == 0).count()..];
+        let len = E::N.bits().div_ceil(8);
+        if private.len() > len {
+            return false
+        }
+        let secret = ecdh::StaticSecret::<E>::from_be_bytes(&[vec![0; len - private.len()], private.to_vec()].concat());
+        match (public, secret) {
+            (Ok(public), Ok(secret)) => secret.diffie_hellman(&public)
+                .is_ok_and(|shared| shared.raw_secret_bytes() == hex(test["shared"].as_str().unwrap())),
+            _ => false
+        }
+    }
+
+    #[test]
+    fn test_wycheproof() {
+        //testvectors_v1 files of Project Wycheproof, copied unchanged
+        let files: [(&str, &str, WycheproofCheck); 2] = [
+            ("ecdsa_secp256k1_sha256_test", include_str!("../testdata/wycheproof/ecdsa_secp256k1_sha256_test.json"), wycheproof_ecdsa::<Secp256k1>),
+            ("ecdsa_secp256r1_sha256_test", include_str!("../testdata/wycheproof/ecdsa_secp256r1_sha256_test.json"), wycheproof_ecdsa::<P256>),
+        ];
+        assert_eq!(files.into_iter().map(|(name, json, accepts)| wycheproof_failures(name, json, accepts)).sum::<usize>(), 0);
+    }
+    #[test]
+    #[ignore = "needs the upstream Wycheproof ecdh_*_test.json files in testdata/wycheproof"]
+    fn test_wycheproof_ecdh() {
+        let files: [(&str, WycheproofCheck); 3] = [
+            ("ecdh_secp256k1_test", wycheproof_ecdh::<Secp256k1>),
+            ("ecdh_secp256r1_test", wycheproof_ecdh::<P256>),
+            ("ecdh_secp256r1_ecpoint_test", wycheproof_ecdh::<P256>),
+        ];
+        let failures = files.into_iter().map(|(name, accepts)| {
+            let json = std::fs::read_to_string(format!("{}/testdata/wycheproof/{name}.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+            wycheproof_failures(name, &json, accepts)
+        });
+        assert_eq!(failures.sum::<usize>(), 0);
+    }
 }
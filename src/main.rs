@@ -1,5 +1,5 @@
 use std::{iter::once, vec};
-use ecc_generic::types::{ECpoint, Zp, EC, U256};
+use ecc_generic::types::{ECpoint, Zp, Generator, EC, U256};
 use rand::{thread_rng, Rng};
 use ring::digest::{self, digest};
 use colored::Colorize;
@@ -24,8 +24,22 @@ impl EC for Secp256k1 {
         18446744073709551615,
     ]);
 }
+impl Generator for Secp256k1 {
+    const GX: U256 = U256([
+        6481385041966929816,
+        188021827762530521,
+        6170039885052185351,
+        8772561819708210092,
+    ]);
+    const GY: U256 = U256([
+        11261198710074299576,
+        18237243440184513561,
+        6747795201694173352,
+        5204712524664259685,
+    ]);
+}
 impl std::fmt::Display for Secp256k1 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         todo!()
     }
 }
@@ -55,6 +69,10 @@ impl EC for Curve10729p {
     // // const P: U256 = U256([349, 0, 0, 0]);
     const P: U256 = U256([10729, 0, 0, 0]);
 }
+impl Generator for Curve10729p {
+    const GX: U256 = U256([6898, 0, 0, 0]);
+    const GY: U256 = U256([2464, 0, 0, 0]);
+}
 
 impl std::fmt::Debug for Curve10729p {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -90,9 +108,9 @@ impl Curve10729p {
             let y = x.pow(3) + Zp::new(Self::A)*x + Zp::new(Self::B);
             let y = y.sqrt();
             dbg!(y);    
-            if y.is_some() {
+            if let Some((y, _)) = y {
                 println!("found point:");
-                return ECpoint::<Self>::new(x, y.unwrap().0).unwrap();
+                return ECpoint::<Self>::new(x, y).unwrap();
             } else {
                 println!("no square root for y, retrying...");
             }
@@ -104,8 +122,7 @@ impl Curve10729p {
             .unwrap()
             .0
             .into_iter()
-            .map(|v| v.to_be_bytes())
-            .flatten();
+            .flat_map(|v| v.to_be_bytes());
         let G = Self::generator();
         // let g_encoding = &[
         //     vec![0x04],
@@ -120,7 +137,7 @@ impl Curve10729p {
         let mut counter = 0u8;
         let mut hx = Zp::<Self>::zero();
         let mut hy = None;
-        while let None = hy {
+        while hy.is_none() {
             dbg!(counter);
             // let preimage = &[g_encoding, &[counter][..]].concat()[..];
             let preimage = g_encoding.clone().into_iter().chain(once(counter)).collect::<Vec<_>>();
@@ -157,9 +174,9 @@ fn silent_payment_demo() {
     print!("{}", format!("{:?}", Curve10729p).red());
     println!("{}", "Alice sends payment to Bob ...".green());
     let G = Secp256k1::generator();
-    let bob_priv = Some(0).map(random).map(Scalar::new).unwrap();
+    let bob_priv = Scalar::new(random(0));
     let bob_pub_key = G * bob_priv;
-    let alice_priv = Some(0).map(random).map(Scalar::new).unwrap();
+    let alice_priv = Scalar::new(random(0));
     let alice_pub_key = G * alice_priv;
     println!("{}{}", "b = ".red(), format!("{:?}", bob_priv).red());
     println!("{}{}", "B = ".red(), format!("{:?}", bob_pub_key).red());
@@ -189,6 +206,27 @@ fn silent_payment_demo() {
     println!("{}", "Q.E.D".white().on_bright_green().bold());
 }
 
+fn range_proof_demo() {
+    use ecc_generic::{bulletproofs::{BulletproofGens, PedersenGens, RangeProof}, proofs::Transcript};
+    type Scalar = ecc_generic::types::Scalar<Secp256k1>;
+    println!("{}", "-".repeat(30));
+    println!("{}", "range proofs demo:".green());
+    println!("{}", "-".repeat(30));
+    let outputs = [8, 2];
+    let blindings = outputs.map(|_| Scalar::generate_secret());
+    let pc_gens = PedersenGens::<Secp256k1>::default();
+    let bp_gens = BulletproofGens::new(16);
+    println!("{}{}", "Proving the outputs are in ".green(), "[0, 2^8)".red());
+    let (proof, commitments) = RangeProof::prove_multiple(&bp_gens, &pc_gens, &mut Transcript::new(b"demo"), &outputs, &blindings, 8).unwrap();
+    println!("{}", format!("=>	{:?}", commitments).red());
+    println!("{}{}", "Proof size in bytes: ".green(), format!("{}", proof.to_bytes().len()).red());
+    let valid = proof.verify_multiple(&bp_gens, &pc_gens, &mut Transcript::new(b"demo"), &commitments, 8);
+    println!("{}{}", "Valid :=> ".green(), format!("{valid}").white().on_green());
+    let negative = pc_gens.commit(-Scalar::new(outputs[0]), blindings[0]);
+    let valid = proof.verify_multiple(&bp_gens, &pc_gens, &mut Transcript::new(b"demo"), &[negative, commitments[1]], 8);
+    println!("{}{}", "Committing to -v instead :=> ".green(), format!("{valid}").white().on_red());
+}
+
 fn main() {
     // type Zp = ecc_generic::types::Zp<Curve127p>;
     type Scalar = ecc_generic::types::Scalar<Curve10729p>;
@@ -255,7 +293,7 @@ fn main() {
     let blinding_inputs = [0; 4].map(random).map(Scalar::new).map(|v| -v);//.map(scalar);
     let blinding_outputs = [0; 2].map(random).map(Scalar::new);//.map(scalar);
     let excess = [&blinding_inputs[..], &blinding_outputs[..]].concat().into_iter().sum::<Scalar>();
-    let excess_in_scalar: Scalar = excess.into();
+    let excess_in_scalar: Scalar = excess;
     dbg!(blinding_inputs, blinding_outputs);
     dbg!(excess);
     dbg!(excess_in_scalar);
@@ -273,12 +311,12 @@ fn main() {
         public_excess.y().unwrap().0
         ].concat()
          .into_iter()
-         .map(|v| v.to_ne_bytes())
-         .flatten()
+         .flat_map(|v| v.to_ne_bytes())
          .collect::<Vec<_>>();
     let hash = hash256(&h[..]);
     dbg!(format!("0x{:X}", hash));
     dbg!(Scalar::new(hash));
 
     silent_payment_demo();
+    range_proof_demo();
 }
\ No newline at end of file
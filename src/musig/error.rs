@@ -0,0 +1,34 @@
+///Reasons a MuSig2 operation can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///the public key at the index is infinity, or the aggregate key is
+    InvalidPublicKey(usize),
+    ///a public nonce is not a valid pair of points
+    InvalidPublicNonce,
+    ///the aggregate nonce is not a valid pair of points or infinities
+    InvalidAggregateNonce,
+    ///the tweak is not below N or the tweaked key is infinity
+    InvalidTweak,
+    ///the secret nonce is zero, malformed or does not belong to the secret key
+    InvalidSecretNonce,
+    ///the signer's public key is not one of the aggregated keys
+    SignerNotFound,
+    ///the partial signature just computed does not verify
+    InvalidPartialSignature,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidPublicKey(i) => write!(f, "invalid public key of signer {}", i),
+            Error::InvalidPublicNonce => write!(f, "invalid public nonce"),
+            Error::InvalidAggregateNonce => write!(f, "invalid aggregate nonce"),
+            Error::InvalidTweak => write!(f, "invalid tweak"),
+            Error::InvalidSecretNonce => write!(f, "invalid secret nonce"),
+            Error::SignerNotFound => write!(f, "signer is not part of the aggregate key"),
+            Error::InvalidPartialSignature => write!(f, "partial signature does not verify"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,94 @@
+use crate::{curves::Secp256k1, musig::Error, schnorr::bip340::{hash_to_scalar, x_bytes}, types::{ECpoint, Scalar, Generator}, utils::tagged_hash};
+
+///Sorts the public keys by their compressed encoding, so the aggregate key does not
+///depend on the order the signers were listed in
+pub fn key_sort(public_keys: &[ECpoint<Secp256k1>]) -> Vec<ECpoint<Secp256k1>> {
+    let mut sorted = public_keys.to_vec();
+    sorted.sort_by_key(|p| p.to_sec1(true));
+    sorted
+}
+
+///The aggregate public key Q = sum of a_i * P_i with the tweaks applied so far.
+///gacc and tacc track the negations and tweaks, so signers can account for them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyAggContext {
+    public_keys: Vec<ECpoint<Secp256k1>>,
+    list_hash: [u8; 32],
+    second_key: Option<ECpoint<Secp256k1>>,
+    q: ECpoint<Secp256k1>,
+    gacc: Scalar<Secp256k1>,
+    tacc: Scalar<Secp256k1>,
+}
+
+impl KeyAggContext {
+    ///KeyAgg of BIP327, the keys are used in the given order, see key_sort
+    pub fn new(public_keys: &[ECpoint<Secp256k1>]) -> Result<Self, Error> {
+        if let Some(i) = public_keys.iter().position(|p| p.is_infinity()) {
+            return Err(Error::InvalidPublicKey(i))
+        }
+        let encoded = public_keys.iter().flat_map(|p| p.to_sec1(true)).collect::<Vec<u8>>();
+        let list_hash = tagged_hash(b"KeyAgg list", &encoded);
+        let second_key = public_keys.iter().find(|&p| Some(p) != public_keys.first()).copied();
+        let mut ctx = Self {
+            public_keys: public_keys.to_vec(),
+            list_hash,
+            second_key,
+            q: ECpoint::Infinity,
+            gacc: Scalar::one(),
+            tacc: Scalar::zero(),
+        };
+        ctx.q = public_keys.iter().map(|&p| p * ctx.coefficient(&p)).sum();
+        if ctx.q.is_infinity() {
+            return Err(Error::InvalidPublicKey(0))
+        }
+        Ok(ctx)
+    }
+    ///a_i = H_KeyAgg coefficient(L || P_i), except 1 for the second distinct key
+    fn coefficient(&self, public_key: &ECpoint<Secp256k1>) -> Scalar<Secp256k1> {
+        match Some(*public_key) == self.second_key {
+            true => Scalar::one(),
+            false => hash_to_scalar(tagged_hash(b"KeyAgg coefficient", &[self.list_hash.as_slice(), &public_key.to_sec1(true)].concat()))
+        }
+    }
+    ///The key aggregation coefficient of the signer, None if the key was not aggregated
+    pub fn key_agg_coefficient(&self, public_key: &ECpoint<Secp256k1>) -> Option<Scalar<Secp256k1>> {
+        match self.public_keys.contains(public_key) {
+            true => Some(self.coefficient(public_key)),
+            false => None
+        }
+    }
+    ///Adds tweak * G to the aggregate key. An x-only tweak, such as the BIP341
+    ///taproot tweak, first negates the key if its y is odd.
+    pub fn apply_tweak(mut self, tweak: &[u8; 32], is_xonly: bool) -> Result<Self, Error> {
+        let t = Scalar::from_be_bytes(tweak).ok_or(Error::InvalidTweak)?;
+        let negate = is_xonly && !self.q.has_even_y();
+        let (q, g) = match negate {
+            true => (-self.q, -Scalar::one()),
+            false => (self.q, Scalar::one())
+        };
+        self.q = q + Secp256k1::generator() * t;
+        if self.q.is_infinity() {
+            return Err(Error::InvalidTweak)
+        }
+        self.gacc *= g;
+        self.tacc = t + g * self.tacc;
+        Ok(self)
+    }
+    pub fn public_keys(&self) -> &[ECpoint<Secp256k1>] {
+        &self.public_keys
+    }
+    ///Q, including the tweaks
+    pub fn aggregate_public_key(&self) -> ECpoint<Secp256k1> {
+        self.q
+    }
+    ///The x-only key the final signature verifies with
+    pub fn x_only_public_key(&self) -> [u8; 32] {
+        x_bytes(&self.q)
+    }
+    pub(crate) fn gacc(&self) -> Scalar<Secp256k1> {
+        self.gacc
+    }
+    pub(crate) fn tacc(&self) -> Scalar<Secp256k1> {
+        self.tacc
+    }
+}
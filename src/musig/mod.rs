@@ -0,0 +1,13 @@
+//!MuSig2 multi-signatures as in BIP327. n signers aggregate their keys into one
+//!x-only key and produce, in two rounds, a BIP340 signature that verifies with it,
+//!indistinguishable from a single signer's. Supports plain and x-only tweaks for
+//!taproot.
+pub use error::Error;
+pub use key_agg::{key_sort, KeyAggContext};
+pub use nonce::{nonce_agg, nonce_gen, nonce_gen_with_randomness, AggNonce, PubNonce, SecNonce};
+pub use session::Session;
+
+mod error;
+mod key_agg;
+mod nonce;
+mod session;
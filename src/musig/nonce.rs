@@ -0,0 +1,126 @@
+use crate::{curves::Secp256k1, musig::Error, schnorr::bip340::hash_to_scalar, types::{ECpoint, Scalar, Generator, EC}, utils::tagged_hash};
+
+///The two secret nonces of a signer and its public key. Not Clone, signing consumes
+///it, reusing a nonce for a second signature reveals the secret key.
+pub struct SecNonce {
+    pub(crate) k1: Scalar<Secp256k1>,
+    pub(crate) k2: Scalar<Secp256k1>,
+    pub(crate) public_key: ECpoint<Secp256k1>,
+}
+
+impl std::fmt::Debug for SecNonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecNonce [curve {}]", Secp256k1::NAME)
+    }
+}
+
+impl SecNonce {
+    ///k1 || k2 || compressed public key, 97 bytes
+    pub fn from_bytes(bytes: &[u8; 97]) -> Result<Self, Error> {
+        let k1 = Scalar::from_be_bytes(&bytes[..32]).ok_or(Error::InvalidSecretNonce)?;
+        let k2 = Scalar::from_be_bytes(&bytes[32..64]).ok_or(Error::InvalidSecretNonce)?;
+        let public_key = ECpoint::from_sec1(&bytes[64..]).ok_or(Error::InvalidSecretNonce)?;
+        Ok(Self { k1, k2, public_key })
+    }
+    pub fn to_bytes(&self) -> [u8; 97] {
+        [self.k1.to_be_bytes(), self.k2.to_be_bytes(), self.public_key.to_sec1(true)].concat().try_into().unwrap()
+    }
+}
+
+///The public nonces R1 = k1 * G and R2 = k2 * G, sent to the other signers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PubNonce {
+    pub r1: ECpoint<Secp256k1>,
+    pub r2: ECpoint<Secp256k1>,
+}
+
+impl PubNonce {
+    ///Two compressed points, 66 bytes
+    pub fn from_bytes(bytes: &[u8; 66]) -> Result<Self, Error> {
+        let point = |bytes: &[u8]| ECpoint::from_sec1(bytes).ok_or(Error::InvalidPublicNonce);
+        Ok(Self { r1: point(&bytes[..33])?, r2: point(&bytes[33..])? })
+    }
+    pub fn to_bytes(&self) -> [u8; 66] {
+        [self.r1.to_sec1(true), self.r2.to_sec1(true)].concat().try_into().unwrap()
+    }
+}
+
+///The sums of the R1 and of the R2 nonces of all signers, either may be infinity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AggNonce {
+    pub r1: ECpoint<Secp256k1>,
+    pub r2: ECpoint<Secp256k1>,
+}
+
+impl AggNonce {
+    ///Two compressed points, infinity encoded as 33 zero bytes
+    pub fn from_bytes(bytes: &[u8; 66]) -> Result<Self, Error> {
+        let point = |bytes: &[u8]| match bytes == [0; 33] {
+            true => Ok(ECpoint::Infinity),
+            false => ECpoint::from_sec1(bytes).ok_or(Error::InvalidAggregateNonce)
+        };
+        Ok(Self { r1: point(&bytes[..33])?, r2: point(&bytes[33..])? })
+    }
+    pub fn to_bytes(&self) -> [u8; 66] {
+        let encode = |p: &ECpoint<Secp256k1>| match p {
+            ECpoint::Infinity => vec![0; 33],
+            _ => p.to_sec1(true)
+        };
+        [encode(&self.r1), encode(&self.r2)].concat().try_into().unwrap()
+    }
+}
+
+///NonceGen of BIP327 with fresh randomness. The optional inputs only add extra
+///protection against a bad random number generator.
+pub fn nonce_gen(
+    secret: Option<Scalar<Secp256k1>>,
+    public_key: &ECpoint<Secp256k1>,
+    aggregate_public_key: Option<&[u8; 32]>,
+    msg: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> (SecNonce, PubNonce) {
+    nonce_gen_with_randomness(&rand::random(), secret, public_key, aggregate_public_key, msg, extra_in)
+}
+
+///NonceGen with the given randomness, meant for test vectors. Never call it twice
+///with the same randomness.
+pub fn nonce_gen_with_randomness(
+    rand: &[u8; 32],
+    secret: Option<Scalar<Secp256k1>>,
+    public_key: &ECpoint<Secp256k1>,
+    aggregate_public_key: Option<&[u8; 32]>,
+    msg: Option<&[u8]>,
+    extra_in: Option<&[u8]>,
+) -> (SecNonce, PubNonce) {
+    let rand = match secret {
+        Some(secret) => secret.to_be_bytes().iter().zip(tagged_hash(b"MuSig/aux", rand)).map(|(a, b)| a ^ b).collect(),
+        None => rand.to_vec()
+    };
+    let pk = public_key.to_sec1(true);
+    let aggregate_public_key = aggregate_public_key.map(|k| k.as_slice()).unwrap_or_default();
+    let msg_prefixed = match msg {
+        Some(msg) => [&[1], (msg.len() as u64).to_be_bytes().as_slice(), msg].concat(),
+        None => vec![0]
+    };
+    let extra_in = extra_in.unwrap_or_default();
+    let k = |i: u8| hash_to_scalar(tagged_hash(b"MuSig/nonce", &[
+        rand.as_slice(),
+        &[pk.len() as u8], &pk,
+        &[aggregate_public_key.len() as u8], aggregate_public_key,
+        &msg_prefixed,
+        &(extra_in.len() as u32).to_be_bytes(), extra_in,
+        &[i]
+    ].concat()));
+    let (k1, k2) = (k(0), k(1));
+    let g = Secp256k1::generator();
+    let pubnonce = PubNonce { r1: g * k1, r2: g * k2 };
+    (SecNonce { k1, k2, public_key: *public_key }, pubnonce)
+}
+
+///Sums the public nonces of all signers, done by any of them or a coordinator
+pub fn nonce_agg(pubnonces: &[PubNonce]) -> AggNonce {
+    AggNonce {
+        r1: pubnonces.iter().map(|n| n.r1).sum(),
+        r2: pubnonces.iter().map(|n| n.r2).sum(),
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{curves::Secp256k1, musig::{AggNonce, Error, KeyAggContext, PubNonce, SecNonce}, schnorr::bip340::{challenge, hash_to_scalar, x_bytes}, types::{ECpoint, Scalar, Generator}, utils::tagged_hash};
+
+///Everything the signers agree on before the second round, the aggregate nonce,
+///the (tweaked) key aggregation context and the message, with the values derived
+///from them: the nonce coefficient b, the final nonce R and the challenge e
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    key_agg: KeyAggContext,
+    aggnonce: AggNonce,
+    b: Scalar<Secp256k1>,
+    r: ECpoint<Secp256k1>,
+    e: Scalar<Secp256k1>,
+}
+
+impl Session {
+    pub fn new(aggnonce: AggNonce, key_agg: KeyAggContext, msg: &[u8]) -> Self {
+        let q = key_agg.x_only_public_key();
+        let b = hash_to_scalar(tagged_hash(b"MuSig/noncecoef", &[aggnonce.to_bytes().as_slice(), &q, msg].concat()));
+        let r = match aggnonce.r1 + aggnonce.r2 * b {
+            ECpoint::Infinity => Secp256k1::generator(),
+            r => r
+        };
+        let e = challenge(&x_bytes(&r), &q, msg);
+        Self { key_agg, aggnonce, b, r, e }
+    }
+    ///-1 if Q has an odd y, as the signature verifies with the even y key
+    fn g(&self) -> Scalar<Secp256k1> {
+        match self.key_agg.aggregate_public_key().has_even_y() {
+            true => Scalar::one(),
+            false => -Scalar::one()
+        }
+    }
+    ///The partial signature s = k1 + b * k2 + e * a * d, the nonces and the secret
+    ///negated as needed for an even R and Q. Like BIP327, s is verified before it is
+    ///returned, so a faulty computation does not give away a share of the secret.
+    pub fn sign(&self, secnonce: SecNonce, secret: Scalar<Secp256k1>) -> Result<Scalar<Secp256k1>, Error> {
+        if secnonce.k1.is_zero() || secnonce.k2.is_zero() || Secp256k1::generator() * secret != secnonce.public_key {
+            return Err(Error::InvalidSecretNonce)
+        }
+        let a = self.key_agg.key_agg_coefficient(&secnonce.public_key).ok_or(Error::SignerNotFound)?;
+        let (k1, k2) = match self.r.has_even_y() {
+            true => (secnonce.k1, secnonce.k2),
+            false => (-secnonce.k1, -secnonce.k2)
+        };
+        let d = self.g() * self.key_agg.gacc() * secret;
+        let s = k1 + self.b * k2 + self.e * a * d;
+        let g = Secp256k1::generator();
+        let pubnonce = PubNonce { r1: g * secnonce.k1, r2: g * secnonce.k2 };
+        match self.verify_partial_signature(&s, &pubnonce, &secnonce.public_key) {
+            true => Ok(s),
+            false => Err(Error::InvalidPartialSignature)
+        }
+    }
+    ///Checks s * G == R1 + b * R2 + e * a * g * P, with R1 and R2 negated if R has an odd y
+    pub fn verify_partial_signature(&self, partial_signature: &Scalar<Secp256k1>, pubnonce: &PubNonce, public_key: &ECpoint<Secp256k1>) -> bool {
+        let Some(a) = self.key_agg.key_agg_coefficient(public_key) else {
+            return false
+        };
+        let r = pubnonce.r1 + pubnonce.r2 * self.b;
+        let r = if self.r.has_even_y() { r } else { -r };
+        let g = self.g() * self.key_agg.gacc();
+        Secp256k1::generator() * *partial_signature == r + *public_key * (self.e * a * g)
+    }
+    ///Sums the partial signatures into a BIP340 signature for the x-only aggregate key
+    pub fn aggregate(&self, partial_signatures: &[Scalar<Secp256k1>]) -> [u8; 64] {
+        let s = partial_signatures.iter().copied().sum::<Scalar<Secp256k1>>() + self.e * self.g() * self.key_agg.tacc();
+        [x_bytes(&self.r).as_slice(), &s.to_be_bytes()].concat().try_into().unwrap()
+    }
+    pub fn aggnonce(&self) -> &AggNonce {
+        &self.aggnonce
+    }
+}
@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+
+use crate::{oprf::{suite::{finalize_hash, hash_to_group_in}, Error, Mode, Proof, Suite}, types::{ECpoint, Scalar, Generator}};
+
+///Client blinding its inputs and unblinding the server's evaluations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Client<S: Suite> {
+    mode: Mode,
+    _suite: PhantomData<S>,
+}
+
+impl<S: Suite> Client<S> {
+    pub fn new(mode: Mode) -> Self {
+        Self { mode, _suite: PhantomData }
+    }
+    ///Blind, returns the random blind r and r * HashToGroup(input) for the server
+    #[allow(clippy::type_complexity)]
+    pub fn blind(&self, input: &[u8]) -> Result<(Scalar<S::Curve>, ECpoint<S::Curve>), Error> {
+        let blind = Scalar::generate_secret();
+        Ok((blind, self.blind_with_randomness(input, blind)?))
+    }
+    ///Blind with a given r, for test vectors
+    pub fn blind_with_randomness(&self, input: &[u8], blind: Scalar<S::Curve>) -> Result<ECpoint<S::Curve>, Error> {
+        let element = hash_to_group_in::<S>(self.mode, input);
+        if element.is_infinity() {
+            return Err(Error::InvalidInput)
+        }
+        Ok(element * blind)
+    }
+    ///Finalize of the base mode, Hash(input, r^-1 * evaluated)
+    pub fn finalize(&self, input: &[u8], blind: Scalar<S::Curve>, evaluated: &ECpoint<S::Curve>) -> Vec<u8> {
+        finalize_hash::<S>(input, &(*evaluated / blind))
+    }
+    ///FinalizeBatch of the verifiable mode, checks the proof for the server's public
+    ///key before unblinding
+    pub fn finalize_batch(
+        &self,
+        public_key: &ECpoint<S::Curve>,
+        inputs: &[&[u8]],
+        blinds: &[Scalar<S::Curve>],
+        blinded: &[ECpoint<S::Curve>],
+        evaluated: &[ECpoint<S::Curve>],
+        proof: &Proof<S>,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        if [blinds.len(), blinded.len(), evaluated.len()].iter().any(|&len| len != inputs.len())
+            || !proof.verify(self.mode, &S::Curve::generator(), public_key, blinded, evaluated) {
+            return Err(Error::Verify)
+        }
+        Ok(inputs.iter().zip(blinds).zip(evaluated).map(|((input, &blind), evaluated)| self.finalize(input, blind, evaluated)).collect())
+    }
+}
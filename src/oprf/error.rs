@@ -0,0 +1,22 @@
+///Reasons an OPRF operation can fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    ///the input hashes to the identity
+    InvalidInput,
+    ///no non zero key within 256 tries
+    DeriveKeyPair,
+    ///the proof does not verify or the batch sizes differ
+    Verify,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidInput => write!(f, "input hashes to the identity"),
+            Error::DeriveKeyPair => write!(f, "key pair derivation failed"),
+            Error::Verify => write!(f, "proof verification failed"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,16 @@
+//!Oblivious pseudorandom functions of RFC 9497: the client learns F(k, x) for the
+//!server's key k without the server learning x, e.g. for password hardening or
+//!anonymous tokens. The client blinds H(x) with r, the server multiplies by k and the
+//!client unblinds. In the verifiable mode the server proves with a batched DLEQ proof
+//!that it used the key of its public key. Generic over the suite, with P256-SHA256.
+pub use suite::{Mode, P256Sha256, Suite};
+pub use error::Error;
+pub use proof::Proof;
+pub use server::Server;
+pub use client::Client;
+
+mod suite;
+mod error;
+mod proof;
+mod server;
+mod client;
@@ -0,0 +1,62 @@
+use crate::{oprf::{suite::{context_string, hash_to_scalar_in, length_prefixed}, Mode, Suite}, types::{ECpoint, Scalar, EC}, utils::multiscalar_mul};
+
+///Batched DLEQ proof (c, s) that D_i = k * C_i for all i with B = k * A, the C_i and
+///D_i combined into M and Z with weights hashed from all of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Proof<S: Suite> {
+    pub c: Scalar<S::Curve>,
+    pub s: Scalar<S::Curve>,
+}
+
+///ComputeComposites, M = sum of d_i * C_i and Z = sum of d_i * D_i
+fn compute_composites<S: Suite>(mode: Mode, b: &ECpoint<S::Curve>, c: &[ECpoint<S::Curve>], d: &[ECpoint<S::Curve>])
+-> (ECpoint<S::Curve>, ECpoint<S::Curve>) {
+    let seed_dst = [b"Seed-".as_slice(), &context_string::<S>(mode)].concat();
+    let seed = S::hash(&[length_prefixed(&b.to_sec1(true)), length_prefixed(&seed_dst)].concat());
+    let weights = c.iter().zip(d).enumerate()
+        .map(|(i, (c, d))| hash_to_scalar_in::<S>(mode, &[
+            length_prefixed(&seed),
+            (i as u16).to_be_bytes().to_vec(),
+            length_prefixed(&c.to_sec1(true)),
+            length_prefixed(&d.to_sec1(true)),
+            b"Composite".to_vec(),
+        ].concat()))
+        .collect::<Vec<_>>();
+    (multiscalar_mul(&weights, c), multiscalar_mul(&weights, d))
+}
+
+fn challenge<S: Suite>(mode: Mode, points: [&ECpoint<S::Curve>; 5]) -> Scalar<S::Curve> {
+    let transcript = points.iter().flat_map(|p| length_prefixed(&p.to_sec1(true))).collect::<Vec<u8>>();
+    hash_to_scalar_in::<S>(mode, &[transcript.as_slice(), b"Challenge"].concat())
+}
+
+impl<S: Suite> Proof<S> {
+    ///GenerateProof with the randomness r, Z = k * M as the prover knows k
+    pub(crate) fn generate(mode: Mode, k: Scalar<S::Curve>, a: &ECpoint<S::Curve>, b: &ECpoint<S::Curve>, c: &[ECpoint<S::Curve>], d: &[ECpoint<S::Curve>], r: Scalar<S::Curve>) -> Self {
+        let (m, _) = compute_composites::<S>(mode, b, c, d);
+        let z = m * k;
+        let c = challenge::<S>(mode, [b, &m, &z, &(*a * r), &(m * r)]);
+        Self { c, s: r - c * k }
+    }
+    ///VerifyProof, recomputes the challenge with s * A + c * B and s * M + c * Z
+    pub(crate) fn verify(&self, mode: Mode, a: &ECpoint<S::Curve>, b: &ECpoint<S::Curve>, c: &[ECpoint<S::Curve>], d: &[ECpoint<S::Curve>]) -> bool {
+        if c.len() != d.len() {
+            return false
+        }
+        let (m, z) = compute_composites::<S>(mode, b, c, d);
+        let t2 = multiscalar_mul(&[self.s, self.c], &[*a, *b]);
+        let t3 = multiscalar_mul(&[self.s, self.c], &[m, z]);
+        challenge::<S>(mode, [b, &m, &z, &t2, &t3]) == self.c
+    }
+    ///c || s
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.c.to_be_bytes(), self.s.to_be_bytes()].concat()
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let len = S::Curve::N.bits().div_ceil(8);
+        if bytes.len() != 2 * len {
+            return None
+        }
+        Some(Self { c: Scalar::from_be_bytes(&bytes[..len])?, s: Scalar::from_be_bytes(&bytes[len..])? })
+    }
+}
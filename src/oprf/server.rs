@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+use crate::{oprf::{suite::{context_string, finalize_hash, hash_to_group_in, length_prefixed}, Error, Mode, Proof, Suite}, types::{ECpoint, Scalar, Generator}};
+
+///Server holding the OPRF key k
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Server<S: Suite> {
+    mode: Mode,
+    secret_key: Scalar<S::Curve>,
+    _suite: PhantomData<S>,
+}
+
+impl<S: Suite> Server<S> {
+    pub fn new(mode: Mode, secret_key: Scalar<S::Curve>) -> Self {
+        Self { mode, secret_key, _suite: PhantomData }
+    }
+    ///DeriveKeyPair, the first non zero HashToScalar(seed || I2OSP(len(info), 2) ||
+    ///info || counter) with the DST "DeriveKeyPair" || contextString
+    pub fn derive_key_pair(mode: Mode, seed: &[u8], info: &[u8]) -> Result<Self, Error> {
+        let input = [seed, &length_prefixed(info)].concat();
+        let dst = [b"DeriveKeyPair".as_slice(), &context_string::<S>(mode)].concat();
+        (0..=u8::MAX)
+            .map(|counter| S::hash_to_scalar(&[input.as_slice(), &[counter]].concat(), &dst))
+            .find(|k| !k.is_zero())
+            .map(|k| Self::new(mode, k))
+            .ok_or(Error::DeriveKeyPair)
+    }
+    pub fn secret_key(&self) -> Scalar<S::Curve> {
+        self.secret_key
+    }
+    pub fn public_key(&self) -> ECpoint<S::Curve> {
+        S::Curve::generator() * self.secret_key
+    }
+    ///BlindEvaluate of the base mode, k * blinded
+    pub fn blind_evaluate(&self, blinded: &ECpoint<S::Curve>) -> ECpoint<S::Curve> {
+        *blinded * self.secret_key
+    }
+    ///BlindEvaluateBatch of the verifiable mode, the evaluated elements with one proof
+    ///for all of them
+    pub fn blind_evaluate_batch(&self, blinded: &[ECpoint<S::Curve>]) -> (Vec<ECpoint<S::Curve>>, Proof<S>) {
+        self.blind_evaluate_batch_with_randomness(blinded, Scalar::generate_secret())
+    }
+    ///blind_evaluate_batch with the randomness of the proof, for test vectors
+    pub fn blind_evaluate_batch_with_randomness(&self, blinded: &[ECpoint<S::Curve>], r: Scalar<S::Curve>) -> (Vec<ECpoint<S::Curve>>, Proof<S>) {
+        let evaluated = blinded.iter().map(|b| self.blind_evaluate(b)).collect::<Vec<_>>();
+        let proof = Proof::generate(self.mode, self.secret_key, &S::Curve::generator(), &self.public_key(), blinded, &evaluated, r);
+        (evaluated, proof)
+    }
+    ///Evaluate, the OPRF output computed directly from the input
+    pub fn evaluate(&self, input: &[u8]) -> Result<Vec<u8>, Error> {
+        let element = hash_to_group_in::<S>(self.mode, input);
+        if element.is_infinity() {
+            return Err(Error::InvalidInput)
+        }
+        Ok(finalize_hash::<S>(input, &(element * self.secret_key)))
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Debug;
+
+use ring::digest::{digest, SHA256};
+
+use crate::{curves::P256, types::{ECpoint, Scalar, Zp, Generator}, utils::{hash_to_curve_sswu, hash_to_scalar}};
+
+///Protocol variant, part of every domain separation tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Oprf = 0x00,
+    Voprf = 0x01,
+}
+
+///OPRF ciphersuite, the group with its hash functions and the hash of the output
+pub trait Suite: Debug + Copy + Eq {
+    type Curve: Generator;
+    const IDENTIFIER: &'static [u8];
+    ///HashToGroup, hash_to_curve of RFC 9380
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> ECpoint<Self::Curve>;
+    ///HashToScalar, hash_to_field of RFC 9380 mod N
+    fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> Scalar<Self::Curve>;
+    fn hash(msg: &[u8]) -> Vec<u8>;
+}
+
+///P256-SHA256, hash_to_curve with P256_XMD:SHA-256_SSWU_RO_
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P256Sha256;
+
+impl Suite for P256Sha256 {
+    type Curve = P256;
+    const IDENTIFIER: &'static [u8] = b"P256-SHA256";
+    fn hash_to_group(msg: &[u8], dst: &[u8]) -> ECpoint<P256> {
+        hash_to_curve_sswu(msg, dst, Zp::new(-10))
+    }
+    fn hash_to_scalar(msg: &[u8], dst: &[u8]) -> Scalar<P256> {
+        hash_to_scalar(msg, dst)
+    }
+    fn hash(msg: &[u8]) -> Vec<u8> {
+        digest(&SHA256, msg).as_ref().to_vec()
+    }
+}
+
+///"OPRFV1-" || mode || "-" || identifier
+pub(crate) fn context_string<S: Suite>(mode: Mode) -> Vec<u8> {
+    [b"OPRFV1-".as_slice(), &[mode as u8], b"-", S::IDENTIFIER].concat()
+}
+
+///I2OSP(len(bytes), 2) || bytes
+pub(crate) fn length_prefixed(bytes: &[u8]) -> Vec<u8> {
+    [&(bytes.len() as u16).to_be_bytes(), bytes].concat()
+}
+
+///HashToScalar with the DST "HashToScalar-" || contextString
+pub(crate) fn hash_to_scalar_in<S: Suite>(mode: Mode, msg: &[u8]) -> Scalar<S::Curve> {
+    S::hash_to_scalar(msg, &[b"HashToScalar-".as_slice(), &context_string::<S>(mode)].concat())
+}
+
+///HashToGroup with the DST "HashToGroup-" || contextString
+pub(crate) fn hash_to_group_in<S: Suite>(mode: Mode, msg: &[u8]) -> ECpoint<S::Curve> {
+    S::hash_to_group(msg, &[b"HashToGroup-".as_slice(), &context_string::<S>(mode)].concat())
+}
+
+///Hash(I2OSP(len(input), 2) || input || I2OSP(len(element), 2) || element || "Finalize")
+pub(crate) fn finalize_hash<S: Suite>(input: &[u8], element: &ECpoint<S::Curve>) -> Vec<u8> {
+    S::hash(&[length_prefixed(input), length_prefixed(&element.to_sec1(true)), b"Finalize".to_vec()].concat())
+}
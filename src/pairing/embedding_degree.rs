@@ -0,0 +1,17 @@
+use crate::types::{EC, U512};
+
+///Smallest k <= max_k such that N divides P^k - 1, in other words the degree of
+///the extension of Zp the pairing values of the subgroup of order N live in.
+///A small embedding degree makes the MOV attack possible.
+pub fn embedding_degree<E: EC>(max_k: u32) -> Option<u32> {
+    let n = U512::from(E::N);
+    let p = U512::from(E::P) % n;
+    let mut p_k = U512::one();
+    for k in 1..=max_k {
+        p_k = p_k * p % n;
+        if p_k == U512::one() % n {
+            return Some(k)
+        }
+    }
+    None
+}
@@ -0,0 +1,61 @@
+use crate::types::{CurveOver, ECpoint, Field, U256};
+
+///Evaluates at Q the line through T and P (the tangent if T == P) divided by the
+///vertical line through T + P. Returns the value together with T + P, or None
+///if Q is on the vertical line.
+fn line<E: CurveOver<K>, K: Field>(t: ECpoint<E, K>, p: ECpoint<E, K>, q: ECpoint<E, K>) -> Option<(K, ECpoint<E, K>)> {
+    let sum = t + p;
+    let (xq, yq) = (q.x(), q.y());
+    let numerator = match t == -p {
+        //vertical line x = xT
+        true => xq - t.x(),
+        false => {
+            let lambda = match t == p {
+                true => (t.x().square() * K::from(3) + E::a()) / (t.y() * K::from(2)),
+                false => (p.y() - t.y()) / (p.x() - t.x()),
+            };
+            yq - t.y() - lambda * (xq - t.x())
+        }
+    };
+    let denominator = match sum {
+        ECpoint::Infinity => K::one(),
+        ECpoint::Point(_) => xq - sum.x(),
+    };
+    match denominator.is_zero() {
+        true => None,
+        false => Some((numerator / denominator, sum))
+    }
+}
+
+///Miller's algorithm, evaluates at Q the function f with divisor r(P) - r(O),
+///normalized at infinity. Returns None if Q is a zero or pole of one of the
+///intermediate lines, e.g. Q is a multiple of P, or either point is infinity.
+pub fn miller_loop<E: CurveOver<K>, K: Field, T: Into<U256>>(p: ECpoint<E, K>, q: ECpoint<E, K>, r: T) -> Option<K> {
+    let r = r.into();
+    if p.is_infinity() || q.is_infinity() || r.is_zero() {
+        return None
+    }
+    let mut f = K::one();
+    let mut t = p;
+    for i in (0..r.bits() - 1).rev() {
+        //the loop can not continue from infinity, happens if r is a multiple of the order of P
+        if t.is_infinity() {
+            return None
+        }
+        let (l, double) = line(t, t, q)?;
+        f = f.square() * l;
+        t = double;
+        if r.bit(i) {
+            if t.is_infinity() {
+                return None
+            }
+            let (l, sum) = line(t, p, q)?;
+            f *= l;
+            t = sum;
+        }
+    }
+    match f.is_zero() {
+        true => None,
+        false => Some(f)
+    }
+}
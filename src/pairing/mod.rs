@@ -0,0 +1,12 @@
+//!Bilinear pairings on curves with a small embedding degree. Both points live on
+//!the curve over the extension field K, points over Zp can be moved there with
+//!ECpoint::lift. Useful for BLS style experiments and the MOV attack.
+pub use miller_loop::miller_loop;
+pub use tate_pairing::tate_pairing;
+pub use weil_pairing::weil_pairing;
+pub use embedding_degree::embedding_degree;
+
+mod miller_loop;
+mod tate_pairing;
+mod weil_pairing;
+mod embedding_degree;
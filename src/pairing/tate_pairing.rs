@@ -0,0 +1,15 @@
+use crate::{pairing::miller_loop, types::{CurveOver, ECpoint, Field, U256}, utils::limbs};
+
+///Reduced Tate pairing e(P, Q) = f_{r,P}(Q)^((q^k - 1) / r), where q^k is the order of K.
+///P has to be of order r, K is expected to contain the r-th roots of unity, so its
+///degree over Zp is a multiple of the embedding degree. The result is an r-th root
+///of unity. Returns None when Miller's loop hits a degenerate point.
+pub fn tate_pairing<E: CurveOver<K>, K: Field, T: Into<U256>>(p: ECpoint<E, K>, q: ECpoint<E, K>, r: T) -> Option<K> {
+    let r = r.into();
+    if p.is_infinity() || q.is_infinity() {
+        return Some(K::one())
+    }
+    let f = miller_loop(p, q, r)?;
+    let (exponent, _) = limbs::div_rem(&limbs::sub_one(&K::order()), r);
+    Some(f.pow_limbs(&exponent))
+}
@@ -0,0 +1,16 @@
+use crate::{pairing::miller_loop, types::{CurveOver, ECpoint, Field, U256}};
+
+///Weil pairing e(P, Q) = (-1)^r f_{r,P}(Q) / f_{r,Q}(P) for P and Q in E[r].
+///The result is an r-th root of unity, e(P, P) = 1. Returns None when Miller's
+///loop hits a degenerate point, e.g. Q is a multiple of P other than P.
+pub fn weil_pairing<E: CurveOver<K>, K: Field, T: Into<U256>>(p: ECpoint<E, K>, q: ECpoint<E, K>, r: T) -> Option<K> {
+    let r = r.into();
+    if p.is_infinity() || q.is_infinity() || p == q {
+        return Some(K::one())
+    }
+    let e = miller_loop(p, q, r)? / miller_loop(q, p, r)?;
+    match r.bit(0) {
+        true => Some(-e),
+        false => Some(e)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{proofs::Transcript, types::{ECpoint, Scalar, Generator, EC}, utils::multiscalar_mul};
+
+///Chaum-Pedersen proof that log_G(A) = log_H(B), e.g. that a shared secret B = k * H
+///was computed with the secret key of A = k * G, without revealing k. The prover
+///commits to R1 = r * G and R2 = r * H, and s = r + c * k.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DleqProof<E: EC> {
+    pub commitment_g: ECpoint<E>,
+    pub commitment_h: ECpoint<E>,
+    pub response: Scalar<E>,
+}
+
+///Absorbs the statement and the commitments and returns the challenge
+fn challenge<E: EC>(transcript: &mut Transcript, h: &ECpoint<E>, a: &ECpoint<E>, b: &ECpoint<E>, r1: &ECpoint<E>, r2: &ECpoint<E>) -> Scalar<E> {
+    transcript.append_message(b"proof", b"chaum-pedersen-dleq");
+    transcript.append_point(b"H", h);
+    transcript.append_point(b"A", a);
+    transcript.append_point(b"B", b);
+    transcript.append_point(b"R1", r1);
+    transcript.append_point(b"R2", r2);
+    transcript.challenge_scalar(b"c")
+}
+
+impl<E: Generator> DleqProof<E> {
+    ///Proves A = k * G and B = k * H share the secret k
+    pub fn prove(transcript: &mut Transcript, h: &ECpoint<E>, secret: Scalar<E>) -> Self {
+        let g = E::generator();
+        let r = Scalar::generate_secret();
+        let (commitment_g, commitment_h) = (g * r, *h * r);
+        let c = challenge(transcript, h, &(g * secret), &(*h * secret), &commitment_g, &commitment_h);
+        Self { commitment_g, commitment_h, response: r + c * secret }
+    }
+    ///Checks s * G == R1 + c * A and s * H == R2 + c * B
+    pub fn verify(&self, transcript: &mut Transcript, h: &ECpoint<E>, a: &ECpoint<E>, b: &ECpoint<E>) -> bool {
+        let c = challenge(transcript, h, a, b, &self.commitment_g, &self.commitment_h);
+        E::generator() * self.response == self.commitment_g + *a * c
+            && *h * self.response == self.commitment_h + *b * c
+    }
+    ///Verifies many proofs for the same H at once with a random linear combination
+    ///of both equations of every proof
+    pub fn verify_batch(transcripts: &mut [Transcript], h: &ECpoint<E>, statements: &[(ECpoint<E>, ECpoint<E>)], proofs: &[Self]) -> bool {
+        if transcripts.len() != proofs.len() || statements.len() != proofs.len() {
+            return false
+        }
+        let (mut s_g, mut s_h) = (Scalar::zero(), Scalar::zero());
+        let (mut scalars, mut points) = (vec![], vec![]);
+        for ((transcript, (a, b)), proof) in transcripts.iter_mut().zip(statements).zip(proofs) {
+            let c = challenge(transcript, h, a, b, &proof.commitment_g, &proof.commitment_h);
+            let (z1, z2) = (Scalar::generate_secret(), Scalar::generate_secret());
+            s_g += z1 * proof.response;
+            s_h += z2 * proof.response;
+            scalars.extend([-z1, -z1 * c, -z2, -z2 * c]);
+            points.extend([proof.commitment_g, *a, proof.commitment_h, *b]);
+        }
+        scalars.extend([s_g, s_h]);
+        points.extend([E::generator(), *h]);
+        multiscalar_mul(&scalars, &points).is_infinity()
+    }
+    ///Compressed R1 || compressed R2 || s
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.commitment_g.to_sec1(true), self.commitment_h.to_sec1(true), self.response.to_be_bytes()].concat()
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let point_len = 1 + E::P.bits().div_ceil(8);
+        if bytes.len() != 2 * point_len + E::N.bits().div_ceil(8) {
+            return None
+        }
+        Some(Self {
+            commitment_g: ECpoint::from_sec1(&bytes[..point_len])?,
+            commitment_h: ECpoint::from_sec1(&bytes[point_len..2 * point_len])?,
+            response: Scalar::from_be_bytes(&bytes[2 * point_len..])?,
+        })
+    }
+}
@@ -0,0 +1,11 @@
+//!Non-interactive zero knowledge proofs about discrete logs, made non-interactive
+//!with the Fiat-Shamir transform over a domain separated transcript: Schnorr proofs
+//!of knowledge and Chaum-Pedersen proofs of discrete log equality, with batch
+//!verification.
+pub use transcript::Transcript;
+pub use schnorr::SchnorrProof;
+pub use dleq::DleqProof;
+
+mod transcript;
+mod schnorr;
+mod dleq;
@@ -0,0 +1,63 @@
+use crate::{proofs::Transcript, types::{ECpoint, Scalar, Generator, EC}, utils::multiscalar_mul};
+
+///Non-interactive proof of knowledge of k such that P = k * G. The prover commits
+///to R = r * G, the challenge c comes from the transcript and s = r + c * k.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchnorrProof<E: EC> {
+    pub commitment: ECpoint<E>,
+    pub response: Scalar<E>,
+}
+
+///Absorbs the statement and the commitment and returns the challenge
+fn challenge<E: EC>(transcript: &mut Transcript, public: &ECpoint<E>, commitment: &ECpoint<E>) -> Scalar<E> {
+    transcript.append_message(b"proof", b"schnorr-pok");
+    transcript.append_point(b"P", public);
+    transcript.append_point(b"R", commitment);
+    transcript.challenge_scalar(b"c")
+}
+
+impl<E: Generator> SchnorrProof<E> {
+    pub fn prove(transcript: &mut Transcript, secret: Scalar<E>) -> Self {
+        let g = E::generator();
+        let r = Scalar::generate_secret();
+        let commitment = g * r;
+        let c = challenge(transcript, &(g * secret), &commitment);
+        Self { commitment, response: r + c * secret }
+    }
+    ///Checks s * G == R + c * P, with the transcript in the same state as the prover's
+    pub fn verify(&self, transcript: &mut Transcript, public: &ECpoint<E>) -> bool {
+        let c = challenge(transcript, public, &self.commitment);
+        E::generator() * self.response == self.commitment + *public * c
+    }
+    ///Verifies many proofs at once with a random linear combination, the sum of
+    ///z_i * (s_i * G - R_i - c_i * P_i) is infinity for random z_i only if every
+    ///proof is valid, except with negligible probability
+    pub fn verify_batch(transcripts: &mut [Transcript], publics: &[ECpoint<E>], proofs: &[Self]) -> bool {
+        if transcripts.len() != proofs.len() || publics.len() != proofs.len() {
+            return false
+        }
+        let mut s = Scalar::zero();
+        let (mut scalars, mut points) = (vec![], vec![]);
+        for ((transcript, public), proof) in transcripts.iter_mut().zip(publics).zip(proofs) {
+            let c = challenge(transcript, public, &proof.commitment);
+            let z = Scalar::generate_secret();
+            s += z * proof.response;
+            scalars.extend([-z, -z * c]);
+            points.extend([proof.commitment, *public]);
+        }
+        scalars.push(s);
+        points.push(E::generator());
+        multiscalar_mul(&scalars, &points).is_infinity()
+    }
+    ///Compressed R || s
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [self.commitment.to_sec1(true), self.response.to_be_bytes()].concat()
+    }
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let split = bytes.len().checked_sub(E::N.bits().div_ceil(8))?;
+        Some(Self {
+            commitment: ECpoint::from_sec1(&bytes[..split])?,
+            response: Scalar::from_be_bytes(&bytes[split..])?,
+        })
+    }
+}
@@ -0,0 +1,51 @@
+use ring::digest::{digest, SHA256};
+
+use crate::{types::{ECpoint, Scalar, Zp, EC}, utils::hash_to_scalar};
+
+///Fiat-Shamir transcript. Every message is absorbed with its label into a running
+///SHA-256 state, lengths included, so the challenges bind the domain, the statement
+///and the prover's commitments, and two different sequences never collide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    state: [u8; 32],
+}
+
+impl Transcript {
+    ///Starts a transcript for the protocol named by the domain separator
+    pub fn new(domain: &[u8]) -> Self {
+        let mut transcript = Self { state: [0; 32] };
+        transcript.append_message(b"domain-separator", domain);
+        transcript
+    }
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        let hash = digest(&SHA256, &[
+            self.state.as_slice(),
+            &(label.len() as u64).to_be_bytes(), label,
+            &(message.len() as u64).to_be_bytes(), message
+        ].concat());
+        self.state = hash.as_ref().try_into().unwrap();
+    }
+    ///Absorbs the compressed SEC1 encoding of the point
+    pub fn append_point<E: EC>(&mut self, label: &[u8], point: &ECpoint<E>) {
+        self.append_message(label, &point.to_sec1(true));
+    }
+    pub fn append_scalar<E: EC>(&mut self, label: &[u8], scalar: &Scalar<E>) {
+        self.append_message(label, &scalar.to_be_bytes());
+    }
+    ///Absorbs the big endian value, padded to the byte length of P
+    pub fn append_zp<E: EC>(&mut self, label: &[u8], value: &Zp<E>) {
+        let mut bytes = [0u8; 32];
+        value.unwrap().to_big_endian(&mut bytes);
+        self.append_message(label, &bytes[32 - E::P.bits().div_ceil(8)..]);
+    }
+    pub fn append_u64(&mut self, label: &[u8], value: u64) {
+        self.append_message(label, &value.to_be_bytes());
+    }
+    ///Derives a uniform challenge from everything absorbed so far, and absorbs it,
+    ///so the next challenge differs
+    pub fn challenge_scalar<E: EC>(&mut self, label: &[u8]) -> Scalar<E> {
+        let challenge = hash_to_scalar(&[self.state.as_slice(), label].concat(), b"transcript challenge");
+        self.append_scalar(label, &challenge);
+        challenge
+    }
+}
@@ -0,0 +1,19 @@
+use ring::{error::KeyRejected, rand::SystemRandom, signature::{EcdsaKeyPair, KeyPair, UnparsedPublicKey, ECDSA_P256_SHA256_FIXED, ECDSA_P256_SHA256_FIXED_SIGNING}};
+
+use crate::{curves::P256, der::pkcs8_to_der, types::{ECpoint, Scalar}};
+
+///ring key pair of the secret, imported from its PKCS#8 encoding. ring signs with
+///fixed size r || s signatures and random nonces of its own.
+pub fn to_ring_key_pair(secret: &Scalar<P256>) -> Result<EcdsaKeyPair, KeyRejected> {
+    EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &pkcs8_to_der(secret), &SystemRandom::new())
+}
+
+///Public key of a ring key pair, ring hands it out uncompressed
+pub fn from_ring_public_key(key_pair: &EcdsaKeyPair) -> Option<ECpoint<P256>> {
+    ECpoint::from_sec1(key_pair.public_key().as_ref()).filter(|p| !p.is_infinity())
+}
+
+///ring public key for fixed size signatures, None for infinity
+pub fn to_ring_public_key(public: &ECpoint<P256>) -> Option<UnparsedPublicKey<Vec<u8>>> {
+    (!public.is_infinity()).then(|| UnparsedPublicKey::new(&ECDSA_P256_SHA256_FIXED, public.to_sec1(false)))
+}
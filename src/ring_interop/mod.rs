@@ -0,0 +1,8 @@
+//!Bridge to ring's ECDSA P-256 with SHA-256, enabled by the ring-interop feature:
+//!keys and signatures converted to and from ring's formats, so ring can check
+//!signatures made here and the other way around.
+pub use keys::{from_ring_public_key, to_ring_key_pair, to_ring_public_key};
+pub use signature::{from_ring_signature, verify_der_with_ring, verify_with_ring};
+
+mod keys;
+mod signature;
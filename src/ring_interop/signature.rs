@@ -0,0 +1,18 @@
+use ring::signature::{UnparsedPublicKey, ECDSA_P256_SHA256_ASN1};
+
+use crate::{curves::P256, ecdsa::Signature, ring_interop::to_ring_public_key, types::ECpoint};
+
+///Signature made by a ring key pair of to_ring_key_pair
+pub fn from_ring_signature(signature: &ring::signature::Signature) -> Option<Signature<P256>> {
+    Signature::from_bytes(signature.as_ref())
+}
+
+///ring's verification of the SHA-256 ECDSA signature in its r || s form
+pub fn verify_with_ring(public: &ECpoint<P256>, msg: &[u8], signature: &Signature<P256>) -> bool {
+    to_ring_public_key(public).is_some_and(|key| key.verify(msg, &signature.to_bytes()).is_ok())
+}
+
+///ring's verification of the signature in its DER form
+pub fn verify_der_with_ring(public: &ECpoint<P256>, msg: &[u8], signature: &Signature<P256>) -> bool {
+    !public.is_infinity() && UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public.to_sec1(false)).verify(msg, &signature.to_der()).is_ok()
+}
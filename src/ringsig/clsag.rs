@@ -0,0 +1,83 @@
+use crate::{ringsig::{hash_point, key_image::in_subgroup, sag}, types::{ECpoint, Scalar, Generator, EC}, utils::hash_to_scalar};
+
+///Concise linkable ring signature over a ring of (public key, amount commitment)
+///pairs. Proves knowledge of p with P_pi = p * G and of z with C_pi - C' = z * G for
+///the pseudo output commitment C', so both commit to the same amount, at the cost
+///of a single response per member. D = z * Hp(P_pi) is the auxiliary image of z.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClsagSignature<E: EC> {
+    pub challenge: Scalar<E>,
+    pub responses: Vec<Scalar<E>>,
+    pub key_image: ECpoint<E>,
+    pub commitment_image: ECpoint<E>,
+}
+
+///The ring, the commitments and the pseudo output, what every hash starts with
+fn ring_bytes<E: EC>(ring: &[(ECpoint<E>, ECpoint<E>)], pseudo_output: &ECpoint<E>) -> Vec<u8> {
+    [
+        ring.iter().flat_map(|(p, _)| p.to_sec1(true)).collect(),
+        ring.iter().flat_map(|(_, c)| c.to_sec1(true)).collect(),
+        pseudo_output.to_sec1(true),
+    ].concat()
+}
+
+///The weights mu_P and mu_C of the key and the commitment in W_i = mu_P * P_i +
+///mu_C * (C_i - C'), binding the images to the ring
+fn aggregation_coefficients<E: EC>(prefix: &[u8], key_image: &ECpoint<E>, commitment_image: &ECpoint<E>) -> (Scalar<E>, Scalar<E>) {
+    let msg = [prefix, &key_image.to_sec1(true), &commitment_image.to_sec1(true)].concat();
+    (hash_to_scalar(&msg, b"CLSAG_agg_0"), hash_to_scalar(&msg, b"CLSAG_agg_1"))
+}
+
+///The aggregated keys W_i, the hashed keys Hp(P_i) and the aggregated image
+fn aggregate<E: EC>(ring: &[(ECpoint<E>, ECpoint<E>)], pseudo_output: &ECpoint<E>, mu: (Scalar<E>, Scalar<E>), key_image: &ECpoint<E>, commitment_image: &ECpoint<E>)
+-> (Vec<ECpoint<E>>, Vec<ECpoint<E>>, ECpoint<E>) {
+    let keys = ring.iter().map(|&(p, c)| p * mu.0 + (c - *pseudo_output) * mu.1).collect();
+    let hashed = ring.iter().map(|(p, _)| hash_point(p)).collect();
+    (keys, hashed, *key_image * mu.0 + *commitment_image * mu.1)
+}
+
+///H(ring || commitments || C' || msg || L || R)
+fn challenge<E: EC>(prefix: &[u8], msg: &[u8]) -> impl Fn(&ECpoint<E>, &ECpoint<E>) -> Scalar<E> {
+    let prefix = [prefix, msg].concat();
+    move |l, r| hash_to_scalar(&[prefix.as_slice(), &l.to_sec1(true), &r.to_sec1(true)].concat(), b"CLSAG_round")
+}
+
+impl<E: Generator> ClsagSignature<E> {
+    ///Signs msg as ring[index] with P_pi = secret * G and C_pi - C' =
+    ///commitment_secret * G, None if index is out of the ring or either does not hold
+    pub fn sign(
+        msg: &[u8],
+        ring: &[(ECpoint<E>, ECpoint<E>)],
+        pseudo_output: &ECpoint<E>,
+        index: usize,
+        secret: Scalar<E>,
+        commitment_secret: Scalar<E>,
+    ) -> Option<Self> {
+        let g = E::generator();
+        let &(public, commitment) = ring.get(index)?;
+        if public != g * secret || secret.is_zero() || commitment - *pseudo_output != g * commitment_secret {
+            return None
+        }
+        let hashed = hash_point(&public);
+        let (key_image, commitment_image) = (hashed * secret, hashed * commitment_secret);
+        let prefix = ring_bytes(ring, pseudo_output);
+        let mu = aggregation_coefficients(&prefix, &key_image, &commitment_image);
+        let (keys, hashed, image) = aggregate(ring, pseudo_output, mu, &key_image, &commitment_image);
+        let (challenge, responses) = sag::sign(challenge(&prefix, msg), &keys, &hashed, &image, index, mu.0 * secret + mu.1 * commitment_secret);
+        Some(Self { challenge, responses, key_image, commitment_image })
+    }
+    pub fn verify(&self, msg: &[u8], ring: &[(ECpoint<E>, ECpoint<E>)], pseudo_output: &ECpoint<E>) -> bool {
+        if ring.is_empty() || ring.iter().any(|(p, _)| p.is_infinity())
+            || self.key_image.is_infinity() || !in_subgroup(&self.key_image) || !in_subgroup(&self.commitment_image) {
+            return false
+        }
+        let prefix = ring_bytes(ring, pseudo_output);
+        let mu = aggregation_coefficients(&prefix, &self.key_image, &self.commitment_image);
+        let (keys, hashed, image) = aggregate(ring, pseudo_output, mu, &self.key_image, &self.commitment_image);
+        sag::verify(challenge(&prefix, msg), &keys, &hashed, &image, self.challenge, &self.responses)
+    }
+    ///Whether both signatures were made with the same secret key, in any rings
+    pub fn is_linked(&self, other: &Self) -> bool {
+        self.key_image == other.key_image
+    }
+}
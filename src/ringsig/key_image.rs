@@ -0,0 +1,18 @@
+use crate::{types::{ECpoint, Scalar, Generator, EC}, utils::hash_to_point};
+
+///Hp(P), the public key hashed to a point with hash_to_point, so nobody knows its
+///discrete log with respect to G
+pub fn hash_point<E: EC>(public: &ECpoint<E>) -> ECpoint<E> {
+    hash_to_point(&[b"ring signature key image".as_slice(), &public.to_sec1(true)].concat())
+}
+
+///I = x * Hp(x * G), the same for every signature made with x whatever the ring
+pub fn key_image<E: Generator>(secret: Scalar<E>) -> ECpoint<E> {
+    hash_point(&(E::generator() * secret)) * secret
+}
+
+///Whether the point is in the subgroup of order N, so a signer cannot make fresh
+///images by adding points of small order on curves with a cofactor
+pub(crate) fn in_subgroup<E: EC>(point: &ECpoint<E>) -> bool {
+    point.mul_integer(E::N).is_infinity()
+}
@@ -0,0 +1,41 @@
+use crate::{ringsig::{hash_point, key_image::in_subgroup, sag}, types::{ECpoint, Scalar, Generator, EC}, utils::hash_to_scalar};
+
+///Linkable spontaneous anonymous group signature, the challenge c_0, one response
+///per ring member and the key image of the signer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LsagSignature<E: EC> {
+    pub challenge: Scalar<E>,
+    pub responses: Vec<Scalar<E>>,
+    pub key_image: ECpoint<E>,
+}
+
+///H(ring || I || msg || L || R)
+fn challenge<E: EC>(ring: &[ECpoint<E>], key_image: &ECpoint<E>, msg: &[u8]) -> impl Fn(&ECpoint<E>, &ECpoint<E>) -> Scalar<E> {
+    let prefix = [ring.iter().flat_map(|p| p.to_sec1(true)).collect(), key_image.to_sec1(true), msg.to_vec()].concat();
+    move |l, r| hash_to_scalar(&[prefix.as_slice(), &l.to_sec1(true), &r.to_sec1(true)].concat(), b"LSAG challenge")
+}
+
+impl<E: Generator> LsagSignature<E> {
+    ///Signs msg as ring[index], None if index is out of the ring or ring[index] is
+    ///not secret * G
+    pub fn sign(msg: &[u8], ring: &[ECpoint<E>], index: usize, secret: Scalar<E>) -> Option<Self> {
+        if ring.get(index) != Some(&(E::generator() * secret)) || secret.is_zero() {
+            return None
+        }
+        let hashed = ring.iter().map(hash_point).collect::<Vec<_>>();
+        let key_image = hashed[index] * secret;
+        let (challenge, responses) = sag::sign(challenge(ring, &key_image, msg), ring, &hashed, &key_image, index, secret);
+        Some(Self { challenge, responses, key_image })
+    }
+    pub fn verify(&self, msg: &[u8], ring: &[ECpoint<E>]) -> bool {
+        if ring.is_empty() || ring.iter().any(ECpoint::is_infinity) || self.key_image.is_infinity() || !in_subgroup(&self.key_image) {
+            return false
+        }
+        let hashed = ring.iter().map(hash_point).collect::<Vec<_>>();
+        sag::verify(challenge(ring, &self.key_image, msg), ring, &hashed, &self.key_image, self.challenge, &self.responses)
+    }
+    ///Whether both signatures were made with the same secret key, in any rings
+    pub fn is_linked(&self, other: &Self) -> bool {
+        self.key_image == other.key_image
+    }
+}
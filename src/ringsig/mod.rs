@@ -0,0 +1,13 @@
+//!Linkable ring signatures: a member of a ring of public keys signs without revealing
+//!which one, and every signature of the same key carries the same key image
+//!I = x * Hp(P), so signing twice, e.g. spending the same output twice, is detected.
+//!LSAG (Liu, Wei, Wong 2004, as in Monero's MLSAG) and CLSAG (Goodell, Noether, Blue
+//!2019), which also proves the signer's amount commitment balances a pseudo output.
+pub use key_image::{hash_point, key_image};
+pub use lsag::LsagSignature;
+pub use clsag::ClsagSignature;
+
+mod key_image;
+mod sag;
+mod lsag;
+mod clsag;
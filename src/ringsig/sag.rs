@@ -0,0 +1,49 @@
+use crate::{types::{ECpoint, Scalar, Generator}, utils::multiscalar_mul};
+
+///s * G + c * K and s * H + c * I
+fn step<E: Generator>(key: &ECpoint<E>, hashed: &ECpoint<E>, image: &ECpoint<E>, c: Scalar<E>, s: Scalar<E>) -> (ECpoint<E>, ECpoint<E>) {
+    (multiscalar_mul(&[s, c], &[E::generator(), *key]), multiscalar_mul(&[s, c], &[*hashed, *image]))
+}
+
+///Closes the ring at the signer: starts with c_(pi+1) = challenge(a * G, a * H_pi),
+///goes around with c_(i+1) = challenge(s_i * G + c_i * K_i, s_i * H_i + c_i * I) for
+///random s_i and sets s_pi = a - c_pi * k. Returns c_0 and the responses.
+pub(crate) fn sign<E: Generator>(
+    challenge: impl Fn(&ECpoint<E>, &ECpoint<E>) -> Scalar<E>,
+    keys: &[ECpoint<E>],
+    hashed: &[ECpoint<E>],
+    image: &ECpoint<E>,
+    index: usize,
+    secret: Scalar<E>,
+) -> (Scalar<E>, Vec<Scalar<E>>) {
+    let n = keys.len();
+    let alpha = Scalar::generate_secret();
+    let (mut challenges, mut responses) = (vec![Scalar::zero(); n], vec![Scalar::zero(); n]);
+    challenges[(index + 1) % n] = challenge(&(E::generator() * alpha), &(hashed[index] * alpha));
+    for i in (index + 1..index + n).map(|i| i % n) {
+        responses[i] = Scalar::generate_secret();
+        let (l, r) = step(&keys[i], &hashed[i], image, challenges[i], responses[i]);
+        challenges[(i + 1) % n] = challenge(&l, &r);
+    }
+    responses[index] = alpha - challenges[index] * secret;
+    (challenges[0], responses)
+}
+
+///Goes around the ring from c_0 and checks it comes back to c_0
+pub(crate) fn verify<E: Generator>(
+    challenge: impl Fn(&ECpoint<E>, &ECpoint<E>) -> Scalar<E>,
+    keys: &[ECpoint<E>],
+    hashed: &[ECpoint<E>],
+    image: &ECpoint<E>,
+    c0: Scalar<E>,
+    responses: &[Scalar<E>],
+) -> bool {
+    if responses.len() != keys.len() {
+        return false
+    }
+    let c = keys.iter().zip(hashed).zip(responses).fold(c0, |c, ((key, hashed), &s)| {
+        let (l, r) = step(key, hashed, image, c, s);
+        challenge(&l, &r)
+    });
+    c == c0
+}
@@ -0,0 +1,58 @@
+use crate::{curves::Secp256k1, types::{ECpoint, Scalar, U256, Generator, EC}, utils::tagged_hash};
+
+///Big endian x coordinate
+pub(crate) fn x_bytes(point: &ECpoint<Secp256k1>) -> [u8; 32] {
+    point.to_sec1(true)[1..].try_into().unwrap()
+}
+
+///int(hash) mod N
+pub(crate) fn hash_to_scalar(hash: [u8; 32]) -> Scalar<Secp256k1> {
+    Scalar::new(U256::from_big_endian(&hash))
+}
+
+///e = H_BIP0340/challenge(R.x || P.x || msg)
+pub(crate) fn challenge(r: &[u8; 32], public_key: &[u8; 32], msg: &[u8]) -> Scalar<Secp256k1> {
+    hash_to_scalar(tagged_hash(b"BIP0340/challenge", &[r.as_slice(), public_key, msg].concat()))
+}
+
+///x-only public key of the secret, (d * G).x
+pub fn public_key(secret: Scalar<Secp256k1>) -> [u8; 32] {
+    x_bytes(&(Secp256k1::generator() * secret))
+}
+
+///Signs the message with the deterministic nonce of BIP340, aux_rand is mixed into
+///the nonce as protection against side channels. The secret is negated if its
+///public key has an odd y. None for a zero secret.
+pub fn sign(secret: Scalar<Secp256k1>, msg: &[u8], aux_rand: &[u8; 32]) -> Option<[u8; 64]> {
+    if secret.is_zero() {
+        return None
+    }
+    let g = Secp256k1::generator();
+    let public = g * secret;
+    let d = if public.has_even_y() { secret } else { -secret };
+    let p = x_bytes(&public);
+    let mask = tagged_hash(b"BIP0340/aux", aux_rand);
+    let t = d.to_be_bytes().iter().zip(mask).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+    let k = hash_to_scalar(tagged_hash(b"BIP0340/nonce", &[t.as_slice(), &p, msg].concat()));
+    if k.is_zero() {
+        return None
+    }
+    let r = g * k;
+    let k = if r.has_even_y() { k } else { -k };
+    let r = x_bytes(&r);
+    let s = k + challenge(&r, &p, msg) * d;
+    Some([r, s.to_be_bytes().try_into().unwrap()].concat().try_into().unwrap())
+}
+
+///Checks s * G - e * P is a point with an even y and the x coordinate r
+pub fn verify(public_key: &[u8; 32], msg: &[u8], signature: &[u8; 64]) -> bool {
+    let Some(p) = ECpoint::<Secp256k1>::lift_x(U256::from_big_endian(public_key)) else {
+        return false
+    };
+    let r: [u8; 32] = signature[..32].try_into().unwrap();
+    let (Some(s), true) = (Scalar::from_be_bytes(&signature[32..]), U256::from_big_endian(&r) < Secp256k1::P) else {
+        return false
+    };
+    let point = Secp256k1::generator() * s - p * challenge(&r, public_key, msg);
+    !point.is_infinity() && point.has_even_y() && x_bytes(&point) == r
+}
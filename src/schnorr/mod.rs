@@ -0,0 +1,5 @@
+//!BIP340 Schnorr signatures over secp256k1. Public keys are x-only, the 32 byte x
+//!coordinate of the point with an even y, and signatures are R.x || s.
+pub use bip340::{public_key, sign, verify};
+
+pub(crate) mod bip340;
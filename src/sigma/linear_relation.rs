@@ -0,0 +1,64 @@
+use crate::{types::{ECpoint, Scalar, EC}, utils::multiscalar_mul};
+
+///image = sum of x_k * base over the (k, base) terms
+pub type Equation<E> = (ECpoint<E>, Vec<(usize, ECpoint<E>)>);
+
+///A statement about a witness x_0..x_m-1 of scalars, given as a linear map over the
+///group: every equation says image = sum of x_k * base_k. Discrete logs, DLEQ,
+///Pedersen openings, ElGamal plaintexts and any mix sharing witnesses fit this form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearRelation<E: EC> {
+    witness_len: usize,
+    equations: Vec<Equation<E>>,
+}
+
+impl<E: EC> LinearRelation<E> {
+    ///A relation over witness_len scalars, without equations yet
+    pub fn new(witness_len: usize) -> Self {
+        Self { witness_len, equations: vec![] }
+    }
+    ///Adds the equation image = sum of x_k * base for the (k, base) terms.
+    ///Panics if a witness index is out of range.
+    pub fn add_equation(&mut self, image: ECpoint<E>, terms: &[(usize, ECpoint<E>)]) -> &mut Self {
+        assert!(terms.iter().all(|&(k, _)| k < self.witness_len), "witness index out of range");
+        self.equations.push((image, terms.to_vec()));
+        self
+    }
+    ///P = x * G
+    pub fn discrete_log(g: ECpoint<E>, p: ECpoint<E>) -> Self {
+        let mut relation = Self::new(1);
+        relation.add_equation(p, &[(0, g)]);
+        relation
+    }
+    ///A = x * G and B = x * H
+    pub fn dleq(g: ECpoint<E>, a: ECpoint<E>, h: ECpoint<E>, b: ECpoint<E>) -> Self {
+        let mut relation = Self::new(1);
+        relation.add_equation(a, &[(0, g)]).add_equation(b, &[(0, h)]);
+        relation
+    }
+    ///C = v * G + r * H, the witness is (v, r)
+    pub fn pedersen_opening(g: ECpoint<E>, h: ECpoint<E>, c: ECpoint<E>) -> Self {
+        let mut relation = Self::new(2);
+        relation.add_equation(c, &[(0, g), (1, h)]);
+        relation
+    }
+    pub fn witness_len(&self) -> usize {
+        self.witness_len
+    }
+    pub fn equations(&self) -> &[Equation<E>] {
+        &self.equations
+    }
+    ///The linear map applied to x, one point per equation
+    pub fn evaluate(&self, x: &[Scalar<E>]) -> Vec<ECpoint<E>> {
+        self.equations.iter()
+            .map(|(_, terms)| {
+                let scalars = terms.iter().map(|&(k, _)| x[k]).collect::<Vec<_>>();
+                let bases = terms.iter().map(|&(_, base)| base).collect::<Vec<_>>();
+                multiscalar_mul(&scalars, &bases)
+            })
+            .collect()
+    }
+    pub fn is_satisfied_by(&self, x: &[Scalar<E>]) -> bool {
+        x.len() == self.witness_len && self.evaluate(x).iter().zip(&self.equations).all(|(y, (image, _))| y == image)
+    }
+}
@@ -0,0 +1,11 @@
+//!Sigma protocols for statements given as linear maps over the group, composed with
+//!AND and OR. Proving and verifying follow from the statement: commitments of random
+//!nonces, a Fiat-Shamir challenge from the transcript and linear responses, OR
+//!branches without a witness are simulated.
+pub use linear_relation::{Equation, LinearRelation};
+pub use statement::{Statement, Witness};
+pub use proof::{Proof, Response};
+
+mod linear_relation;
+mod statement;
+mod proof;
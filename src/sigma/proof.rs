@@ -0,0 +1,18 @@
+use crate::types::{ECpoint, Scalar, EC};
+
+///The response of the prover, shaped like the statement. An OR carries the challenge
+///of every branch, they add up to the challenge of the proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response<E: EC> {
+    Linear(Vec<Scalar<E>>),
+    And(Vec<Response<E>>),
+    Or(Vec<(Scalar<E>, Response<E>)>),
+}
+
+///Non-interactive sigma protocol proof, the commitments of all linear relations in
+///statement order and the response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proof<E: EC> {
+    pub commitments: Vec<ECpoint<E>>,
+    pub response: Response<E>,
+}
@@ -0,0 +1,192 @@
+use crate::{proofs::Transcript, sigma::{LinearRelation, Proof, Response}, types::{ECpoint, Scalar, EC}};
+
+///A statement to prove knowledge of a witness for, a linear relation or an AND / OR
+///composition of statements
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement<E: EC> {
+    Linear(LinearRelation<E>),
+    ///every statement holds
+    And(Vec<Statement<E>>),
+    ///at least one statement holds, the proof does not reveal which
+    Or(Vec<Statement<E>>),
+}
+
+///The secret the prover knows, shaped like the statement. For an OR only the witness
+///of one branch, with its index, is needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Witness<E: EC> {
+    Linear(Vec<Scalar<E>>),
+    And(Vec<Witness<E>>),
+    Or(usize, Box<Witness<E>>),
+}
+
+///What the prover remembers between the commitment and the response
+enum ProverState<E: EC> {
+    Linear(Vec<Scalar<E>>),
+    And(Vec<ProverState<E>>),
+    ///the real branch and the simulated challenges and responses of the others
+    Or(Box<ProverState<E>>, Vec<Option<(Scalar<E>, Response<E>)>>),
+}
+
+impl<E: EC> Statement<E> {
+    ///Number of points in the first message of the prover
+    pub fn num_commitments(&self) -> usize {
+        match self {
+            Statement::Linear(relation) => relation.equations().len(),
+            Statement::And(statements) | Statement::Or(statements) => statements.iter().map(Self::num_commitments).sum()
+        }
+    }
+    ///Absorbs the structure, every base and every image
+    fn absorb(&self, transcript: &mut Transcript) {
+        match self {
+            Statement::Linear(relation) => {
+                transcript.append_u64(b"linear", relation.witness_len() as u64);
+                for (image, terms) in relation.equations() {
+                    transcript.append_point(b"image", image);
+                    for (k, base) in terms {
+                        transcript.append_u64(b"index", *k as u64);
+                        transcript.append_point(b"base", base);
+                    }
+                }
+            },
+            Statement::And(statements) | Statement::Or(statements) => {
+                let label: &[u8] = if matches!(self, Statement::And(_)) { b"and" } else { b"or" };
+                transcript.append_u64(label, statements.len() as u64);
+                statements.iter().for_each(|s| s.absorb(transcript));
+            }
+        }
+    }
+    ///First move, None if the witness does not fit the statement
+    fn commit(&self, witness: &Witness<E>) -> Option<(Vec<ECpoint<E>>, ProverState<E>)> {
+        match (self, witness) {
+            (Statement::Linear(relation), Witness::Linear(x)) if relation.is_satisfied_by(x) => {
+                let nonces = (0..x.len()).map(|_| Scalar::generate_secret()).collect::<Vec<_>>();
+                Some((relation.evaluate(&nonces), ProverState::Linear(nonces)))
+            },
+            (Statement::And(statements), Witness::And(witnesses)) if statements.len() == witnesses.len() => {
+                let (commitments, states): (Vec<_>, Vec<_>) = statements.iter().zip(witnesses)
+                    .map(|(s, w)| s.commit(w))
+                    .collect::<Option<Vec<_>>>()?
+                    .into_iter()
+                    .unzip();
+                Some((commitments.concat(), ProverState::And(states)))
+            },
+            (Statement::Or(statements), Witness::Or(index, w)) if *index < statements.len() => {
+                let (mut commitments, mut simulated, mut real) = (vec![], vec![], None);
+                for (i, s) in statements.iter().enumerate() {
+                    if i == *index {
+                        let (c, state) = s.commit(w)?;
+                        commitments.extend(c);
+                        simulated.push(None);
+                        real = Some(state);
+                    } else {
+                        let challenge = Scalar::generate_secret();
+                        let (c, response) = s.simulate(challenge);
+                        commitments.extend(c);
+                        simulated.push(Some((challenge, response)));
+                    }
+                }
+                Some((commitments, ProverState::Or(Box::new(real?), simulated)))
+            },
+            _ => None
+        }
+    }
+    ///Third move, the response to the challenge
+    fn respond(&self, witness: &Witness<E>, state: ProverState<E>, challenge: Scalar<E>) -> Response<E> {
+        match (self, witness, state) {
+            (Statement::Linear(_), Witness::Linear(x), ProverState::Linear(nonces)) => {
+                Response::Linear(nonces.iter().zip(x).map(|(&r, &x)| r + challenge * x).collect())
+            },
+            (Statement::And(statements), Witness::And(witnesses), ProverState::And(states)) => {
+                Response::And(statements.iter().zip(witnesses).zip(states).map(|((s, w), st)| s.respond(w, st, challenge)).collect())
+            },
+            (Statement::Or(statements), Witness::Or(_, w), ProverState::Or(state, simulated)) => {
+                //the real branch gets what is left of the challenge
+                let real_challenge = challenge - simulated.iter().flatten().map(|(c, _)| *c).sum();
+                let mut state = Some(*state);
+                Response::Or(simulated.into_iter().enumerate()
+                    .map(|(i, sim)| sim.unwrap_or_else(|| (real_challenge, statements[i].respond(w, state.take().unwrap(), real_challenge))))
+                    .collect())
+            },
+            _ => unreachable!("the state was created by commit for the same statement and witness")
+        }
+    }
+    ///A transcript with the given challenge that verifies without a witness, the
+    ///special honest verifier zero knowledge simulator
+    fn simulate(&self, challenge: Scalar<E>) -> (Vec<ECpoint<E>>, Response<E>) {
+        match self {
+            Statement::Linear(relation) => {
+                let s = (0..relation.witness_len()).map(|_| Scalar::generate_secret()).collect::<Vec<_>>();
+                let commitments = relation.evaluate(&s).into_iter()
+                    .zip(relation.equations())
+                    .map(|(t, (image, _))| t - *image * challenge)
+                    .collect();
+                (commitments, Response::Linear(s))
+            },
+            Statement::And(statements) => {
+                let (commitments, responses): (Vec<_>, Vec<_>) = statements.iter().map(|s| s.simulate(challenge)).unzip();
+                (commitments.concat(), Response::And(responses))
+            },
+            Statement::Or(statements) => {
+                let mut challenges = (1..statements.len()).map(|_| Scalar::generate_secret()).collect::<Vec<_>>();
+                challenges.push(challenge - challenges.iter().copied().sum());
+                let (commitments, responses): (Vec<_>, Vec<_>) = statements.iter().zip(&challenges)
+                    .map(|(s, &c)| {
+                        let (commitments, response) = s.simulate(c);
+                        (commitments, (c, response))
+                    })
+                    .unzip();
+                (commitments.concat(), Response::Or(responses))
+            }
+        }
+    }
+    ///Checks the transcript (commitments, challenge, response)
+    fn check(&self, commitments: &[ECpoint<E>], challenge: Scalar<E>, response: &Response<E>) -> bool {
+        if commitments.len() != self.num_commitments() {
+            return false
+        }
+        match (self, response) {
+            (Statement::Linear(relation), Response::Linear(s)) if s.len() == relation.witness_len() => {
+                relation.evaluate(s).into_iter()
+                    .zip(relation.equations())
+                    .zip(commitments)
+                    .all(|((lhs, (image, _)), &t)| lhs == t + *image * challenge)
+            },
+            (Statement::And(statements), Response::And(responses)) if statements.len() == responses.len() => {
+                let mut offset = 0;
+                statements.iter().zip(responses).all(|(s, r)| {
+                    offset += s.num_commitments();
+                    s.check(&commitments[offset - s.num_commitments()..offset], challenge, r)
+                })
+            },
+            (Statement::Or(statements), Response::Or(responses)) if statements.len() == responses.len() => {
+                let mut offset = 0;
+                responses.iter().map(|(c, _)| *c).sum::<Scalar<E>>() == challenge
+                    && statements.iter().zip(responses).all(|(s, (c, r))| {
+                        offset += s.num_commitments();
+                        s.check(&commitments[offset - s.num_commitments()..offset], *c, r)
+                    })
+            },
+            _ => false
+        }
+    }
+    ///Non-interactive proof with the Fiat-Shamir transform, the challenge is derived
+    ///from the transcript after absorbing the statement and the commitments.
+    ///None if the witness does not satisfy the statement.
+    pub fn prove(&self, transcript: &mut Transcript, witness: &Witness<E>) -> Option<Proof<E>> {
+        let (commitments, state) = self.commit(witness)?;
+        let challenge = self.challenge(transcript, &commitments);
+        let response = self.respond(witness, state, challenge);
+        Some(Proof { commitments, response })
+    }
+    pub fn verify(&self, transcript: &mut Transcript, proof: &Proof<E>) -> bool {
+        let challenge = self.challenge(transcript, &proof.commitments);
+        self.check(&proof.commitments, challenge, &proof.response)
+    }
+    fn challenge(&self, transcript: &mut Transcript, commitments: &[ECpoint<E>]) -> Scalar<E> {
+        transcript.append_message(b"proof", b"sigma");
+        self.absorb(transcript);
+        commitments.iter().for_each(|t| transcript.append_point(b"commitment", t));
+        transcript.challenge_scalar(b"c")
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{sss::{shamir::{shares_of, valid_threshold}, Polynomial, Share}, types::{ECpoint, Scalar, Generator, EC}};
+
+///Evaluates the committed polynomial in the exponent, the sum of C_j * index^j
+pub(crate) fn evaluate_commitments<E: EC>(commitments: &[ECpoint<E>], index: Scalar<E>) -> ECpoint<E> {
+    commitments.iter().rev().fold(ECpoint::Infinity, |acc, &c| acc * index + c)
+}
+
+///Feldman's verifiable secret sharing. Like split, but also returns the commitments
+///a_j * G to the coefficients, so every share can be checked against them. The first
+///commitment is secret * G, the secret is only computationally hidden.
+#[allow(clippy::type_complexity)]
+pub fn feldman_split<E: Generator>(secret: Scalar<E>, t: usize, n: usize) -> Option<(Vec<Share<E>>, Vec<ECpoint<E>>)> {
+    if !valid_threshold::<E>(t, n) {
+        return None
+    }
+    let polynomial = Polynomial::random(secret, t - 1);
+    let g = E::generator();
+    let commitments = polynomial.coefficients().iter().map(|&a| g * a).collect();
+    Some((shares_of(&polynomial, n), commitments))
+}
+
+///Checks value * G == sum of C_j * index^j
+pub fn feldman_verify<E: Generator>(share: &Share<E>, commitments: &[ECpoint<E>]) -> bool {
+    E::generator() * share.value == evaluate_commitments(commitments, share.index)
+}
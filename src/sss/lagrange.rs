@@ -0,0 +1,31 @@
+use crate::types::{Scalar, EC};
+
+///Lagrange basis polynomial of x_i over the points xs, evaluated at x:
+///the product of (x - x_j) / (x_i - x_j) for all x_j != x_i.
+///None if xs contains a duplicate of x_i.
+pub fn lagrange_coefficient<E: EC>(x_i: Scalar<E>, xs: &[Scalar<E>], x: Scalar<E>) -> Option<Scalar<E>> {
+    let (mut numerator, mut denominator) = (Scalar::one(), Scalar::one());
+    let mut seen = false;
+    for &x_j in xs {
+        if x_j == x_i {
+            //x_i itself has to be in xs exactly once
+            if seen {
+                return None
+            }
+            seen = true;
+            continue
+        }
+        numerator *= x - x_j;
+        denominator *= x_i - x_j;
+    }
+    Some(numerator / denominator)
+}
+
+///Evaluates at x the unique polynomial of degree < points.len() going through
+///the points (x_i, y_i). None if two points share the same x_i.
+pub fn lagrange_interpolate<E: EC>(points: &[(Scalar<E>, Scalar<E>)], x: Scalar<E>) -> Option<Scalar<E>> {
+    let xs = points.iter().map(|&(x_i, _)| x_i).collect::<Vec<_>>();
+    points.iter().try_fold(Scalar::zero(), |acc, &(x_i, y_i)| {
+        Some(acc + y_i * lagrange_coefficient(x_i, &xs, x)?)
+    })
+}
@@ -0,0 +1,17 @@
+//!Threshold secret sharing over Scalar<E>: Shamir's scheme, and the verifiable
+//!variants of Feldman and Pedersen where the dealer commits to the polynomial so
+//!shareholders can check their shares.
+pub use polynomial::Polynomial;
+pub use lagrange::{lagrange_coefficient, lagrange_interpolate};
+pub use share::{PedersenShare, Share};
+pub use shamir::{combine, split};
+pub use feldman::{feldman_split, feldman_verify};
+pub(crate) use feldman::evaluate_commitments;
+pub use pedersen::{pedersen_split, pedersen_verify};
+
+mod polynomial;
+mod lagrange;
+mod share;
+mod shamir;
+mod feldman;
+mod pedersen;
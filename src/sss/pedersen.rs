@@ -0,0 +1,28 @@
+use crate::{sss::{feldman::evaluate_commitments, shamir::valid_threshold, PedersenShare, Polynomial, Share}, types::{ECpoint, Scalar, Generator}};
+
+///Pedersen's verifiable secret sharing. The coefficients a_j of the secret polynomial
+///are committed as a_j * G + b_j * H with a random blinding polynomial, which hides
+///the secret perfectly. H has to be a generator nobody knows the discrete log of,
+///e.g. from utils::hash_to_point.
+#[allow(clippy::type_complexity)]
+pub fn pedersen_split<E: Generator>(secret: Scalar<E>, t: usize, n: usize, h: ECpoint<E>) -> Option<(Vec<PedersenShare<E>>, Vec<ECpoint<E>>)> {
+    if !valid_threshold::<E>(t, n) {
+        return None
+    }
+    let f = Polynomial::random(secret, t - 1);
+    let g = Polynomial::random(Scalar::generate_secret(), t - 1);
+    let generator = E::generator();
+    let commitments = f.coefficients().iter().zip(g.coefficients())
+        .map(|(&a, &b)| generator * a + h * b)
+        .collect();
+    let shares = (1..=n as u64)
+        .map(Scalar::new)
+        .map(|i| PedersenShare { share: Share { index: i, value: f.evaluate(i) }, blinding: g.evaluate(i) })
+        .collect();
+    Some((shares, commitments))
+}
+
+///Checks value * G + blinding * H == sum of C_j * index^j
+pub fn pedersen_verify<E: Generator>(share: &PedersenShare<E>, commitments: &[ECpoint<E>], h: ECpoint<E>) -> bool {
+    E::generator() * share.share.value + h * share.blinding == evaluate_commitments(commitments, share.share.index)
+}
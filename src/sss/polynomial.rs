@@ -0,0 +1,28 @@
+use crate::types::{Scalar, EC};
+
+///Polynomial a_0 + a_1 * x + ... + a_t-1 * x^(t-1) over Scalar<E>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial<E: EC> {
+    coefficients: Vec<Scalar<E>>,
+}
+
+impl<E: EC> Polynomial<E> {
+    ///Coefficients from a_0 up
+    pub fn new(coefficients: Vec<Scalar<E>>) -> Self {
+        Self { coefficients }
+    }
+    ///Polynomial of the given degree with a_0 = constant and random other coefficients
+    pub fn random(constant: Scalar<E>, degree: usize) -> Self {
+        let coefficients = std::iter::once(constant)
+            .chain((0..degree).map(|_| Scalar::generate_secret()))
+            .collect();
+        Self { coefficients }
+    }
+    pub fn coefficients(&self) -> &[Scalar<E>] {
+        &self.coefficients
+    }
+    ///Evaluates the polynomial at x using Horner's method
+    pub fn evaluate(&self, x: Scalar<E>) -> Scalar<E> {
+        self.coefficients.iter().rev().fold(Scalar::zero(), |acc, &a| acc * x + a)
+    }
+}
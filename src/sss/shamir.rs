@@ -0,0 +1,34 @@
+use crate::{sss::{lagrange_interpolate, Polynomial, Share}, types::{Scalar, EC}};
+
+///Evaluates the polynomial at the indices 1..=n
+pub(crate) fn shares_of<E: EC>(polynomial: &Polynomial<E>, n: usize) -> Vec<Share<E>> {
+    (1..=n as u64)
+        .map(|i| Share { index: Scalar::new(i), value: polynomial.evaluate(Scalar::new(i)) })
+        .collect()
+}
+
+///Checks 1 <= t <= n < N, so the indices 1..=n are distinct non zero scalars
+pub(crate) fn valid_threshold<E: EC>(t: usize, n: usize) -> bool {
+    t >= 1 && t <= n && E::N > n.into()
+}
+
+///Shamir's secret sharing, splits the secret into n shares, any t of them recover
+///it and fewer reveal nothing. Share i is f(i) of a random polynomial of degree
+///t - 1 with f(0) = secret. None unless 1 <= t <= n < N.
+pub fn split<E: EC>(secret: Scalar<E>, t: usize, n: usize) -> Option<Vec<Share<E>>> {
+    if !valid_threshold::<E>(t, n) {
+        return None
+    }
+    Some(shares_of(&Polynomial::random(secret, t - 1), n))
+}
+
+///Recovers f(0) from the shares by Lagrange interpolation. With fewer than t shares
+///the result is a random looking scalar, not the secret. None if there are no
+///shares or two share the same index.
+pub fn combine<E: EC>(shares: &[Share<E>]) -> Option<Scalar<E>> {
+    if shares.is_empty() {
+        return None
+    }
+    let points = shares.iter().map(|s| (s.index, s.value)).collect::<Vec<_>>();
+    lagrange_interpolate(&points, Scalar::zero())
+}
@@ -0,0 +1,16 @@
+use crate::types::{Scalar, EC};
+
+///Share of a secret, the secret polynomial f evaluated at a non zero index: value = f(index)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Share<E: EC> {
+    pub index: Scalar<E>,
+    pub value: Scalar<E>,
+}
+
+///Share of Pedersen VSS, the secret polynomial f and the blinding polynomial g
+///evaluated at the same index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PedersenShare<E: EC> {
+    pub share: Share<E>,
+    pub blinding: Scalar<E>,
+}
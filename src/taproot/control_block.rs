@@ -0,0 +1,58 @@
+use crate::{curves::Secp256k1, schnorr::bip340::x_bytes, taproot::{tap_branch_hash, tweak_public_key, TapLeaf, TapTree}, types::{ECpoint, U256}};
+
+///Witness element of a script path spend: the leaf version with the parity of the
+///output key in the lowest bit, the internal key and the merkle path of the leaf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlBlock {
+    pub leaf_version: u8,
+    pub output_key_parity: bool,
+    pub internal_key: [u8; 32],
+    pub merkle_path: Vec<[u8; 32]>,
+}
+
+impl ControlBlock {
+    ///Control block to spend the leaf of the tree committed to by the internal key.
+    ///None if the leaf is not in the tree or the tweak fails.
+    pub fn new(internal_key: &ECpoint<Secp256k1>, tree: &TapTree, leaf: &TapLeaf) -> Option<Self> {
+        let merkle_path = tree.merkle_path(leaf)?;
+        let output_key = tweak_public_key(internal_key, Some(&tree.merkle_root()))?;
+        Some(Self {
+            leaf_version: leaf.version,
+            output_key_parity: !output_key.has_even_y(),
+            internal_key: x_bytes(internal_key),
+            merkle_path,
+        })
+    }
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let first = self.leaf_version | self.output_key_parity as u8;
+        [&[first], self.internal_key.as_slice(), &self.merkle_path.concat()].concat()
+    }
+    ///33 + 32m bytes with at most 128 path elements and an internal key on the curve
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 33 || !(bytes.len() - 33).is_multiple_of(32) || (bytes.len() - 33) / 32 > 128 {
+            return None
+        }
+        ECpoint::<Secp256k1>::lift_x(U256::from_big_endian(&bytes[1..33]))?;
+        Some(Self {
+            leaf_version: bytes[0] & 0xfe,
+            output_key_parity: bytes[0] & 1 == 1,
+            internal_key: bytes[1..33].try_into().unwrap(),
+            merkle_path: bytes[33..].chunks(32).map(|c| c.try_into().unwrap()).collect(),
+        })
+    }
+    ///The script path check of BIP341: the merkle root rebuilt from the leaf and the
+    ///path, tweaked into the internal key, gives the output key with the stated parity
+    pub fn verify(&self, output_key: &[u8; 32], leaf: &TapLeaf) -> bool {
+        if leaf.version != self.leaf_version {
+            return false
+        }
+        let merkle_root = self.merkle_path.iter().fold(leaf.hash(), |k, e| tap_branch_hash(&k, e));
+        let Some(internal_key) = ECpoint::lift_x(U256::from_big_endian(&self.internal_key)) else {
+            return false
+        };
+        match tweak_public_key(&internal_key, Some(&merkle_root)) {
+            Some(q) => x_bytes(&q) == *output_key && q.has_even_y() != self.output_key_parity,
+            None => false
+        }
+    }
+}
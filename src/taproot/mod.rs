@@ -0,0 +1,11 @@
+//!BIP341 taproot helpers on secp256k1: tweaking an internal key P into the output key
+//!Q = P + H_TapTweak(P.x || merkle_root) * G and the matching secret key, the
+//!TapLeaf/TapBranch tagged hashes of a script tree and its merkle root, and the
+//!control block that proves a leaf is committed to in the output key.
+pub use tweak::{tap_tweak, tweak_public_key, tweak_secret_key};
+pub use tree::{tap_branch_hash, TapLeaf, TapTree, TAPSCRIPT_LEAF_VERSION};
+pub use control_block::ControlBlock;
+
+mod tweak;
+mod tree;
+mod control_block;
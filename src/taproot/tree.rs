@@ -0,0 +1,67 @@
+use crate::utils::tagged_hash;
+
+///Leaf version of BIP342 tapscript
+pub const TAPSCRIPT_LEAF_VERSION: u8 = 0xc0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TapLeaf {
+    pub version: u8,
+    pub script: Vec<u8>,
+}
+
+impl TapLeaf {
+    ///Tapscript leaf
+    pub fn new(script: Vec<u8>) -> Self {
+        Self { version: TAPSCRIPT_LEAF_VERSION, script }
+    }
+    ///H_TapLeaf(version || compact_size(script) || script)
+    pub fn hash(&self) -> [u8; 32] {
+        let len = self.script.len();
+        let compact_size = match len {
+            0..0xfd => vec![len as u8],
+            0xfd..=0xffff => [&[0xfd], (len as u16).to_le_bytes().as_slice()].concat(),
+            _ => [&[0xfe], (len as u32).to_le_bytes().as_slice()].concat(),
+        };
+        tagged_hash(b"TapLeaf", &[&[self.version], compact_size.as_slice(), &self.script].concat())
+    }
+}
+
+///H_TapBranch of the two child hashes in lexicographic order
+pub fn tap_branch_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    tagged_hash(b"TapBranch", &[first.as_slice(), second].concat())
+}
+
+///Binary tree of scripts, a leaf or a branch of two subtrees
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TapTree {
+    Leaf(TapLeaf),
+    Branch(Box<TapTree>, Box<TapTree>),
+}
+
+impl TapTree {
+    pub fn branch(left: TapTree, right: TapTree) -> Self {
+        Self::Branch(Box::new(left), Box::new(right))
+    }
+    pub fn merkle_root(&self) -> [u8; 32] {
+        match self {
+            Self::Leaf(leaf) => leaf.hash(),
+            Self::Branch(left, right) => tap_branch_hash(&left.merkle_root(), &right.merkle_root()),
+        }
+    }
+    ///Hashes of the siblings from the leaf up to the root, None if the leaf is not in
+    ///the tree
+    pub fn merkle_path(&self, leaf: &TapLeaf) -> Option<Vec<[u8; 32]>> {
+        match self {
+            Self::Leaf(l) => (l == leaf).then(Vec::new),
+            Self::Branch(left, right) => {
+                let (mut path, sibling) = match left.merkle_path(leaf) {
+                    Some(path) => (path, right),
+                    None => (right.merkle_path(leaf)?, left),
+                };
+                path.push(sibling.merkle_root());
+                Some(path)
+            }
+        }
+    }
+}
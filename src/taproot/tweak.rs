@@ -0,0 +1,31 @@
+use crate::{curves::Secp256k1, schnorr::bip340::x_bytes, types::{ECpoint, Scalar, Generator}, utils::tagged_hash};
+
+///t = int(H_TapTweak(P.x || merkle_root)), the merkle root is left out for key path
+///only outputs. None if the hash is not below N.
+pub fn tap_tweak(internal_key: &[u8; 32], merkle_root: Option<&[u8; 32]>) -> Option<Scalar<Secp256k1>> {
+    let hash = tagged_hash(b"TapTweak", &[internal_key.as_slice(), merkle_root.map_or(&[], |r| r.as_slice())].concat());
+    Scalar::from_be_bytes(&hash)
+}
+
+///Output key Q = P + t * G, with P the internal key lifted to an even y. The parity
+///of Q goes into the control block. None for infinity, an invalid tweak or Q = infinity.
+pub fn tweak_public_key(internal_key: &ECpoint<Secp256k1>, merkle_root: Option<&[u8; 32]>) -> Option<ECpoint<Secp256k1>> {
+    if internal_key.is_infinity() {
+        return None
+    }
+    let p = ECpoint::lift_x(internal_key.x().unwrap())?;
+    let q = p + Secp256k1::generator() * tap_tweak(&x_bytes(&p), merkle_root)?;
+    (!q.is_infinity()).then_some(q)
+}
+
+///Secret key of the output key, d + t where d is negated first if d * G has an odd y,
+///so that (d + t) * G = Q. None for a zero secret or result, or an invalid tweak.
+pub fn tweak_secret_key(secret: Scalar<Secp256k1>, merkle_root: Option<&[u8; 32]>) -> Option<Scalar<Secp256k1>> {
+    if secret.is_zero() {
+        return None
+    }
+    let p = Secp256k1::generator() * secret;
+    let d = if p.has_even_y() { secret } else { -secret };
+    let tweaked = d + tap_tweak(&x_bytes(&p), merkle_root)?;
+    (!tweaked.is_zero()).then_some(tweaked)
+}
@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+
+use crate::types::{field::tonelli_shanks, Field, Fp2, NonResidue, U256};
+
+///Cubic extension F[v] / (v^3 - NR), elements are c0 + c1 * v + c2 * v^2.
+///NR supplies the element v^3 equals to, it has to be a non-cube in F.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct CubicExtension<F: Field, NR: NonResidue<F>> {
+    c0: F,
+    c1: F,
+    c2: F,
+    _non_residue: PhantomData<NR>,
+}
+
+///Fp6, a cubic extension of Fp2
+pub type Fp6<E, NR2, NR6> = CubicExtension<Fp2<E, NR2>, NR6>;
+
+impl<F: Field, NR: NonResidue<F>> std::fmt::Display for CubicExtension<F, NR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}*v + {}*v^2)", self.c0, self.c1, self.c2)?;
+        Ok(())
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::fmt::Debug for CubicExtension<F, NR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [mod {}, v^3 = {}]", self, F::characteristic(), NR::non_residue())?;
+        Ok(())
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> Eq for CubicExtension<F, NR> {}
+
+impl<F: Field, NR: NonResidue<F>> std::hash::Hash for CubicExtension<F, NR> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.c0.hash(state);
+        self.c1.hash(state);
+        self.c2.hash(state);
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> CubicExtension<F, NR> {
+    pub fn new<T: Into<F>, U: Into<F>, V: Into<F>>(c0: T, c1: U, c2: V) -> Self {
+        CubicExtension { c0: c0.into(), c1: c1.into(), c2: c2.into(), _non_residue: PhantomData }
+    }
+    pub fn c0(&self) -> F {
+        self.c0
+    }
+    pub fn c1(&self) -> F {
+        self.c1
+    }
+    pub fn c2(&self) -> F {
+        self.c2
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> Field for CubicExtension<F, NR> {
+    const DEGREE: u32 = 3 * F::DEGREE;
+    fn zero() -> Self {
+        CubicExtension::new(F::zero(), F::zero(), F::zero())
+    }
+    fn one() -> Self {
+        CubicExtension::new(F::one(), F::zero(), F::zero())
+    }
+    fn characteristic() -> U256 {
+        F::characteristic()
+    }
+    fn inverse(&self) -> Option<Self> {
+        let xi = NR::non_residue();
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let t0 = a0.square() - xi * a1 * a2;
+        let t1 = xi * a2.square() - a0 * a1;
+        let t2 = a1.square() - a0 * a2;
+        let determinant = a0 * t0 + xi * (a2 * t1 + a1 * t2);
+        let determinant_inverse = determinant.inverse()?;
+        Some(CubicExtension::new(t0 * determinant_inverse, t1 * determinant_inverse, t2 * determinant_inverse))
+    }
+    fn frobenius(&self) -> Self {
+        //v^p is NR^((p - 1) / 3) * v if p = 1 mod 3, NR^((p - 2) / 3) * v^2 if p = 2 mod 3
+        let p = F::characteristic();
+        let v_p = match p % 3 == U256::one() {
+            true => CubicExtension::new(F::zero(), NR::non_residue().pow((p - 1) / 3), F::zero()),
+            false => CubicExtension::new(F::zero(), F::zero(), NR::non_residue().pow((p - 2) / 3)),
+        };
+        let (c0, c1, c2): (Self, Self, Self) = (self.c0.frobenius().into(), self.c1.frobenius().into(), self.c2.frobenius().into());
+        c0 + c1 * v_p + c2 * v_p.square()
+    }
+    fn sqrt(&self) -> Option<(Self, Self)> {
+        tonelli_shanks(self)
+    }
+    fn non_square() -> Self {
+        //the extension has odd degree over F, so non-squares of F stay non-squares
+        F::non_square().into()
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Add for CubicExtension<F, NR> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        CubicExtension::new(self.c0 + rhs.c0, self.c1 + rhs.c1, self.c2 + rhs.c2)
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::AddAssign for CubicExtension<F, NR> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Neg for CubicExtension<F, NR> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        CubicExtension::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Sub for CubicExtension<F, NR> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::SubAssign for CubicExtension<F, NR> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Mul for CubicExtension<F, NR> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let xi = NR::non_residue();
+        let (a0, a1, a2) = (self.c0, self.c1, self.c2);
+        let (b0, b1, b2) = (rhs.c0, rhs.c1, rhs.c2);
+        //v^3 = NR, v^4 = NR * v
+        CubicExtension::new(
+            a0 * b0 + xi * (a1 * b2 + a2 * b1),
+            a0 * b1 + a1 * b0 + xi * a2 * b2,
+            a0 * b2 + a1 * b1 + a2 * b0
+        )
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::MulAssign for CubicExtension<F, NR> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<F: Field, NR: NonResidue<F>> std::ops::Div for CubicExtension<F, NR> {
+    type Output = Self;
+    ///Warning, panics on division by zero!
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::convert::From<u64> for CubicExtension<F, NR> {
+    fn from(value: u64) -> Self {
+        CubicExtension::new(F::from(value), F::zero(), F::zero())
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::convert::From<F> for CubicExtension<F, NR> {
+    fn from(value: F) -> Self {
+        CubicExtension::new(value, F::zero(), F::zero())
+    }
+}
@@ -1,5 +1,4 @@
-use std::{fmt::{Debug, Display}, marker::PhantomData};
-use crate::{types::{ECpoint, U256}, utils::find_factors};
+use crate::types::{ECpoint, Field, Zp, U256};
 
 
 pub trait EC: PartialEq + Default + Copy {
@@ -13,7 +12,9 @@ pub trait EC: PartialEq + Default + Copy {
     const N: U256;
     ///P is the prime used in the Fp field the EC coordinates work in
     const P: U256;
-    ///Finds random point P where nP = 0 and n != 1
+    ///H is the cofactor, the number of curve points divided by N
+    const H: U256 = U256([1, 0, 0, 0]);
+    //Finds random point P where nP = 0 and n != 1
     // fn find_generator<E: EC>(&mut self) -> ECpoint<G, E> {
     //     //pick random x coordinate
     //     let mut x = [0;4];
@@ -30,7 +31,7 @@ pub trait EC: PartialEq + Default + Copy {
         let (mut count, mut x, mut y) = (U256::zero(), U256::zero(), U256::zero());
         while x != E::P  {
             while y != E::P  {
-                if let Some(_) = ECpoint::<E>::new(x, y) {
+                if ECpoint::<E>::new(x, y).is_some() {
                     count += U256::one();
                 }
                 y += U256::one();
@@ -49,6 +50,60 @@ pub trait EC: PartialEq + Default + Copy {
     // fn order_of_cyclic_subgroup<E: EC>(&self) -> U256; 
 }
 
+///A curve with a fixed generator G of the subgroup of order N, which is what
+///the key based protocols (ECDH, signatures, ...) build their public keys from
+pub trait Generator: EC {
+    ///x coordinate of the generator G
+    const GX: U256;
+    ///y coordinate of the generator G
+    const GY: U256;
+    ///Returns the generator G, panics if (GX, GY) is not on the curve
+    fn generator() -> ECpoint<Self> {
+        ECpoint::new(Self::GX, Self::GY).expect("generator is not on the curve")
+    }
+}
+
+///The curve E with coordinates in the field F, y^2 = x^3 + a * x + b where a and b
+///are elements of F. Every curve is defined over its own prime field Zp, implement
+///this trait to define E over an extension field. The coefficients do not have to
+///be the ones of E, that is how twists are defined, e.g. y^2 = x^3 + b / xi over Fp2.
+///Points over F still use Scalar<E>, so N should be the order of the subgroup used.
+pub trait CurveOver<F: Field>: EC {
+    fn a() -> F;
+    fn b() -> F;
+}
+
+impl<E: EC> CurveOver<Zp<E>> for E {
+    fn a() -> Zp<E> {
+        Zp::new(E::A)
+    }
+    fn b() -> Zp<E> {
+        Zp::new(E::B)
+    }
+}
+
+///Montgomery form B * v^2 = u^3 + A * u^2 + u of the curve E. The constants A and B of EC
+///hold the short Weierstrass form of the same curve, x = u / B + A / (3 * B), y = v / B,
+///so ECpoint<E> and MontgomeryPoint<E> are two views of one group.
+pub trait Montgomery: EC {
+    ///A constant in the Montgomery equation
+    const MONTGOMERY_A: U256;
+    ///B constant in the Montgomery equation
+    const MONTGOMERY_B: U256;
+}
+
+///Twisted Edwards form a * x^2 + y^2 = 1 + d * x^2 * y^2 of the curve E. It has to be
+///birationally equivalent to the Montgomery form, A = 2 * (a + d) / (a - d), with
+///u = (1 + y) / (1 - y) and v = c * u / x, where c^2 = 4 / ((a - d) * B).
+pub trait TwistedEdwards: Montgomery {
+    ///a constant in the Edwards equation
+    const EDWARDS_A: U256;
+    ///d constant in the Edwards equation
+    const EDWARDS_D: U256;
+    ///c, the square root scaling v in the map to the Montgomery form
+    const EDWARDS_C: U256;
+}
+
 // #[allow(non_snake_case)]
 // #[derive(Default, Debug)]
 // pub struct Curve<S, E: EC> {
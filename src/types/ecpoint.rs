@@ -1,12 +1,25 @@
-use crate::types::{Zp, EC, Scalar, U256};
+use std::marker::PhantomData;
+
+use crate::types::{CurveOver, Field, Zp, EC, Scalar, U256};
 #[derive(Default, PartialEq, Clone, Copy)]
-///Represents a Point on curve that can be expressed with x,y coordinates
-pub struct Point<E: EC> {
-    x: Zp<E>,
-    y: Zp<E>,
+///Represents a Point on curve that can be expressed with x,y coordinates.
+///The coordinates are in Zp by default, or in the field F the curve is defined over.
+pub struct Point<E: EC, F: Field = Zp<E>> {
+    x: F,
+    y: F,
+    _curve: PhantomData<E>,
+}
+
+impl<E: EC, F: Field> Eq for Point<E, F> {}
+
+impl<E: EC, F: Field> std::hash::Hash for Point<E, F> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
 }
 
-impl<E: EC> std::fmt::Debug for Point<E> {
+impl<E: EC, F: Field> std::fmt::Debug for Point<E, F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // f.debug_struct("Point").field("x", &self.x).field("y", &self.y).finish()
         write!(f, "({}, {}) [mod {}, curve {}]", self.x, self.y, E::P, E::NAME)?;
@@ -16,13 +29,24 @@ impl<E: EC> std::fmt::Debug for Point<E> {
 
 #[derive(Default, PartialEq, Clone, Copy)]
 ///Represents the entire set of points lying on EC, including inifnity
-pub enum ECpoint<E: EC> {
+pub enum ECpoint<E: EC, F: Field = Zp<E>> {
     #[default]
     Infinity,
-    Point(Point<E>)
+    Point(Point<E, F>)
 }
 
-impl<E: EC> std::fmt::Debug for ECpoint<E> {
+impl<E: EC, F: Field> Eq for ECpoint<E, F> {}
+
+impl<E: EC, F: Field> std::hash::Hash for ECpoint<E, F> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Infinity => None::<&Point<E, F>>.hash(state),
+            Self::Point(p) => Some(p).hash(state)
+        }
+    }
+}
+
+impl<E: EC, F: Field> std::fmt::Debug for ECpoint<E, F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Infinity => write!(f, "Point at Infinity"),
@@ -32,12 +56,12 @@ impl<E: EC> std::fmt::Debug for ECpoint<E> {
     }
 }
 
-impl<E: EC> ECpoint<E> {
-    pub fn new<U: Into<Zp<E>>, T: Into<Zp<E>>>(x: U, y: T) -> Option<Self> {
+impl<E: CurveOver<F>, F: Field> ECpoint<E, F> {
+    pub fn new<U: Into<F>, T: Into<F>>(x: U, y: T) -> Option<Self> {
         let (x, y) = (x.into(), y.into());
-        let (a, b): (Zp<E>, Zp<E>) = (Zp::new(E::A), Zp::new(E::B));
-        if y.pow(2) == x.pow(3) + a * x + b {
-            Some(ECpoint::Point(Point{x, y}))
+        let (a, b) = (E::a(), E::b());
+        if y.square() == x.square() * x + a * x + b {
+            Some(ECpoint::Point(Point{x, y, _curve: PhantomData}))
         } else {
             None
         }
@@ -48,26 +72,120 @@ impl<E: EC> ECpoint<E> {
             Self::Point(_) => false
         }
     }
-    pub fn get_point(&self) -> Option<&Point<E>> {
+    pub fn get_point(&self) -> Option<&Point<E, F>> {
         match self {
             ECpoint::Infinity => None,
             ECpoint::Point(p) => Some(p)
         }
     }
     ///Warning, panics on infinity!
-    pub fn x(&self) -> Zp<E> {
+    pub fn x(&self) -> F {
         match self {
             ECpoint::Infinity => panic!("Infinity has no x coordinate"),
             ECpoint::Point(p) => p.x
         }
     }
     ///Warning, panics on infinity!
-    pub fn y(&self) -> Zp<E> {
+    pub fn y(&self) -> F {
         match self {
             ECpoint::Infinity => panic!("Infinity has no y coordinate"),
             ECpoint::Point(p) => p.y
         }
     }
+    ///Maps the point to the curve over an extension K of F, e.g. a point over Zp to
+    ///the same curve over Fp2. None if the point does not satisfy the equation over K,
+    ///which happens when E over K is a twist.
+    pub fn lift<K: Field + From<F>>(&self) -> Option<ECpoint<E, K>> where E: CurveOver<K> {
+        match self {
+            ECpoint::Infinity => Some(ECpoint::Infinity),
+            ECpoint::Point(p) => ECpoint::new(K::from(p.x), K::from(p.y)),
+        }
+    }
+    ///Multiplies the point by an integer using double and add. Unlike multiplying
+    ///with a Scalar, k is not reduced mod N, so it works for points that
+    ///are not in the subgroup generated by G too.
+    ///Runs in Jacobian coordinates, so only the final result needs an inversion.
+    pub fn mul_integer<T: Into<U256>>(&self, k: T) -> Self {
+        let k = k.into();
+        let point = to_jacobian(self);
+        let mut res = to_jacobian(&Self::Infinity);
+        for b in (0..k.bits()).rev() {
+            res = double_jacobian::<E, F>(res);
+            if k.bit(b) {
+                res = add_jacobian::<E, F>(res, point);
+            }
+        }
+        from_jacobian(res)
+    }
+}
+
+///(X : Y : Z) with x = X / Z^2 and y = Y / Z^3, infinity has Z = 0
+pub(crate) type Jacobian<F> = (F, F, F);
+
+///dbl-2007-bl, doubling for any a
+pub(crate) fn double_jacobian<E: CurveOver<F>, F: Field>(p: Jacobian<F>) -> Jacobian<F> {
+    let (x1, y1, z1) = p;
+    if z1.is_zero() || y1.is_zero() {
+        return (F::one(), F::one(), F::zero())
+    }
+    let (xx, yy, zz) = (x1.square(), y1.square(), z1.square());
+    let yyyy = yy.square();
+    let s = ((x1 + yy).square() - xx - yyyy) * F::from(2);
+    let m = xx * F::from(3) + E::a() * zz.square();
+    let x3 = m.square() - s * F::from(2);
+    let y3 = m * (s - x3) - yyyy * F::from(8);
+    let z3 = (y1 + z1).square() - yy - zz;
+    (x3, y3, z3)
+}
+
+///add-2007-bl, falls back to doubling for equal points
+pub(crate) fn add_jacobian<E: CurveOver<F>, F: Field>(p: Jacobian<F>, q: Jacobian<F>) -> Jacobian<F> {
+    let ((x1, y1, z1), (x2, y2, z2)) = (p, q);
+    if z1.is_zero() {
+        return q
+    }
+    if z2.is_zero() {
+        return p
+    }
+    let (z1z1, z2z2) = (z1.square(), z2.square());
+    let (u1, u2) = (x1 * z2z2, x2 * z1z1);
+    let (s1, s2) = (y1 * z2 * z2z2, y2 * z1 * z1z1);
+    let h = u2 - u1;
+    let r = (s2 - s1) * F::from(2);
+    if h.is_zero() {
+        return match r.is_zero() {
+            true => double_jacobian::<E, F>(p),
+            false => (F::one(), F::one(), F::zero())
+        }
+    }
+    let i = (h * F::from(2)).square();
+    let j = h * i;
+    let v = u1 * i;
+    let x3 = r.square() - j - v * F::from(2);
+    let y3 = r * (v - x3) - s1 * j * F::from(2);
+    let z3 = ((z1 + z2).square() - z1z1 - z2z2) * h;
+    (x3, y3, z3)
+}
+
+pub(crate) fn to_jacobian<E: CurveOver<F>, F: Field>(p: &ECpoint<E, F>) -> Jacobian<F> {
+    match p {
+        ECpoint::Infinity => (F::one(), F::one(), F::zero()),
+        ECpoint::Point(p) => (p.x, p.y, F::one())
+    }
+}
+
+pub(crate) fn from_jacobian<E: CurveOver<F>, F: Field>(p: Jacobian<F>) -> ECpoint<E, F> {
+    let (x, y, z) = p;
+    match z.inverse() {
+        None => ECpoint::Infinity,
+        Some(z_inv) => {
+            let z_inv2 = z_inv.square();
+            ECpoint::Point(Point { x: x * z_inv2, y: y * z_inv2 * z_inv, _curve: PhantomData })
+        }
+    }
+}
+
+impl<E: EC> ECpoint<E> {
     ///Creates a u8 representation of the point. Big endian x, then y coordinates
     ///Warning, returns empty vec for infinity!
     pub fn to_u8_vec(&self) -> Vec<u8> {
@@ -78,14 +196,82 @@ impl<E: EC> ECpoint<E> {
                 self.y().unwrap().0
             ].concat()
              .into_iter()
-             .map(|v| v.to_be_bytes())
-             .flatten()
+             .flat_map(|v| v.to_be_bytes())
              .collect::<Vec<u8>>()
         }
     }
+    ///SEC1 encoding of the point. 0x02 or 0x03 (parity of y) followed by x when
+    ///compressed, 0x04 followed by x and y otherwise, big endian and padded to the
+    ///byte length of P. Infinity is encoded as a single 0x00.
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        let len = E::P.bits().div_ceil(8);
+        let be_bytes = |v: U256| {
+            let mut bytes = [0u8; 32];
+            v.to_big_endian(&mut bytes);
+            bytes[32 - len..].to_vec()
+        };
+        match self {
+            Self::Infinity => vec![0x00],
+            Self::Point(_) => {
+                let (x, y) = (self.x().unwrap(), self.y().unwrap());
+                match compressed {
+                    true => [vec![0x02 + y.bit(0) as u8], be_bytes(x)].concat(),
+                    false => [vec![0x04], be_bytes(x), be_bytes(y)].concat()
+                }
+            }
+        }
+    }
+    ///True if the y coordinate is even, as used by the x-only keys of BIP340.
+    ///Warning, panics on infinity!
+    pub fn has_even_y(&self) -> bool {
+        !self.y().unwrap().bit(0)
+    }
+    ///The point with the given x coordinate and an even y, the lift_x of BIP340.
+    ///None if x is not below P or no point has that x coordinate.
+    pub fn lift_x(x: U256) -> Option<Self> {
+        if x >= E::P {
+            return None
+        }
+        let x = Zp::new(x);
+        let (y, minus_y) = (x * x * x + Zp::new(E::A) * x + Zp::new(E::B)).sqrt()?;
+        match y.unwrap().bit(0) {
+            true => Self::new(x, minus_y),
+            false => Self::new(x, y)
+        }
+    }
+    ///Decodes a SEC1 encoded point, compressed or not. None if the encoding is
+    ///malformed, a coordinate is not below P, or the point is not on the curve.
+    pub fn from_sec1(bytes: &[u8]) -> Option<Self> {
+        let len = E::P.bits().div_ceil(8);
+        let coordinate = |bytes: &[u8]| {
+            let v = U256::from_big_endian(bytes);
+            match v < E::P {
+                true => Some(v),
+                false => None
+            }
+        };
+        match (bytes.first()?, bytes.len() - 1) {
+            (0x00, 0) => Some(Self::Infinity),
+            (0x02 | 0x03, l) if l == len => {
+                let x = Zp::new(coordinate(&bytes[1..])?);
+                let (y, minus_y) = (x * x * x + Zp::new(E::A) * x + Zp::new(E::B)).sqrt()?;
+                let odd = bytes[0] == 0x03;
+                let y = if y.unwrap().bit(0) == odd { y } else { minus_y };
+                //for y = 0 both roots are even, so 0x03 does not encode a point
+                match y.unwrap().bit(0) == odd {
+                    true => Self::new(x, y),
+                    false => None
+                }
+            },
+            (0x04, l) if l == 2 * len => {
+                Self::new(coordinate(&bytes[1..=len])?, coordinate(&bytes[len + 1..])?)
+            },
+            _ => None
+        }
+    }
 }
 
-impl<E: EC> std::ops::Add for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::ops::Add for ECpoint<E, F> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         match (&self, &rhs) {
@@ -99,7 +285,7 @@ impl<E: EC> std::ops::Add for ECpoint<E> {
 
                 let lambda = if p == q {
                     // point doubling
-                    (p.x() * p.x() * Zp::new(3) + Zp::new(E::A)) / (p.y() * Zp::new(2))
+                    (p.x() * p.x() * F::from(3) + E::a()) / (p.y() * F::from(2))
                 } else {
                     (q.y() - p.y()) / (q.x() - p.x())
                 };
@@ -107,27 +293,30 @@ impl<E: EC> std::ops::Add for ECpoint<E> {
                 let x = lambda * lambda - p.x() - q.x();
                 let y = lambda * (p.x() - x) - p.y();
 
-                ECpoint::Point(Point { x, y})
+                ECpoint::Point(Point { x, y, _curve: PhantomData })
             }
         }
     }
 }
 
-impl<E: EC> std::ops::AddAssign for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::ops::AddAssign for ECpoint<E, F> {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs
     }
 }
 
-impl<E: EC> std::ops::Neg for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::ops::Neg for ECpoint<E, F> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self::Point(Point { x: self.x(), y: -self.y() })
+        match self {
+            Self::Infinity => Self::Infinity, // -0 = 0
+            Self::Point(p) => Self::Point(Point { x: p.x, y: -p.y, _curve: PhantomData })
+        }
     }
 }
 
-impl<E: EC> std::ops::Sub for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::ops::Sub for ECpoint<E, F> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -136,24 +325,14 @@ impl<E: EC> std::ops::Sub for ECpoint<E> {
 }
 
 ///scalar multiplication
-impl<E: EC> std::ops::Mul<Scalar<E>> for ECpoint<E> {
-    type Output = ECpoint<E>;
+impl<E: CurveOver<F>, F: Field> std::ops::Mul<Scalar<E>> for ECpoint<E, F> {
+    type Output = ECpoint<E, F>;
     ///the scalar is modulo N, the order of the elliptic curve!
     fn mul(self, rhs: Scalar<E>) -> Self::Output {
-        let mut res = ECpoint::Infinity;
-        let rhs = rhs.unwrap();
-        let mut point = self;
-
-        for b in 0..256 {
-            if rhs.bit(b as usize) {
-                res += point;
-            }
-            point += point; //doubleing
-        }
-        res
+        self.mul_integer(rhs.unwrap())
     }
 }
-impl<E: EC> std::ops::MulAssign<Scalar<E>> for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::ops::MulAssign<Scalar<E>> for ECpoint<E, F> {
     fn mul_assign(&mut self, rhs: Scalar<E>) {
         *self = *self * rhs
     }
@@ -164,8 +343,8 @@ impl<E: EC> std::ops::MulAssign<Scalar<E>> for ECpoint<E> {
 macro_rules! impl_mut_for_ecpoint {
     ($($t:ty),*) => {
         $(
-            impl<E: EC> std::ops::Mul<$t> for ECpoint<E> {
-                type Output = ECpoint<E>;
+            impl<E: CurveOver<F>, F: Field> std::ops::Mul<$t> for ECpoint<E, F> {
+                type Output = ECpoint<E, F>;
             
                 fn mul(self, rhs: $t) -> Self::Output {
                     let rhs: Scalar<E> = rhs.into();
@@ -181,8 +360,8 @@ macro_rules! impl_mut_for_ecpoint {
 impl_mut_for_ecpoint!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128);
 
 
-impl<E: EC> std::ops::Div<Scalar<E>> for ECpoint<E>{
-    type Output = ECpoint<E>;
+impl<E: CurveOver<F>, F: Field> std::ops::Div<Scalar<E>> for ECpoint<E, F>{
+    type Output = ECpoint<E, F>;
 
     fn div(self, rhs: Scalar<E>) -> Self::Output {
         (Scalar::new(1)/rhs) * self
@@ -192,8 +371,8 @@ impl<E: EC> std::ops::Div<Scalar<E>> for ECpoint<E>{
 macro_rules! impl_div_for_ecpoint {
     ($($t:ty),*) => {
         $(
-            impl<E: EC> std::ops::Div<$t> for ECpoint<E> {
-                type Output = ECpoint<E>;
+            impl<E: CurveOver<F>, F: Field> std::ops::Div<$t> for ECpoint<E, F> {
+                type Output = ECpoint<E, F>;
             
                 fn div(self, rhs: $t) -> Self::Output {
                     let rhs: Scalar<E> = rhs.into();
@@ -206,13 +385,13 @@ macro_rules! impl_div_for_ecpoint {
 
 impl_div_for_ecpoint!(i8,u8,i16,u16,i32,u32,i64,u64,i128,u128,U256);
 
-impl<E: EC> std::ops::DivAssign<Scalar<E>> for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::ops::DivAssign<Scalar<E>> for ECpoint<E, F> {
     fn div_assign(&mut self, rhs: Scalar<E>) {
         *self = *self / rhs
     }
 }
 
-impl<E: EC> std::iter::Sum for ECpoint<E> {
+impl<E: CurveOver<F>, F: Field> std::iter::Sum for ECpoint<E, F> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
         iter.fold(ECpoint::Infinity, |mut acc, point|{
             acc += point;
@@ -0,0 +1,165 @@
+use crate::types::{ECpoint, MontgomeryPoint, Scalar, TwistedEdwards, Zp, U256};
+
+#[derive(PartialEq, Clone, Copy)]
+///Point of the curve E in twisted Edwards form a * x^2 + y^2 = 1 + d * x^2 * y^2.
+///The neutral element is (0, 1), there is no point at infinity.
+pub struct EdwardsPoint<E: TwistedEdwards> {
+    x: Zp<E>,
+    y: Zp<E>,
+}
+
+impl<E: TwistedEdwards> Eq for EdwardsPoint<E> {}
+
+impl<E: TwistedEdwards> std::hash::Hash for EdwardsPoint<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
+    }
+}
+
+impl<E: TwistedEdwards> Default for EdwardsPoint<E> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<E: TwistedEdwards> std::fmt::Debug for EdwardsPoint<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}) [mod {}, curve {} twisted Edwards form]", self.x, self.y, E::P, E::NAME)
+    }
+}
+
+///(X : Y : Z : T) with x = X / Z, y = Y / Z and x * y = T / Z, used to multiply
+///without an inversion on every addition
+type Extended<E> = (Zp<E>, Zp<E>, Zp<E>, Zp<E>);
+
+///Unified addition in extended coordinates (Hisil, Wong, Carter, Dawson 2008),
+///complete when a is a square and d is not
+fn add_extended<E: TwistedEdwards>(p: Extended<E>, q: Extended<E>) -> Extended<E> {
+    let (x1, y1, z1, t1) = p;
+    let (x2, y2, z2, t2) = q;
+    let a = x1 * x2;
+    let b = y1 * y2;
+    let c = Zp::new(E::EDWARDS_D) * t1 * t2;
+    let d = z1 * z2;
+    let e = (x1 + y1) * (x2 + y2) - a - b;
+    let f = d - c;
+    let g = d + c;
+    let h = b - Zp::new(E::EDWARDS_A) * a;
+    (e * f, g * h, f * g, e * h)
+}
+
+impl<E: TwistedEdwards> EdwardsPoint<E> {
+    pub fn new<U: Into<Zp<E>>, T: Into<Zp<E>>>(x: U, y: T) -> Option<Self> {
+        let (x, y) = (x.into(), y.into());
+        let (a, d) = (Zp::new(E::EDWARDS_A), Zp::new(E::EDWARDS_D));
+        let (xx, yy) = (x * x, y * y);
+        if a * xx + yy == Zp::one() + d * xx * yy {
+            Some(Self { x, y })
+        } else {
+            None
+        }
+    }
+    ///The neutral element (0, 1)
+    pub fn identity() -> Self {
+        Self { x: Zp::zero(), y: Zp::one() }
+    }
+    pub fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+    pub fn x(&self) -> Zp<E> {
+        self.x
+    }
+    pub fn y(&self) -> Zp<E> {
+        self.y
+    }
+    ///Multiplies the point by an integer using double and add in extended
+    ///coordinates, k is not reduced mod N
+    pub fn mul_integer<T: Into<U256>>(&self, k: T) -> Self {
+        let k = k.into();
+        let mut res = (Zp::zero(), Zp::one(), Zp::one(), Zp::zero());
+        let mut point = (self.x, self.y, Zp::one(), self.x * self.y);
+        for b in 0..k.bits() {
+            if k.bit(b) {
+                res = add_extended(res, point);
+            }
+            point = add_extended(point, point); //doubleing
+        }
+        let (x, y, z, _) = res;
+        let z_inv = Zp::one() / z;
+        Self { x: x * z_inv, y: y * z_inv }
+    }
+    ///Maps the point to the Montgomery form, u = (1 + y) / (1 - y), v = c * u / x.
+    ///The neutral element goes to infinity, (0, -1) to (0, 0).
+    pub fn to_montgomery(&self) -> MontgomeryPoint<E> {
+        if self.x.is_zero() {
+            return match self.is_identity() {
+                true => MontgomeryPoint::Infinity,
+                false => MontgomeryPoint::new(0, 0).unwrap()
+            }
+        }
+        let u = (Zp::one() + self.y) / (Zp::one() - self.y);
+        MontgomeryPoint::new(u, Zp::new(E::EDWARDS_C) * u / self.x)
+            .expect("the Edwards and Montgomery constants of the curve do not match")
+    }
+    ///Maps the point to the short Weierstrass form through the Montgomery form
+    pub fn to_weierstrass(&self) -> ECpoint<E> {
+        self.to_montgomery().to_weierstrass()
+    }
+}
+
+impl<E: TwistedEdwards> ECpoint<E> {
+    ///Maps the point from the short Weierstrass form to the twisted Edwards form.
+    ///None for the points without an affine Edwards image.
+    pub fn to_edwards(&self) -> Option<EdwardsPoint<E>> {
+        self.to_montgomery().to_edwards()
+    }
+}
+
+impl<E: TwistedEdwards> std::ops::Add for EdwardsPoint<E> {
+    type Output = Self;
+    ///x3 = (x1 * y2 + y1 * x2) / (1 + d * x1 * x2 * y1 * y2)
+    ///y3 = (y1 * y2 - a * x1 * x2) / (1 - d * x1 * x2 * y1 * y2)
+    ///the same formula doubles, no special cases
+    fn add(self, rhs: Self) -> Self::Output {
+        let (a, d) = (Zp::new(E::EDWARDS_A), Zp::new(E::EDWARDS_D));
+        let dxy = d * self.x * rhs.x * self.y * rhs.y;
+        let x = (self.x * rhs.y + self.y * rhs.x) / (Zp::one() + dxy);
+        let y = (self.y * rhs.y - a * self.x * rhs.x) / (Zp::one() - dxy);
+        Self { x, y }
+    }
+}
+
+impl<E: TwistedEdwards> std::ops::AddAssign for EdwardsPoint<E> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<E: TwistedEdwards> std::ops::Neg for EdwardsPoint<E> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: self.y }
+    }
+}
+
+impl<E: TwistedEdwards> std::ops::Sub for EdwardsPoint<E> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+///scalar multiplication
+impl<E: TwistedEdwards> std::ops::Mul<Scalar<E>> for EdwardsPoint<E> {
+    type Output = Self;
+    fn mul(self, rhs: Scalar<E>) -> Self::Output {
+        self.mul_integer(rhs.unwrap())
+    }
+}
+
+impl<E: TwistedEdwards> std::iter::Sum for EdwardsPoint<E> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, point| acc + point)
+    }
+}
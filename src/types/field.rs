@@ -0,0 +1,156 @@
+use std::{fmt::{Debug, Display}, hash::Hash, ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use crate::{types::{Zp, EC, U256}, utils::limbs};
+
+///Arithmetic shared by the prime field Zp and the extension fields built on top of it.
+///Elements of Zp embed into every extension through From.
+pub trait Field: Copy + Default + PartialEq + Eq + Hash + Debug + Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign + MulAssign + From<u64>
+{
+    ///Degree of the field over the prime field Zp
+    const DEGREE: u32;
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+    ///The prime p of the underlying prime field
+    fn characteristic() -> U256;
+    ///Multiplicative inverse, None for zero
+    fn inverse(&self) -> Option<Self>;
+    ///The Frobenius endomorphism, raises self to the power of p
+    fn frobenius(&self) -> Self;
+    ///Find n such that n * n = self, returns (n, -n)
+    fn sqrt(&self) -> Option<(Self, Self)>;
+    ///Some fixed element that is not a square
+    fn non_square() -> Self;
+    fn square(&self) -> Self {
+        *self * *self
+    }
+    ///Raises self to the power of exp using square and multiply algorithm
+    fn pow<T: Into<U256>>(&self, exp: T) -> Self {
+        self.pow_limbs(&exp.into().0)
+    }
+    ///Raises self to the power of exp given as little endian u64 limbs, for
+    ///exponents that do not fit into U256, e.g. (p^12 - 1) / r
+    fn pow_limbs(&self, exp: &[u64]) -> Self {
+        let mut res = Self::one();
+        for limb in exp.iter().rev() {
+            for b in (0..64).rev() {
+                res = res.square();
+                if (limb >> b) & 1 == 1 {
+                    res *= *self;
+                }
+            }
+        }
+        res
+    }
+    ///Applies the Frobenius endomorphism k times, raising self to p^k
+    fn frobenius_power(&self, k: u32) -> Self {
+        (0..k).fold(*self, |acc, _| acc.frobenius())
+    }
+    ///Number of elements of the field, p^DEGREE as little endian u64 limbs
+    fn order() -> Vec<u64> {
+        let p = Self::characteristic().0;
+        (1..Self::DEGREE).fold(p.to_vec(), |acc, _| limbs::mul(&acc, &p))
+    }
+    ///Decides whether self is a square using Euler's criterion
+    fn is_square(&self) -> bool {
+        if self.is_zero() {
+            return true
+        }
+        let exp = limbs::shr1(&limbs::sub_one(&Self::order()));
+        self.pow_limbs(&exp).is_one()
+    }
+}
+
+///Supplies the element an extension field is built with. For quadratic extensions
+///u^2 = NR, so it must not be a square in F, for cubic extensions v^3 = NR, so it
+///must not be a cube in F.
+pub trait NonResidue<F: Field>: Copy + Default + PartialEq + Debug {
+    fn non_residue() -> F;
+}
+
+impl<E: EC> Field for Zp<E> {
+    const DEGREE: u32 = 1;
+    fn zero() -> Self {
+        Zp::zero()
+    }
+    fn one() -> Self {
+        Zp::one()
+    }
+    fn characteristic() -> U256 {
+        E::P
+    }
+    fn inverse(&self) -> Option<Self> {
+        match self.is_zero() {
+            true => None,
+            false => Some(Zp::one() / *self)
+        }
+    }
+    fn frobenius(&self) -> Self {
+        *self
+    }
+    fn sqrt(&self) -> Option<(Self, Self)> {
+        Zp::sqrt(*self)
+    }
+    fn non_square() -> Self {
+        let mut z = Zp::new(2);
+        while z.is_quadratic_residue() {
+            z += Zp::one();
+        }
+        z
+    }
+    fn pow<T: Into<U256>>(&self, exp: T) -> Self {
+        Zp::pow(*self, exp)
+    }
+    fn is_square(&self) -> bool {
+        self.is_quadratic_residue()
+    }
+}
+
+///Square root in any field of odd characteristic with the Tonelli-Shanks algorithm,
+///used where no faster method is known. Works with the field order as limbs,
+///so it is not limited to orders fitting into U256.
+pub(crate) fn tonelli_shanks<F: Field>(n: &F) -> Option<(F, F)> {
+    if n.is_zero() {
+        return Some((*n, *n))
+    }
+    if !n.is_square() {
+        return None
+    }
+    //Write q - 1 = (2^S)(Q) where Q is odd
+    #[allow(non_snake_case)]
+    let mut Q = limbs::sub_one(&F::order());
+    #[allow(non_snake_case)]
+    let mut S = 0u32;
+    while limbs::is_even(&Q) {
+        Q = limbs::shr1(&Q);
+        S += 1;
+    }
+    #[allow(non_snake_case)]
+    let mut M = S;
+    let mut c = F::non_square().pow_limbs(&Q);
+    let mut t = n.pow_limbs(&Q);
+    #[allow(non_snake_case)]
+    let mut R = n.pow_limbs(&limbs::shr1(&limbs::add_one(&Q)));
+    while !t.is_one() {
+        //find the least i such that t^(2^i) = 1
+        let mut i = 0;
+        let mut temp = t;
+        while !temp.is_one() {
+            i += 1;
+            temp = temp.square();
+        }
+        let b = (0..M - i - 1).fold(c, |acc, _| acc.square());
+        M = i;
+        c = b.square();
+        t *= c;
+        R *= b;
+    }
+    Some((R, -R))
+}
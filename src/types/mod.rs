@@ -1,13 +1,24 @@
 pub use u256::U256;
 pub use u512::U512;
-pub use curve::EC;
+pub use curve::{EC, Generator, CurveOver, Montgomery, TwistedEdwards};
 pub use ecpoint::ECpoint;
+pub(crate) use ecpoint::{add_jacobian, double_jacobian, from_jacobian, to_jacobian};
+pub use montgomery_point::MontgomeryPoint;
+pub use edwards_point::EdwardsPoint;
 pub use zp::Zp;
 pub use scalar::Scalar;
+pub use field::{Field, NonResidue};
+pub use quadratic_extension::{QuadraticExtension, Fp2, Fp12};
+pub use cubic_extension::{CubicExtension, Fp6};
 
 mod u256;
 mod u512;
 mod curve;
 mod ecpoint; 
+mod montgomery_point;
+mod edwards_point;
 mod zp;
-mod scalar;
\ No newline at end of file
+mod scalar;
+mod field;
+mod quadratic_extension;
+mod cubic_extension;
@@ -0,0 +1,196 @@
+use crate::types::{ECpoint, EdwardsPoint, Montgomery, Scalar, TwistedEdwards, Zp, U256};
+
+#[derive(Default, PartialEq, Clone, Copy)]
+///Point of the curve E in Montgomery form B * v^2 = u^3 + A * u^2 + u, including infinity
+pub enum MontgomeryPoint<E: Montgomery> {
+    #[default]
+    Infinity,
+    Point(Zp<E>, Zp<E>)
+}
+
+impl<E: Montgomery> Eq for MontgomeryPoint<E> {}
+
+impl<E: Montgomery> std::hash::Hash for MontgomeryPoint<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Infinity => None::<(Zp<E>, Zp<E>)>.hash(state),
+            Self::Point(u, v) => Some((u, v)).hash(state)
+        }
+    }
+}
+
+impl<E: Montgomery> std::fmt::Debug for MontgomeryPoint<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Infinity => write!(f, "Point at Infinity"),
+            Self::Point(u, v) => write!(f, "({}, {}) [mod {}, curve {} Montgomery form]", u, v, E::P, E::NAME)
+        }
+    }
+}
+
+impl<E: Montgomery> MontgomeryPoint<E> {
+    pub fn new<U: Into<Zp<E>>, T: Into<Zp<E>>>(u: U, v: T) -> Option<Self> {
+        let (u, v) = (u.into(), v.into());
+        let (a, b) = (Zp::new(E::MONTGOMERY_A), Zp::new(E::MONTGOMERY_B));
+        if b * v * v == u * u * u + a * u * u + u {
+            Some(Self::Point(u, v))
+        } else {
+            None
+        }
+    }
+    pub fn is_infinity(&self) -> bool {
+        *self == Self::Infinity
+    }
+    ///Warning, panics on infinity!
+    pub fn u(&self) -> Zp<E> {
+        match self {
+            Self::Infinity => panic!("Infinity has no u coordinate"),
+            Self::Point(u, _) => *u
+        }
+    }
+    ///Warning, panics on infinity!
+    pub fn v(&self) -> Zp<E> {
+        match self {
+            Self::Infinity => panic!("Infinity has no v coordinate"),
+            Self::Point(_, v) => *v
+        }
+    }
+    ///Multiplies the point by an integer using double and add, k is not reduced mod N
+    pub fn mul_integer<T: Into<U256>>(&self, k: T) -> Self {
+        let k = k.into();
+        let mut res = Self::Infinity;
+        let mut point = *self;
+        for b in 0..k.bits() {
+            if k.bit(b) {
+                res += point;
+            }
+            point += point; //doubleing
+        }
+        res
+    }
+    ///x-only scalar multiplication with the Montgomery ladder as in RFC 7748. Given the
+    ///u coordinate of P returns the u coordinate of k * P, or 0 if it is infinity.
+    ///Works for any u, if it is not on the curve the result is on its quadratic twist.
+    ///Warning, the ladder does a fixed sequence of operations but the swaps branch,
+    ///so it is not constant time!
+    pub fn ladder<T: Into<U256>>(u: Zp<E>, k: T) -> Zp<E> {
+        let k = k.into();
+        let a24 = (Zp::new(E::MONTGOMERY_A) - Zp::new(2)) / Zp::new(4);
+        let (mut x2, mut z2) = (Zp::one(), Zp::zero());
+        let (mut x3, mut z3) = (u, Zp::one());
+        let mut swap = false;
+        for t in (0..k.bits()).rev() {
+            let bit = k.bit(t);
+            if swap ^ bit {
+                std::mem::swap(&mut x2, &mut x3);
+                std::mem::swap(&mut z2, &mut z3);
+            }
+            swap = bit;
+            let (a, b) = (x2 + z2, x2 - z2);
+            let (aa, bb) = (a * a, b * b);
+            let e = aa - bb;
+            let (c, d) = (x3 + z3, x3 - z3);
+            let (da, cb) = (d * a, c * b);
+            x3 = (da + cb) * (da + cb);
+            z3 = u * (da - cb) * (da - cb);
+            x2 = aa * bb;
+            z2 = e * (aa + a24 * e);
+        }
+        if swap {
+            std::mem::swap(&mut x2, &mut x3);
+            std::mem::swap(&mut z2, &mut z3);
+        }
+        x2 * z2.pow(E::P - 2)
+    }
+    ///Maps the point to the short Weierstrass form, x = u / B + A / (3 * B), y = v / B
+    pub fn to_weierstrass(&self) -> ECpoint<E> {
+        match self {
+            Self::Infinity => ECpoint::Infinity,
+            Self::Point(u, v) => {
+                let (a, b) = (Zp::new(E::MONTGOMERY_A), Zp::new(E::MONTGOMERY_B));
+                ECpoint::new(*u / b + a / (b * 3.into()), *v / b)
+                    .expect("EC::A and EC::B are not the Weierstrass form of the Montgomery curve")
+            }
+        }
+    }
+    ///Maps the point to the twisted Edwards form, x = c * u / v, y = (u - 1) / (u + 1).
+    ///None for the points the map is not defined at, those with v = 0 or u = -1, other
+    ///than (0, 0) which goes to (0, -1).
+    pub fn to_edwards(&self) -> Option<EdwardsPoint<E>> where E: TwistedEdwards {
+        match self {
+            Self::Infinity => Some(EdwardsPoint::identity()),
+            Self::Point(u, v) if u.is_zero() && v.is_zero() => EdwardsPoint::new(0, -Zp::<E>::one()),
+            Self::Point(u, v) if v.is_zero() || (*u + Zp::one()).is_zero() => None,
+            Self::Point(u, v) => EdwardsPoint::new(Zp::new(E::EDWARDS_C) * *u / *v, (*u - Zp::one()) / (*u + Zp::one()))
+        }
+    }
+}
+
+impl<E: Montgomery> ECpoint<E> {
+    ///Maps the point from the short Weierstrass form to the Montgomery form,
+    ///u = B * x - A / 3, v = B * y
+    pub fn to_montgomery(&self) -> MontgomeryPoint<E> {
+        match self {
+            ECpoint::Infinity => MontgomeryPoint::Infinity,
+            ECpoint::Point(_) => {
+                let (a, b) = (Zp::new(E::MONTGOMERY_A), Zp::new(E::MONTGOMERY_B));
+                MontgomeryPoint::new(b * self.x() - a / 3.into(), b * self.y())
+                    .expect("EC::A and EC::B are not the Weierstrass form of the Montgomery curve")
+            }
+        }
+    }
+}
+
+impl<E: Montgomery> std::ops::Add for MontgomeryPoint<E> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Self::Infinity, _) => rhs,
+            (_, Self::Infinity) => self,
+            (p, q) if p == -q => Self::Infinity,
+            (Self::Point(u1, v1), Self::Point(u2, v2)) => {
+                let (a, b) = (Zp::new(E::MONTGOMERY_A), Zp::new(E::MONTGOMERY_B));
+                let lambda = if self == rhs {
+                    // point doubling
+                    (u1 * u1 * 3.into() + a * u1 * 2.into() + Zp::one()) / (b * v1 * 2.into())
+                } else {
+                    (v2 - v1) / (u2 - u1)
+                };
+                let u = b * lambda * lambda - a - u1 - u2;
+                let v = lambda * (u1 - u) - v1;
+                Self::Point(u, v)
+            }
+        }
+    }
+}
+
+impl<E: Montgomery> std::ops::AddAssign for MontgomeryPoint<E> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<E: Montgomery> std::ops::Neg for MontgomeryPoint<E> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::Infinity => Self::Infinity,
+            Self::Point(u, v) => Self::Point(u, -v)
+        }
+    }
+}
+
+impl<E: Montgomery> std::ops::Sub for MontgomeryPoint<E> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+///scalar multiplication
+impl<E: Montgomery> std::ops::Mul<Scalar<E>> for MontgomeryPoint<E> {
+    type Output = Self;
+    fn mul(self, rhs: Scalar<E>) -> Self::Output {
+        self.mul_integer(rhs.unwrap())
+    }
+}
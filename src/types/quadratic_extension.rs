@@ -0,0 +1,191 @@
+use std::marker::PhantomData;
+
+use crate::types::{Field, Fp6, NonResidue, Zp, U256};
+
+///Quadratic extension F[u] / (u^2 - NR), elements are c0 + c1 * u.
+///NR supplies the element u^2 equals to, it has to be a non-square in F.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct QuadraticExtension<F: Field, NR: NonResidue<F>> {
+    c0: F,
+    c1: F,
+    _non_residue: PhantomData<NR>,
+}
+
+///Fp2, the quadratic extension of the prime field Zp
+pub type Fp2<E, NR> = QuadraticExtension<Zp<E>, NR>;
+///Fp12, a quadratic extension of Fp6
+pub type Fp12<E, NR2, NR6, NR12> = QuadraticExtension<Fp6<E, NR2, NR6>, NR12>;
+
+impl<F: Field, NR: NonResidue<F>> std::fmt::Display for QuadraticExtension<F, NR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} + {}*u)", self.c0, self.c1)?;
+        Ok(())
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::fmt::Debug for QuadraticExtension<F, NR> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [mod {}, u^2 = {}]", self, F::characteristic(), NR::non_residue())?;
+        Ok(())
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> Eq for QuadraticExtension<F, NR> {}
+
+impl<F: Field, NR: NonResidue<F>> std::hash::Hash for QuadraticExtension<F, NR> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.c0.hash(state);
+        self.c1.hash(state);
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> QuadraticExtension<F, NR> {
+    pub fn new<T: Into<F>, U: Into<F>>(c0: T, c1: U) -> Self {
+        QuadraticExtension { c0: c0.into(), c1: c1.into(), _non_residue: PhantomData }
+    }
+    pub fn c0(&self) -> F {
+        self.c0
+    }
+    pub fn c1(&self) -> F {
+        self.c1
+    }
+    ///The conjugate c0 - c1 * u
+    pub fn conjugate(&self) -> Self {
+        QuadraticExtension::new(self.c0, -self.c1)
+    }
+    ///The norm c0^2 - NR * c1^2, an element of F
+    pub fn norm(&self) -> F {
+        self.c0.square() - NR::non_residue() * self.c1.square()
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> Field for QuadraticExtension<F, NR> {
+    const DEGREE: u32 = 2 * F::DEGREE;
+    fn zero() -> Self {
+        QuadraticExtension::new(F::zero(), F::zero())
+    }
+    fn one() -> Self {
+        QuadraticExtension::new(F::one(), F::zero())
+    }
+    fn characteristic() -> U256 {
+        F::characteristic()
+    }
+    fn inverse(&self) -> Option<Self> {
+        //1 / (c0 + c1 * u) = (c0 - c1 * u) / (c0^2 - NR * c1^2)
+        let norm_inverse = self.norm().inverse()?;
+        Some(QuadraticExtension::new(self.c0 * norm_inverse, -self.c1 * norm_inverse))
+    }
+    fn frobenius(&self) -> Self {
+        //u^p = u * (u^2)^((p - 1) / 2) = u * NR^((p - 1) / 2)
+        let gamma = NR::non_residue().pow((F::characteristic() - 1) / 2);
+        QuadraticExtension::new(self.c0.frobenius(), self.c1.frobenius() * gamma)
+    }
+    fn sqrt(&self) -> Option<(Self, Self)> {
+        //the "complex method", reduces the problem to square roots in F
+        if self.c1.is_zero() {
+            let root = match self.c0.sqrt() {
+                Some((r, _)) => QuadraticExtension::new(r, F::zero()),
+                //c0 is not a square in F, but c0 / NR is and (r * u)^2 = r^2 * NR
+                None => QuadraticExtension::new(F::zero(), (self.c0 / NR::non_residue()).sqrt()?.0),
+            };
+            return Some((root, -root))
+        }
+        let (s, _) = self.norm().sqrt()?;
+        let two_inverse = F::from(2).inverse()?;
+        for s in [s, -s] {
+            //x0^2 = (c0 + s) / 2 and x1 = c1 / (2 * x0)
+            if let Some((x0, _)) = ((self.c0 + s) * two_inverse).sqrt() {
+                if let Some(x0_inverse) = (x0 + x0).inverse() {
+                    let root = QuadraticExtension::new(x0, self.c1 * x0_inverse);
+                    return Some((root, -root))
+                }
+            }
+        }
+        None
+    }
+    fn non_square() -> Self {
+        //an element is a square iff its norm is a square in F
+        let mut c0 = F::zero();
+        loop {
+            let candidate = Self::new(c0, F::one());
+            if !candidate.norm().is_square() {
+                return candidate
+            }
+            c0 += F::one();
+        }
+    }
+    fn is_square(&self) -> bool {
+        self.norm().is_square()
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Add for QuadraticExtension<F, NR> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        QuadraticExtension::new(self.c0 + rhs.c0, self.c1 + rhs.c1)
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::AddAssign for QuadraticExtension<F, NR> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Neg for QuadraticExtension<F, NR> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        QuadraticExtension::new(-self.c0, -self.c1)
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Sub for QuadraticExtension<F, NR> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::SubAssign for QuadraticExtension<F, NR> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::Mul for QuadraticExtension<F, NR> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        //(a0 + a1 * u)(b0 + b1 * u) = a0 * b0 + NR * a1 * b1 + (a0 * b1 + a1 * b0) * u
+        QuadraticExtension::new(
+            self.c0 * rhs.c0 + NR::non_residue() * self.c1 * rhs.c1,
+            self.c0 * rhs.c1 + self.c1 * rhs.c0
+        )
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::ops::MulAssign for QuadraticExtension<F, NR> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<F: Field, NR: NonResidue<F>> std::ops::Div for QuadraticExtension<F, NR> {
+    type Output = Self;
+    ///Warning, panics on division by zero!
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("division by zero")
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::convert::From<u64> for QuadraticExtension<F, NR> {
+    fn from(value: u64) -> Self {
+        QuadraticExtension::new(F::from(value), F::zero())
+    }
+}
+
+impl<F: Field, NR: NonResidue<F>> std::convert::From<F> for QuadraticExtension<F, NR> {
+    fn from(value: F) -> Self {
+        QuadraticExtension::new(value, F::zero())
+    }
+}
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 use rand::Rng;
 
-use crate::types::{U256, U512, ECpoint, EC};
+use crate::types::{U256, U512, CurveOver, ECpoint, Field, EC};
 
 #[derive(Clone, Copy, PartialEq, Default)]
 pub struct Scalar<E: EC>(U256, PhantomData<E>);
@@ -21,6 +21,27 @@ impl<E: EC> std::fmt::Debug for Scalar<E> {
     }
 }
 
+impl<E: EC> Eq for Scalar<E> {}
+
+impl<E: EC> std::hash::Hash for Scalar<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+///Orders by the integer value in [0, N), e.g. to sort participant identifiers
+impl<E: EC> PartialOrd for Scalar<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E: EC> Ord for Scalar<E> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
 impl<E: EC> Scalar<E> {
     pub fn new<T: Into<Scalar<E>>>(val: T) -> Self {
         let val: Scalar<E> = val.into();
@@ -63,13 +84,35 @@ impl<E: EC> Scalar<E> {
     ///Generates a random scalar that fits the range <1, N)
     pub fn generate_secret() -> Scalar<E> {
         let mut secret = U256::zero();
+        //only as many random bits as N has, otherwise small N would be hit
+        //with a negligible probability
+        let mask = U256::MAX >> (256 - E::N.bits());
         loop {
             rand::thread_rng().fill(&mut secret.0);
+            secret &= mask;
             if secret > 0.into() && secret < E::N  {
                 return Self::new(secret)
             }
         }
     }
+    ///Big endian encoding, padded to the byte length of N
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = [0u8; 32];
+        self.0.to_big_endian(&mut bytes);
+        bytes[32 - E::N.bits().div_ceil(8)..].to_vec()
+    }
+    ///Decodes a big endian scalar of the byte length of N.
+    ///None for any other length or if the value is not below N.
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != E::N.bits().div_ceil(8) {
+            return None
+        }
+        let value = U256::from_big_endian(bytes);
+        match value < E::N {
+            true => Some(Self(value, PhantomData)),
+            false => None
+        }
+    }
 }
 
 impl<E: EC> std::ops::Add for Scalar<E> {
@@ -131,6 +174,7 @@ impl<E: EC> std::ops::MulAssign for Scalar<E> {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl<E: EC> std::ops::Div for Scalar<E> {
     type Output = Self;
 
@@ -139,27 +183,17 @@ impl<E: EC> std::ops::Div for Scalar<E> {
     }
 }
 
-impl<E: EC> std::ops::Mul<ECpoint<E>> for Scalar<E>{
-    type Output = ECpoint<E>;
+impl<E: CurveOver<F>, F: Field> std::ops::Mul<ECpoint<E, F>> for Scalar<E>{
+    type Output = ECpoint<E, F>;
 
-    fn mul(self, rhs: ECpoint<E>) -> Self::Output {
-        let mut res = ECpoint::Infinity;
-        let lhs = self.unwrap();
-        let mut point = rhs;
-
-        for b in 0..256 {
-            if lhs.bit(b as usize) {
-                res += point;
-            }
-            point += point; //doubleing
-        }
-        res
+    fn mul(self, rhs: ECpoint<E, F>) -> Self::Output {
+        rhs.mul_integer(self.unwrap())
     }
 }
 
 impl<E: EC> std::ops::DivAssign for Scalar<E> {
     fn div_assign(&mut self, rhs: Self) {
-        *self = *self * rhs
+        *self = *self / rhs
     }
 }
 
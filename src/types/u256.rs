@@ -1,10 +1,16 @@
 use crate::types::U512;
-use uint::construct_uint;
 
+pub use uint_impl::U256;
 
-construct_uint!(
-    pub struct U256(4);
-);
+//the expansion of construct_uint! trips these lints, so they are allowed in here only
+mod uint_impl {
+    #![allow(clippy::manual_div_ceil, clippy::assign_op_pattern)]
+    use uint::construct_uint;
+
+    construct_uint!(
+        pub struct U256(4);
+    );
+}
 
 impl std::convert::TryFrom<U512> for U256 {
     type Error = String;
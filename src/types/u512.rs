@@ -1,9 +1,16 @@
 use crate::types::U256;
-use uint::construct_uint;
 
-construct_uint!(
-    pub struct U512(8);
-);
+pub use uint_impl::U512;
+
+//the expansion of construct_uint! trips these lints, so they are allowed in here only
+mod uint_impl {
+    #![allow(clippy::manual_div_ceil, clippy::assign_op_pattern)]
+    use uint::construct_uint;
+
+    construct_uint!(
+        pub struct U512(8);
+    );
+}
 
 impl std::convert::From<U256> for U512 {
     fn from(value: U256) -> Self {
@@ -20,6 +20,14 @@ impl<E: EC> std::fmt::Debug for Zp<E> {
     }
 }
 
+impl<E: EC> Eq for Zp<E> {}
+
+impl<E: EC> std::hash::Hash for Zp<E> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
 impl<E: EC> Zp<E> {
     pub const ZERO: Zp<E> = Zp(U256([0;4]), PhantomData);
     pub fn new<T: Into<Zp<E>>>(val: T) -> Self {
@@ -102,7 +110,7 @@ impl<E: EC> Zp<E> {
         if self.is_zero() {
             return Some((self, self))
         }
-        if self.is_quadratic_residue() == false {
+        if !self.is_quadratic_residue() {
             return None
         }
         //If p=3(mod 4) and we know n is a quadratic residue then 
@@ -149,10 +157,10 @@ impl<E: EC> Zp<E> {
             M = i;
             c = b * b;
             t = t * b * b;
-            R = R * b;
+            R *= b;
             
         }
-        return Some((R, -R))
+        Some((R, -R))
     }
     
 
@@ -217,6 +225,7 @@ impl<E: EC> std::ops::MulAssign for Zp<E> {
     }
 }
 
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl<E: EC> std::ops::Div for Zp<E> {
     type Output = Self;
 
@@ -0,0 +1,61 @@
+use ring::digest::{digest, SHA256};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+///Base58 of Bitcoin, every leading zero byte becomes a '1'
+pub fn base58_encode(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+    //little endian base 58 digits
+    let mut digits: Vec<u8> = vec![];
+    for &byte in &data[zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    std::iter::repeat_n('1', zeros)
+        .chain(digits.iter().rev().map(|&d| ALPHABET[d as usize] as char))
+        .collect()
+}
+
+///None for characters outside the alphabet
+pub fn base58_decode(s: &str) -> Option<Vec<u8>> {
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+    //little endian bytes
+    let mut bytes: Vec<u8> = vec![];
+    for c in s.bytes().skip(zeros) {
+        let mut carry = ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    Some(std::iter::repeat_n(0, zeros).chain(bytes.into_iter().rev()).collect())
+}
+
+fn checksum(data: &[u8]) -> [u8; 4] {
+    digest(&SHA256, digest(&SHA256, data).as_ref()).as_ref()[..4].try_into().unwrap()
+}
+
+///Base58 of data || the first 4 bytes of SHA-256(SHA-256(data))
+pub fn base58check_encode(data: &[u8]) -> String {
+    base58_encode(&[data, &checksum(data)].concat())
+}
+
+///The data without the checksum, None if it is malformed or the checksum is wrong
+pub fn base58check_decode(s: &str) -> Option<Vec<u8>> {
+    let bytes = base58_decode(s)?;
+    let (data, check) = bytes.split_at(bytes.len().checked_sub(4)?);
+    (checksum(data) == check).then(|| data.to_vec())
+}
@@ -0,0 +1,43 @@
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+///Base64 of RFC 4648 with padding
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            match i <= chunk.len() {
+                true => encoded.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize] as char),
+                false => encoded.push('=')
+            }
+        }
+    }
+    encoded
+}
+
+///None for characters outside the alphabet, a length that is not a multiple of 4,
+///misplaced padding or nonzero padding bits
+pub fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.as_bytes();
+    if !s.len().is_multiple_of(4) {
+        return None
+    }
+    let mut decoded = Vec::with_capacity(s.len() / 4 * 3);
+    for (n, chunk) in s.chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 || (padding > 0 && n != s.len() / 4 - 1) {
+            return None
+        }
+        let mut bits = 0u32;
+        for (i, &c) in chunk[..4 - padding].iter().enumerate() {
+            let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+            bits |= value << (18 - 6 * i);
+        }
+        let len = 3 - padding;
+        if bits & (0xffffff >> (8 * len)) != 0 {
+            return None
+        }
+        decoded.extend((0..len).map(|i| (bits >> (16 - 8 * i)) as u8));
+    }
+    Some(decoded)
+}
@@ -0,0 +1,23 @@
+use ring::digest::{digest, SHA256};
+
+///expand_message_xmd of RFC 9380 with SHA-256, expands msg into len uniformly random
+///looking bytes, domain separated by dst. None if len > 255 * 32 or dst is longer
+///than 255 bytes.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Option<Vec<u8>> {
+    let ell = len.div_ceil(32);
+    if ell > 255 || len > u16::MAX as usize || dst.len() > 255 {
+        return None
+    }
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let msg_prime = [&[0u8; 64], msg, &(len as u16).to_be_bytes(), &[0u8], &dst_prime].concat();
+    let b_0 = digest(&SHA256, &msg_prime);
+    let mut b_i = digest(&SHA256, &[b_0.as_ref(), &[1u8], &dst_prime].concat());
+    let mut uniform_bytes = b_i.as_ref().to_vec();
+    for i in 2..=ell {
+        let xored = b_0.as_ref().iter().zip(b_i.as_ref()).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+        b_i = digest(&SHA256, &[&xored, &[i as u8][..], &dst_prime].concat());
+        uniform_bytes.extend_from_slice(b_i.as_ref());
+    }
+    uniform_bytes.truncate(len);
+    Some(uniform_bytes)
+}
@@ -1,5 +1,3 @@
-use std::collections::HashSet;
-
 use crate::types::U256;
 
 pub fn find_factors<T: Into<U256>>(n: T) -> Vec<U256> {
@@ -8,6 +6,11 @@ pub fn find_factors<T: Into<U256>>(n: T) -> Vec<U256> {
     let mut candidate = U256::one();
     while n > 1.into() {
         candidate += 1.into();
+        //no factor is left below sqrt(n), so what remains is a prime
+        if candidate * candidate > n {
+            factors.push(n);
+            break;
+        }
         while n % candidate == 0.into() {
             n /= candidate;
             factors.push(candidate);
@@ -17,4 +20,4 @@ pub fn find_factors<T: Into<U256>>(n: T) -> Vec<U256> {
     // let mut factors: Vec<_> = factors.into_iter().collect(); //transform to vec again
     // factors.sort();
     factors
-}
\ No newline at end of file
+}
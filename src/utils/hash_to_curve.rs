@@ -0,0 +1,45 @@
+use crate::{types::{ECpoint, Zp, EC, U256, U512}, utils::expand_message_xmd};
+
+///hash_to_field of RFC 9380 for the base field, count elements of
+///L = ceil((ceil(log2(P)) + 128) / 8) bytes of expand_message_xmd with SHA-256 each
+fn hash_to_field<E: EC>(msg: &[u8], dst: &[u8], count: usize) -> Vec<Zp<E>> {
+    let len = (E::P.bits() + 128).div_ceil(8);
+    let bytes = expand_message_xmd(msg, dst, count * len).expect("dst is at most 255 bytes");
+    bytes.chunks(len)
+        .map(|chunk| Zp::new(U256::try_from(U512::from_big_endian(chunk) % U512::from(E::P)).unwrap()))
+        .collect()
+}
+
+///Simplified Shallue-van de Woestijne-Ulas map of RFC 9380 section 6.6.2 for curves
+///with A != 0 and B != 0, z is the non-square of the suite, e.g. -10 for P-256.
+///Not constant time.
+pub fn map_to_curve_sswu<E: EC>(u: Zp<E>, z: Zp<E>) -> ECpoint<E> {
+    let (a, b) = (Zp::<E>::new(E::A), Zp::<E>::new(E::B));
+    let tv1 = z * z * u * u * u * u + z * u * u;
+    let x1 = match tv1.is_zero() {
+        true => b / (z * a),
+        false => -b / a * (Zp::one() + Zp::one() / tv1)
+    };
+    let x2 = z * u * u * x1;
+    let g = |x: Zp<E>| x * x * x + a * x + b;
+    let (x, (y, _)) = match g(x1).sqrt() {
+        Some(roots) => (x1, roots),
+        None => (x2, g(x2).sqrt().expect("g(x2) is a square when g(x1) is not"))
+    };
+    //sgn0, the parity, of y has to match the one of u
+    let y = if y.unwrap().bit(0) == u.unwrap().bit(0) { y } else { -y };
+    ECpoint::new(x, y).unwrap()
+}
+
+///encode_to_curve of RFC 9380 with expand_message_xmd SHA-256 and the SSWU map, the
+///nonuniform _NU_ suites, e.g. P256_XMD:SHA-256_SSWU_NU_ with z = -10
+pub fn encode_to_curve_sswu<E: EC>(msg: &[u8], dst: &[u8], z: Zp<E>) -> ECpoint<E> {
+    map_to_curve_sswu(hash_to_field(msg, dst, 1)[0], z).mul_integer(E::H)
+}
+
+///hash_to_curve of RFC 9380 with expand_message_xmd SHA-256 and the SSWU map, the
+///random oracle _RO_ suites, e.g. P256_XMD:SHA-256_SSWU_RO_ with z = -10
+pub fn hash_to_curve_sswu<E: EC>(msg: &[u8], dst: &[u8], z: Zp<E>) -> ECpoint<E> {
+    let u = hash_to_field(msg, dst, 2);
+    (map_to_curve_sswu(u[0], z) + map_to_curve_sswu(u[1], z)).mul_integer(E::H)
+}
@@ -0,0 +1,27 @@
+use ring::digest::{digest, SHA256};
+
+use crate::types::{ECpoint, Zp, EC, U256};
+
+///Hashes data to a point of the subgroup of order N with try and increment:
+///x = SHA-256(data || counter) mod P for counter = 0, 1, ... until x^3 + a * x + b
+///has a square root, the even root is taken as y and the point is multiplied by
+///the cofactor. Nobody knows the discrete log of the result with respect to G, so it
+///works as an independent generator. Not constant time, do not hash secrets.
+pub fn hash_to_point<E: EC>(data: &[u8]) -> ECpoint<E> {
+    let mut counter = 0u32;
+    loop {
+        let hash = digest(&SHA256, &[data, &counter.to_be_bytes()].concat());
+        let x = Zp::<E>::new(U256::from_big_endian(hash.as_ref()));
+        if let Some((y, minus_y)) = (x * x * x + Zp::new(E::A) * x + Zp::new(E::B)).sqrt() {
+            let y = match y.unwrap().bit(0) {
+                true => minus_y,
+                false => y
+            };
+            let point = ECpoint::new(x, y).unwrap().mul_integer(E::H);
+            if !point.is_infinity() {
+                return point
+            }
+        }
+        counter += 1;
+    }
+}
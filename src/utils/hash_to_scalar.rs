@@ -0,0 +1,11 @@
+use crate::{types::{Scalar, EC, U256, U512}, utils::expand_message_xmd};
+
+///hash_to_field of RFC 9380 for the scalar field, L = ceil((ceil(log2(N)) + 128) / 8)
+///bytes of expand_message_xmd with SHA-256 reduced mod N, so the result is
+///indistinguishable from uniform. Panics if dst is longer than 255 bytes.
+pub fn hash_to_scalar<E: EC>(msg: &[u8], dst: &[u8]) -> Scalar<E> {
+    let len = (E::N.bits() + 128).div_ceil(8);
+    let bytes = expand_message_xmd(msg, dst, len).expect("dst is at most 255 bytes");
+    let reduced = U512::from_big_endian(&bytes) % U512::from(E::N);
+    Scalar::new(U256::try_from(reduced).unwrap())
+}
@@ -0,0 +1,76 @@
+//!Minimal arithmetic on little endian u64 limbs, enough to handle exponents
+//!derived from field orders that do not fit into U256
+use crate::types::{U256, U512};
+
+pub fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut res = vec![0u64; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, y) in b.iter().enumerate() {
+            let t = (*x as u128) * (*y as u128) + res[i + j] as u128 + carry;
+            res[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        res[i + b.len()] = carry as u64;
+    }
+    trim(res)
+}
+
+pub fn sub_one(a: &[u64]) -> Vec<u64> {
+    let mut res = a.to_vec();
+    for limb in res.iter_mut() {
+        let (v, borrow) = limb.overflowing_sub(1);
+        *limb = v;
+        if !borrow {
+            break;
+        }
+    }
+    trim(res)
+}
+
+pub fn add_one(a: &[u64]) -> Vec<u64> {
+    let mut res = a.to_vec();
+    for limb in res.iter_mut() {
+        let (v, carry) = limb.overflowing_add(1);
+        *limb = v;
+        if !carry {
+            return trim(res)
+        }
+    }
+    res.push(1);
+    res
+}
+
+pub fn shr1(a: &[u64]) -> Vec<u64> {
+    let mut res = a.to_vec();
+    for i in 0..res.len() {
+        let high = res.get(i + 1).map(|v| v << 63).unwrap_or(0);
+        res[i] = (res[i] >> 1) | high;
+    }
+    trim(res)
+}
+///Long division by a U256 divisor, returns (quotient, remainder)
+pub fn div_rem(a: &[u64], d: U256) -> (Vec<u64>, U256) {
+    let mut quotient = vec![0u64; a.len()];
+    let mut rem = U512::zero();
+    let d = U512::from(d);
+    for i in (0..a.len() * 64).rev() {
+        rem = (rem << 1) | U512::from((a[i / 64] >> (i % 64)) & 1);
+        if rem >= d {
+            rem -= d;
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+    (trim(quotient), U256::try_from(rem).unwrap()) //rem < d, fits
+}
+
+pub fn is_even(a: &[u64]) -> bool {
+    a.first().map(|v| v & 1 == 0).unwrap_or(true)
+}
+
+fn trim(mut a: Vec<u64>) -> Vec<u64> {
+    while a.len() > 1 && a.last() == Some(&0) {
+        a.pop();
+    }
+    a
+}
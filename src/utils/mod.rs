@@ -1,8 +1,28 @@
 pub use find_factors::find_factors;
 pub use is_prime::is_prime;
 pub use find_divisors::find_divisors;
+pub use mod_inverse::mod_inverse;
+pub use hash_to_point::hash_to_point;
+pub use expand_message_xmd::expand_message_xmd;
+pub use hash_to_scalar::hash_to_scalar;
+pub use tagged_hash::tagged_hash;
+pub use multiscalar_mul::multiscalar_mul;
+pub use hash_to_curve::{encode_to_curve_sswu, hash_to_curve_sswu, map_to_curve_sswu};
+pub use ripemd160::{hash160, ripemd160};
+pub use base58::{base58_decode, base58_encode, base58check_decode, base58check_encode};
+pub use base64::{base64_decode, base64_encode};
 
 mod find_factors;
 mod is_prime;
 mod find_divisors;
-
+mod mod_inverse;
+mod hash_to_point;
+mod expand_message_xmd;
+mod hash_to_scalar;
+mod tagged_hash;
+mod multiscalar_mul;
+mod hash_to_curve;
+mod ripemd160;
+mod base58;
+mod base64;
+pub(crate) mod limbs;
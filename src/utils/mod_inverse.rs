@@ -0,0 +1,27 @@
+use crate::types::{U256, U512};
+
+///Finds m such that a * m = 1 mod n using the extended euclidean algorithm.
+///Unlike Zp or Scalar inversion the modulus does not have to be a prime,
+///None is returned if a and n are not coprime.
+pub fn mod_inverse<T: Into<U256>, U: Into<U256>>(a: T, n: U) -> Option<U256> {
+    let (a, n) = (a.into(), n.into());
+    if n <= U256::one() {
+        return None
+    }
+    let mut mn = (n, a % n);
+    let mut xy = (U256::zero(), U256::one());
+    while mn.1 != U256::zero() {
+        let sb = U256::try_from(U512::from(mn.0 / mn.1) * U512::from(xy.1) % U512::from(n)).unwrap();
+        if sb > xy.0 {
+            xy = (xy.1, n - ((sb - xy.0) % n))
+        } else {
+            xy = (xy.1, xy.0 - sb)
+        }
+        mn = (mn.1, mn.0 % mn.1);
+    }
+    //mn.0 holds gcd(a, n), an inverse exists only if it is 1
+    match mn.0 == U256::one() {
+        true => Some(xy.0),
+        false => None
+    }
+}
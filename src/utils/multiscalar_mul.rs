@@ -0,0 +1,20 @@
+use crate::types::{add_jacobian, double_jacobian, from_jacobian, to_jacobian, ECpoint, Scalar, EC};
+
+///Computes the sum of k_i * P_i with one shared chain of doublings (Straus' trick),
+///about as fast as a single multiplication plus one addition per set bit.
+///Panics if the slices differ in length.
+pub fn multiscalar_mul<E: EC>(scalars: &[Scalar<E>], points: &[ECpoint<E>]) -> ECpoint<E> {
+    assert_eq!(scalars.len(), points.len(), "every scalar needs a point");
+    let bits = scalars.iter().map(|k| k.unwrap().bits()).max().unwrap_or(0);
+    let points = points.iter().map(to_jacobian).collect::<Vec<_>>();
+    let mut res = to_jacobian(&ECpoint::<E>::Infinity);
+    for b in (0..bits).rev() {
+        res = double_jacobian::<E, _>(res);
+        for (k, &point) in scalars.iter().zip(&points) {
+            if k.unwrap().bit(b) {
+                res = add_jacobian::<E, _>(res, point);
+            }
+        }
+    }
+    from_jacobian(res)
+}
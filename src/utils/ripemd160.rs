@@ -0,0 +1,83 @@
+use ring::digest::{digest, SHA256};
+
+///Message word selection of the left and the right line
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8,
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12,
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2,
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12,
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2,
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13,
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14,
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+///Rotation amounts of the left and the right line
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8,
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12,
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5,
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12,
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6,
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11,
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5,
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8,
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+const K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+const K_RIGHT: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+///The boolean function of round j / 16, the right line goes through them backwards
+fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+fn compress(h: &mut [u32; 5], block: &[u8]) {
+    let x = block.chunks(4).map(|w| u32::from_le_bytes(w.try_into().unwrap())).collect::<Vec<_>>();
+    let (mut left, mut right) = (*h, *h);
+    for j in 0..80 {
+        let round = j / 16;
+        let [a, b, c, d, e] = left;
+        let t = a.wrapping_add(f(round, b, c, d)).wrapping_add(x[R_LEFT[j]]).wrapping_add(K_LEFT[round])
+            .rotate_left(S_LEFT[j]).wrapping_add(e);
+        left = [e, t, b, c.rotate_left(10), d];
+        let [a, b, c, d, e] = right;
+        let t = a.wrapping_add(f(4 - round, b, c, d)).wrapping_add(x[R_RIGHT[j]]).wrapping_add(K_RIGHT[round])
+            .rotate_left(S_RIGHT[j]).wrapping_add(e);
+        right = [e, t, b, c.rotate_left(10), d];
+    }
+    *h = [
+        h[1].wrapping_add(left[2]).wrapping_add(right[3]),
+        h[2].wrapping_add(left[3]).wrapping_add(right[4]),
+        h[3].wrapping_add(left[4]).wrapping_add(right[0]),
+        h[4].wrapping_add(left[0]).wrapping_add(right[1]),
+        h[0].wrapping_add(left[1]).wrapping_add(right[2]),
+    ];
+}
+
+///RIPEMD-160 (Dobbertin, Bosselaers, Preneel 1996), ring does not provide it
+pub fn ripemd160(data: &[u8]) -> [u8; 20] {
+    let mut h = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+    //padding as in MD4, 0x80, zeros and the bit length little endian
+    let zeros = (55usize.wrapping_sub(data.len())) % 64;
+    let padded = [data, &[0x80], &vec![0; zeros], &((data.len() as u64) * 8).to_le_bytes()].concat();
+    padded.chunks(64).for_each(|block| compress(&mut h, block));
+    h.iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<u8>>().try_into().unwrap()
+}
+
+///RIPEMD-160(SHA-256(data)), the hash of Bitcoin addresses and key fingerprints
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    ripemd160(digest(&SHA256, data).as_ref())
+}
@@ -0,0 +1,8 @@
+use ring::digest::{digest, SHA256};
+
+///SHA-256(SHA-256(tag) || SHA-256(tag) || msg), the tagged hash of BIP340
+pub fn tagged_hash(tag: &[u8], msg: &[u8]) -> [u8; 32] {
+    let tag_hash = digest(&SHA256, tag);
+    let hash = digest(&SHA256, &[tag_hash.as_ref(), tag_hash.as_ref(), msg].concat());
+    hash.as_ref().try_into().unwrap()
+}
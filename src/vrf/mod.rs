@@ -0,0 +1,10 @@
+//!ECVRF verifiable random functions of RFC 9381: the holder of a secret key maps an
+//!input alpha to a pseudorandom output beta, with a proof anyone with the public key
+//!can check, so the output can neither be predicted nor chosen, e.g. for leader
+//!election. Generic over the curve of the suite, with the try and increment and the
+//!RFC 9380 SSWU suites for P-256.
+pub use suite::{encode_to_curve_try_and_increment, P256Sha256Sswu, P256Sha256Tai, Suite};
+pub use proof::Proof;
+
+mod suite;
+mod proof;
@@ -0,0 +1,66 @@
+use ring::digest::{digest, SHA256};
+
+use crate::{ecdsa::rfc6979_nonce, types::{ECpoint, Scalar, Generator, EC, U256}, utils::multiscalar_mul, vrf::Suite};
+
+///ECVRF proof pi = (Gamma, c, s) with Gamma = x * H for H = encode_to_curve(Y, alpha)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Proof<S: Suite> {
+    pub gamma: ECpoint<S::Curve>,
+    pub c: Scalar<S::Curve>,
+    pub s: Scalar<S::Curve>,
+}
+
+///ECVRF_challenge_generation, the first cLen bytes of
+///SHA-256(suite_string || 0x02 || P1 || ... || P5 || 0x00)
+fn challenge<S: Suite>(points: &[ECpoint<S::Curve>; 5]) -> Scalar<S::Curve> {
+    let encoded = points.iter().flat_map(|p| p.to_sec1(true)).collect::<Vec<u8>>();
+    let hash = digest(&SHA256, &[&[S::SUITE_STRING, 0x02], encoded.as_slice(), &[0x00]].concat());
+    Scalar::new(U256::from_big_endian(&hash.as_ref()[..S::C_LEN]))
+}
+
+impl<S: Suite> Proof<S> {
+    ///ECVRF_prove for the secret key x and the input alpha
+    pub fn prove(secret: Scalar<S::Curve>, alpha: &[u8]) -> Self {
+        let g = S::Curve::generator();
+        let public = g * secret;
+        let h = S::encode_to_curve(&public.to_sec1(true), alpha);
+        let gamma = h * secret;
+        let k = rfc6979_nonce(secret, digest(&SHA256, &h.to_sec1(true)).as_ref());
+        let c = challenge::<S>(&[public, h, gamma, g * k, h * k]);
+        Self { gamma, c, s: k + c * secret }
+    }
+    ///ECVRF_verify, beta if the proof is valid for the public key and alpha. Public
+    ///keys of low order are rejected.
+    pub fn verify(&self, public: &ECpoint<S::Curve>, alpha: &[u8]) -> Option<[u8; 32]> {
+        if public.mul_integer(S::Curve::H).is_infinity() || self.gamma.is_infinity() {
+            return None
+        }
+        let h = S::encode_to_curve(&public.to_sec1(true), alpha);
+        let u = multiscalar_mul(&[self.s, -self.c], &[S::Curve::generator(), *public]);
+        let v = multiscalar_mul(&[self.s, -self.c], &[h, self.gamma]);
+        (challenge::<S>(&[*public, h, self.gamma, u, v]) == self.c).then(|| self.to_hash())
+    }
+    ///ECVRF_proof_to_hash, beta = SHA-256(suite_string || 0x03 || cofactor * Gamma || 0x00).
+    ///Only meaningful for a verified proof.
+    pub fn to_hash(&self) -> [u8; 32] {
+        let gamma = self.gamma.mul_integer(S::Curve::H).to_sec1(true);
+        let hash = digest(&SHA256, &[&[S::SUITE_STRING, 0x03], gamma.as_slice(), &[0x00]].concat());
+        hash.as_ref().try_into().unwrap()
+    }
+    ///Gamma || c || s, c in cLen bytes and s in the byte length of N
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let c = self.c.to_be_bytes();
+        [self.gamma.to_sec1(true), c[c.len() - S::C_LEN..].to_vec(), self.s.to_be_bytes()].concat()
+    }
+    ///ECVRF_decode_proof, None for an invalid Gamma or s not below N
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (point_len, scalar_len) = (1 + S::Curve::P.bits().div_ceil(8), S::Curve::N.bits().div_ceil(8));
+        if bytes.len() != point_len + S::C_LEN + scalar_len {
+            return None
+        }
+        let gamma = ECpoint::from_sec1(&bytes[..point_len])?;
+        let c = Scalar::new(U256::from_big_endian(&bytes[point_len..point_len + S::C_LEN]));
+        let s = Scalar::from_be_bytes(&bytes[point_len + S::C_LEN..])?;
+        Some(Self { gamma, c, s })
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt::Debug;
+
+use ring::digest::{digest, SHA256};
+
+use crate::{curves::P256, types::{ECpoint, Zp, Generator, EC}, utils::encode_to_curve_sswu};
+
+///ECVRF ciphersuite, the curve, the suite_string and the encoding to the curve. The
+///hash is SHA-256, points are compressed SEC1 and nonces come from RFC 6979.
+pub trait Suite: Debug + Copy + Eq {
+    type Curve: Generator;
+    const SUITE_STRING: u8;
+    ///cLen, the byte length of the challenge
+    const C_LEN: usize;
+    ///ECVRF_encode_to_curve(encode_to_curve_salt, alpha_string)
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> ECpoint<Self::Curve>;
+}
+
+///ECVRF_encode_to_curve_try_and_increment of RFC 9381 section 5.4.1.1, the first
+///SHA-256(suite_string || 0x01 || salt || alpha || ctr || 0x00) that is the x
+///coordinate of a point with an even y, times the cofactor. Not constant time.
+pub fn encode_to_curve_try_and_increment<E: EC>(suite_string: u8, salt: &[u8], alpha: &[u8]) -> ECpoint<E> {
+    (0..=u8::MAX)
+        .find_map(|ctr| {
+            let hash = digest(&SHA256, &[&[suite_string, 0x01], salt, alpha, &[ctr, 0x00]].concat());
+            ECpoint::<E>::from_sec1(&[&[0x02], hash.as_ref()].concat())
+                .map(|point| point.mul_integer(E::H))
+                .filter(|point| !point.is_infinity())
+        })
+        .expect("a valid point within 256 tries but with negligible probability")
+}
+
+///ECVRF-P256-SHA256-TAI, suite_string 0x01
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P256Sha256Tai;
+
+impl Suite for P256Sha256Tai {
+    type Curve = P256;
+    const SUITE_STRING: u8 = 0x01;
+    const C_LEN: usize = 16;
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> ECpoint<P256> {
+        encode_to_curve_try_and_increment(Self::SUITE_STRING, salt, alpha)
+    }
+}
+
+///ECVRF-P256-SHA256-SSWU, suite_string 0x02, encode_to_curve of RFC 9380 with
+///P256_XMD:SHA-256_SSWU_NU_
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct P256Sha256Sswu;
+
+impl Suite for P256Sha256Sswu {
+    type Curve = P256;
+    const SUITE_STRING: u8 = 0x02;
+    const C_LEN: usize = 16;
+    fn encode_to_curve(salt: &[u8], alpha: &[u8]) -> ECpoint<P256> {
+        let dst = [b"ECVRF_P256_XMD:SHA-256_SSWU_NU_".as_slice(), &[Self::SUITE_STRING]].concat();
+        encode_to_curve_sswu(&[salt, alpha].concat(), &dst, Zp::new(-10))
+    }
+}